@@ -0,0 +1,52 @@
+//! Compares the number of heap allocations per guard fork and drop in the default and
+//! the minimal mode.
+//!
+//! Run with `cargo bench --bench fork_allocations`.
+
+mod common;
+
+use common::{allocations, CountingAllocator};
+use rendezvous::{Rendezvous, RendezvousBuilder};
+use std::time::Instant;
+
+const GUARDS: usize = 100_000;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Forks and drops [`GUARDS`] guards, returning the total number of allocations and the
+/// elapsed time.
+fn measure(rendezvous: Rendezvous) -> (usize, std::time::Duration) {
+    // Create the channel up front so that it is not attributed to the first fork.
+    drop(rendezvous.fork_guard());
+
+    // Keep all guards alive to account for the bookkeeping of outstanding guards.
+    let mut guards = Vec::with_capacity(GUARDS);
+
+    let before = allocations();
+    let start = Instant::now();
+    for _ in 0..GUARDS {
+        guards.push(rendezvous.fork_guard());
+    }
+    drop(guards);
+    let elapsed = start.elapsed();
+    let allocations = allocations() - before;
+
+    rendezvous.rendezvous();
+    (allocations, elapsed)
+}
+
+fn main() {
+    let (default_allocations, default_elapsed) = measure(Rendezvous::new());
+    let (minimal_allocations, minimal_elapsed) =
+        measure(RendezvousBuilder::new().minimal().build());
+
+    println!(
+        "default mode: {default_allocations} allocations for {GUARDS} guards, {:?}/guard",
+        default_elapsed / GUARDS as u32
+    );
+    println!(
+        "minimal mode: {minimal_allocations} allocations for {GUARDS} guards, {:?}/guard",
+        minimal_elapsed / GUARDS as u32
+    );
+}
@@ -0,0 +1,37 @@
+//! Compares the cost of a rendezvous that never forks a guard against the eager channel
+//! setup used before the channel was initialized lazily.
+//!
+//! Run with `cargo bench --bench no_guard`.
+
+use rendezvous::Rendezvous;
+use std::hint::black_box;
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+
+const ITERATIONS: u32 = 1_000_000;
+
+/// Runs `f` for [`ITERATIONS`] iterations and returns the average time per iteration.
+fn measure(mut f: impl FnMut()) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    start.elapsed() / ITERATIONS
+}
+
+fn main() {
+    let eager = measure(|| {
+        // The previous implementation: create the channel, drop the sender, then receive.
+        let (tx, rx) = black_box(mpsc::channel::<()>());
+        drop(tx);
+        rx.recv().ok();
+    });
+
+    let lazy = measure(|| {
+        let rendezvous = black_box(Rendezvous::new());
+        rendezvous.rendezvous();
+    });
+
+    println!("no-guard rendezvous (eager channel): {eager:?}/iter");
+    println!("no-guard rendezvous (lazy channel):  {lazy:?}/iter");
+}
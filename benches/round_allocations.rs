@@ -0,0 +1,50 @@
+//! Compares the number of heap allocations per round when reusing a rendezvous using
+//! `reset()` and when creating a fresh rendezvous for every round.
+//!
+//! Run with `cargo bench --bench round_allocations`.
+
+mod common;
+
+use common::{allocations, CountingAllocator};
+use rendezvous::Rendezvous;
+use std::time::Instant;
+
+const ROUNDS: usize = 10_000;
+const GUARDS: usize = 16;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Forks and drops [`GUARDS`] guards, then waits for them.
+fn round(rendezvous: &mut Rendezvous) {
+    let guards: Vec<_> = (0..GUARDS).map(|_| rendezvous.fork_guard()).collect();
+    drop(guards);
+    rendezvous.rendezvous_ref();
+}
+
+/// Runs [`ROUNDS`] rounds using `next`, returning the allocations per round and the elapsed
+/// time per round.
+fn measure(mut next: impl FnMut()) -> (f64, std::time::Duration) {
+    let before = allocations();
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        next();
+    }
+    let elapsed = start.elapsed();
+    let allocations = allocations() - before;
+    (allocations as f64 / ROUNDS as f64, elapsed / ROUNDS as u32)
+}
+
+fn main() {
+    let (fresh_allocations, fresh_elapsed) = measure(|| round(&mut Rendezvous::new()));
+
+    let mut rendezvous = Rendezvous::new();
+    round(&mut rendezvous);
+    let (reset_allocations, reset_elapsed) = measure(|| {
+        rendezvous.reset().unwrap();
+        round(&mut rendezvous);
+    });
+
+    println!("fresh rendezvous: {fresh_allocations:.2} allocations/round, {fresh_elapsed:?}/round");
+    println!("reset rendezvous: {reset_allocations:.2} allocations/round, {reset_elapsed:?}/round");
+}
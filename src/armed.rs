@@ -0,0 +1,126 @@
+//! Arming guards for completion and committing them together.
+
+use crate::bridge::SignalSender;
+use crate::{lock, GuardId, Rendezvous, RendezvousGuard, Shared};
+
+impl Shared {
+    /// Returns a sender of the armed, not yet completed guard `id`.
+    pub(crate) fn armed_sender(&self, id: GuardId) -> Option<SignalSender> {
+        lock(&self.armed)
+            .iter()
+            .find(|(armed, _)| *armed == id)
+            .map(|(_, tx)| tx.clone())
+    }
+
+    /// Removes the armed guard `id`, returning its sender if it was not completed yet.
+    pub(crate) fn take_armed(&self, id: GuardId) -> Option<SignalSender> {
+        let mut armed = lock(&self.armed);
+        let index = armed.iter().position(|(armed, _)| *armed == id)?;
+        Some(armed.swap_remove(index).1)
+    }
+}
+
+impl RendezvousGuard {
+    /// Marks this guard as ready to complete.
+    ///
+    /// An armed guard keeps the rendezvous open until either it is dropped, or the coordinator
+    /// completes all armed guards at once using [`Rendezvous::complete_armed`]. This supports
+    /// a two-phase commit in which workers signal readiness and the coordinator commits them
+    /// together. Arming a guard more than once has no further effect.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use rendezvous::Rendezvous;
+    ///
+    /// let rendezvous = Rendezvous::new();
+    /// let guard = rendezvous.fork_guard();
+    /// guard.arm_completion();
+    /// assert_eq!(rendezvous.complete_armed(), 1);
+    ///
+    /// // The guard is completed even though it is still alive.
+    /// assert_eq!(rendezvous.remaining_guards(), 0);
+    /// rendezvous.rendezvous();
+    /// ```
+    pub fn arm_completion(&self) {
+        if let Some(tx) = lock(&self.tx).take() {
+            lock(&self.shared.armed).push((self.id, tx));
+        }
+    }
+}
+
+impl<C> Rendezvous<C> {
+    /// Completes all guards armed using [`RendezvousGuard::arm_completion`] at once, returning
+    /// how many were completed.
+    ///
+    /// Completed guards no longer keep the rendezvous open, even if they are still alive;
+    /// dropping them later has no further effect. Guards that are not armed are left untouched.
+    pub fn complete_armed(&self) -> usize {
+        let Some(channel) = self.channel.get() else {
+            return 0;
+        };
+        let armed = std::mem::take(&mut *lock(&channel.shared.armed));
+        let count = armed.len();
+        for (id, tx) in armed {
+            channel.shared.on_release(id);
+            drop(tx);
+            channel.shared.on_settled();
+        }
+        #[cfg(any(feature = "log", feature = "tracing"))]
+        {
+            trace!(
+                "Completed {count} armed rendezvous guards{}",
+                self.log_context()
+            );
+        }
+        count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RendezvousTimeoutError;
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn complete_armed_releases_only_armed_guards() {
+        let mut rendezvous = Rendezvous::new();
+        let first = rendezvous.fork_guard();
+        let second = rendezvous.fork_guard();
+        let third = rendezvous.fork_guard();
+
+        first.arm_completion();
+        second.arm_completion();
+        assert_eq!(rendezvous.complete_armed(), 2);
+        assert_eq!(rendezvous.remaining_guards(), 1);
+        assert_eq!(
+            rendezvous.rendezvous_timeout(Duration::from_millis(10)),
+            Err(RendezvousTimeoutError::Timeout)
+        );
+
+        // Dropping completed guards does not complete them twice.
+        drop(first);
+        drop(second);
+        assert_eq!(rendezvous.remaining_guards(), 1);
+
+        thread::spawn(move || drop(third));
+        rendezvous.rendezvous();
+    }
+
+    #[test]
+    fn dropping_an_armed_guard_completes_it() {
+        let rendezvous = Rendezvous::new();
+        let guard = rendezvous.fork_guard();
+        guard.arm_completion();
+        guard.arm_completion();
+        let nested = guard.fork();
+        drop(guard);
+
+        assert_eq!(rendezvous.complete_armed(), 0);
+        assert_eq!(rendezvous.remaining_guards(), 1);
+        drop(nested);
+        rendezvous.rendezvous();
+    }
+}
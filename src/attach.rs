@@ -0,0 +1,140 @@
+//! Bridging asynchronous tasks into a rendezvous.
+
+use crate::Rendezvous;
+use std::future::Future;
+use tokio::task::{AbortHandle, JoinHandle, JoinSet};
+
+impl<C> Rendezvous<C> {
+    /// Spawns `fut` on the Tokio runtime holding a forked guard, which is dropped when the
+    /// future completes.
+    ///
+    /// This bridges asynchronous tasks into a rendezvous that is otherwise waited for
+    /// synchronously. The returned handle can be used to obtain the output of the future. If
+    /// the task is aborted or panics, the guard is dropped as well.
+    ///
+    /// <div class="warning">
+    /// This must be called from within the context of a Tokio runtime, e.g. from an
+    /// asynchronous task or while a runtime is entered; otherwise it panics. Blocking on the
+    /// rendezvous must not happen on a runtime worker thread the future needs to make progress,
+    /// e.g. use <code>spawn_blocking</code> or <code>rendezvous_async</code>.
+    /// </div>
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use std::time::Duration;
+    /// use rendezvous::Rendezvous;
+    ///
+    /// let rendezvous = Rendezvous::new();
+    /// rendezvous.attach_future(tokio::time::sleep(Duration::from_millis(10)));
+    /// tokio::task::spawn_blocking(move || rendezvous.rendezvous())
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+    pub fn attach_future<F>(&self, fut: F) -> JoinHandle<F::Output>
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        let guard = self.fork_guard();
+        tokio::spawn(async move {
+            let output = fut.await;
+            drop(guard);
+            output
+        })
+    }
+}
+
+impl<C> Rendezvous<C> {
+    /// Spawns `fut` into `set` holding a forked guard, which is dropped when the task
+    /// completes.
+    ///
+    /// This lets the rendezvous track all tasks of a [`JoinSet`] used for asynchronous fan-out.
+    /// Like [`attach_future`](Self::attach_future), this must be called from within the
+    /// context of a Tokio runtime. The guard is also dropped if the task is aborted, including
+    /// when the set is dropped.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use tokio::task::JoinSet;
+    /// use rendezvous::Rendezvous;
+    ///
+    /// let rendezvous = Rendezvous::new();
+    /// let mut set = JoinSet::new();
+    /// for value in 0..3 {
+    ///     rendezvous.guard_joinset(&mut set, async move { value * 2 });
+    /// }
+    /// rendezvous.rendezvous_async().await.unwrap();
+    /// assert_eq!(set.len(), 3);
+    /// # }
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+    pub fn guard_joinset<T, F>(&self, set: &mut JoinSet<T>, fut: F) -> AbortHandle
+    where
+        T: Send + 'static,
+        F: Future<Output = T> + Send + 'static,
+    {
+        let guard = self.fork_guard();
+        set.spawn(async move {
+            let output = fut.await;
+            drop(guard);
+            output
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn rendezvous_waits_for_attached_future() {
+        let finished = Arc::new(AtomicBool::new(false));
+        let rendezvous = Rendezvous::new();
+        let handle = rendezvous.attach_future({
+            let finished = finished.clone();
+            async move {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                finished.store(true, Ordering::Release);
+                42
+            }
+        });
+
+        tokio::task::spawn_blocking(move || rendezvous.rendezvous())
+            .await
+            .unwrap();
+        assert!(finished.load(Ordering::Acquire));
+        assert_eq!(handle.await.unwrap(), 42);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn rendezvous_waits_for_joinset_tasks() {
+        let rendezvous = Rendezvous::new();
+        let mut set = JoinSet::new();
+        for delay in [10, 30, 50] {
+            rendezvous.guard_joinset(&mut set, async move {
+                tokio::time::sleep(Duration::from_millis(delay)).await;
+                delay
+            });
+        }
+        assert_eq!(rendezvous.remaining_guards(), 3);
+
+        rendezvous.rendezvous_async().await.unwrap();
+        let mut delays = Vec::new();
+        while let Some(delay) = set.join_next().await {
+            delays.push(delay.unwrap());
+        }
+        delays.sort();
+        assert_eq!(delays, vec![10, 30, 50]);
+    }
+}
@@ -0,0 +1,275 @@
+//! Retaining the lifetimes of completed guards for post-mortem analysis.
+
+use crate::{lock, GuardId, Rendezvous, Shared};
+use std::fmt::{Display, Formatter};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// The audited lifetime of a completed guard.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct GuardAudit {
+    /// The identifier of the guard.
+    pub id: GuardId,
+    /// The label the guard was forked with, if any.
+    pub label: Option<String>,
+    /// The name of the thread the guard was forked on, or its [`ThreadId`](thread::ThreadId)
+    /// if the thread is unnamed.
+    pub thread: String,
+    /// The point in time the guard was forked at.
+    pub forked_at: Instant,
+    /// The point in time the guard was dropped at.
+    pub completed_at: Instant,
+    /// Whether the guard was dropped while its thread was panicking.
+    pub panicked: bool,
+}
+
+impl GuardAudit {
+    /// Returns the time elapsed between forking and dropping the guard.
+    pub fn lifetime(&self) -> Duration {
+        self.completed_at.saturating_duration_since(self.forked_at)
+    }
+}
+
+impl Display for GuardAudit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.id)?;
+        if let Some(label) = &self.label {
+            write!(f, " '{label}'")?;
+        }
+        write!(f, " on thread '{}' took {:?}", self.thread, self.lifetime())?;
+        if self.panicked {
+            write!(f, " (panicked)")?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the name of the current thread, or its identifier if the thread is unnamed.
+pub(crate) fn current_thread_name() -> String {
+    let current = thread::current();
+    match current.name() {
+        Some(name) => name.to_owned(),
+        None => format!("{:?}", current.id()),
+    }
+}
+
+/// The audit of a completed rendezvous; see [`Rendezvous::rendezvous_audited`].
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct AuditReport {
+    /// The round of the rendezvous; see [`Rendezvous::round`].
+    pub round: u64,
+    /// The audits of all guards, ordered by id.
+    pub guards: Vec<GuardAudit>,
+}
+
+impl AuditReport {
+    /// Returns the audits of all guards dropped while their thread was panicking.
+    pub fn panicked(&self) -> impl Iterator<Item = &GuardAudit> {
+        self.guards.iter().filter(|guard| guard.panicked)
+    }
+}
+
+impl Shared {
+    /// Returns whether the lifetimes of completed guards are retained.
+    pub(crate) fn is_audited(&self) -> bool {
+        self.options.audit || cfg!(feature = "chrome-trace")
+    }
+
+    /// Retains the lifetime of a completed guard, if auditing is enabled.
+    pub(crate) fn record_audit(
+        &self,
+        id: GuardId,
+        label: Option<String>,
+        thread: Option<String>,
+        forked_at: Instant,
+    ) {
+        if !self.is_audited() {
+            return;
+        }
+        lock(&self.audits).push(GuardAudit {
+            id,
+            label,
+            thread: thread.unwrap_or_default(),
+            forked_at,
+            completed_at: Instant::now(),
+            panicked: thread::panicking(),
+        });
+    }
+
+    /// Returns the audits of all completed guards, ordered by id.
+    pub(crate) fn audits(&self) -> Vec<GuardAudit> {
+        let mut audits = lock(&self.audits).clone();
+        audits.sort_by_key(|audit| audit.id);
+        audits
+    }
+
+    /// Creates the audit report of the completed rendezvous.
+    fn audit_report(&self) -> AuditReport {
+        AuditReport {
+            round: self.round,
+            guards: self.audits(),
+        }
+    }
+}
+
+impl<C> Rendezvous<C> {
+    /// Executes the rendezvous process, then returns the audit of all guards.
+    ///
+    /// The report lists the lifetime of every guard and whether it was dropped while its thread
+    /// was panicking. Guards are only audited if the rendezvous was built with
+    /// [`RendezvousBuilder::audit`](crate::RendezvousBuilder::audit); otherwise, the report
+    /// lists no guards.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use rendezvous::RendezvousBuilder;
+    ///
+    /// let rendezvous = RendezvousBuilder::new().audit().build();
+    /// rendezvous.fork_guard_labeled("worker").completed();
+    ///
+    /// let report = rendezvous.rendezvous_audited();
+    /// assert_eq!(report.guards[0].label.as_deref(), Some("worker"));
+    /// assert_eq!(report.panicked().count(), 0);
+    /// ```
+    pub fn rendezvous_audited(mut self) -> AuditReport {
+        let _span = self.wait_span("rendezvous_audited");
+        let shared = self.channel.get().map(|channel| channel.shared.clone());
+        self.rendezvous_internal();
+        shared
+            .map(|shared| shared.audit_report())
+            .unwrap_or_else(|| AuditReport {
+                round: self.round,
+                guards: Vec::new(),
+            })
+    }
+
+    /// Asynchronously executes the rendezvous process, then returns the audit of all guards.
+    /// See [`Rendezvous::rendezvous_audited`].
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use rendezvous::RendezvousBuilder;
+    ///
+    /// let rendezvous = RendezvousBuilder::new().audit().build();
+    /// let guard = rendezvous.fork_guard();
+    /// tokio::spawn(async move { drop(guard) });
+    ///
+    /// let report = rendezvous.rendezvous_audited_async().await;
+    /// assert_eq!(report.guards.len(), 1);
+    /// # }
+    /// ```
+    #[cfg(feature = "tokio")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+    pub async fn rendezvous_audited_async(self) -> AuditReport
+    where
+        C: Send + 'static,
+    {
+        let round = self.round;
+        let shared = self.channel.get().map(|channel| channel.shared.clone());
+        // The blocking wait only fails if it panicked, in which case it is resumed here.
+        if let Err(error) = self.rendezvous_async().await {
+            std::panic::resume_unwind(error.into_panic());
+        }
+        shared
+            .map(|shared| shared.audit_report())
+            .unwrap_or_else(|| AuditReport {
+                round,
+                guards: Vec::new(),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RendezvousBuilder;
+
+    #[test]
+    fn report_lists_lifetimes_and_panics() {
+        let rendezvous = RendezvousBuilder::new().audit().build();
+        let regular = rendezvous.fork_guard_labeled("regular");
+        let panicking = rendezvous.fork_guard();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(10));
+            drop(regular);
+        });
+        thread::spawn(move || {
+            let _guard = panicking;
+            panic!("worker failed");
+        });
+
+        let report = rendezvous.rendezvous_audited();
+        assert_eq!(report.round, 0);
+        assert_eq!(report.guards.len(), 2);
+        assert_eq!(report.guards[0].label.as_deref(), Some("regular"));
+        assert!(!report.guards[0].panicked);
+        assert!(report.guards[0].lifetime() >= Duration::from_millis(10));
+        let panicked: Vec<_> = report.panicked().map(|guard| guard.id).collect();
+        assert_eq!(panicked, vec![report.guards[1].id]);
+    }
+
+    #[test]
+    fn report_names_the_forking_thread() {
+        let rendezvous = RendezvousBuilder::new().audit().build();
+        let named = thread::Builder::new()
+            .name("db-pool-3".to_owned())
+            .spawn({
+                let rendezvous = rendezvous.clone();
+                move || rendezvous.fork_guard_labeled("query")
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+        drop(named);
+        let unnamed = thread::spawn({
+            let rendezvous = rendezvous.clone();
+            move || (rendezvous.fork_guard(), thread::current().id())
+        });
+        let (guard, id) = unnamed.join().unwrap();
+        drop(guard);
+
+        let report = rendezvous.rendezvous_audited();
+        assert_eq!(report.guards[0].thread, "db-pool-3");
+        assert!(report.guards[0]
+            .to_string()
+            .starts_with("#0 'query' on thread 'db-pool-3' took "));
+        assert_eq!(report.guards[1].thread, format!("{id:?}"));
+    }
+
+    #[test]
+    fn unaudited_rendezvous_reports_no_guards() {
+        let rendezvous = crate::Rendezvous::new();
+        rendezvous.fork_guard().completed();
+        let report = rendezvous.rendezvous_audited();
+        assert_eq!(report.guards.is_empty(), !cfg!(feature = "chrome-trace"));
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn audited_future_resolves_with_report() {
+        let rendezvous = RendezvousBuilder::new().audit().build();
+        for label in ["first", "second"] {
+            let guard = rendezvous.fork_guard_labeled(label);
+            tokio::spawn(async move {
+                tokio::time::sleep(Duration::from_millis(5)).await;
+                drop(guard);
+            });
+        }
+
+        let report = rendezvous.rendezvous_audited_async().await;
+        let labels: Vec<_> = report
+            .guards
+            .iter()
+            .map(|guard| guard.label.as_deref())
+            .collect();
+        assert_eq!(labels, vec![Some("first"), Some("second")]);
+        assert!(report
+            .guards
+            .iter()
+            .all(|guard| guard.completed_at >= guard.forked_at && !guard.panicked));
+    }
+}
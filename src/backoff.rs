@@ -0,0 +1,160 @@
+//! Waiting by polling with an exponential backoff.
+
+use crate::{Rendezvous, RendezvousTimeoutError};
+use std::sync::mpsc::TryRecvError;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// An exponential backoff schedule: starts at an initial interval and doubles it on
+/// every step, up to a maximum.
+#[derive(Debug, Clone)]
+struct Backoff {
+    /// The interval returned by the next step.
+    next: Duration,
+    /// The maximum interval.
+    max: Duration,
+}
+
+impl Backoff {
+    fn new(initial: Duration, max: Duration) -> Self {
+        Self {
+            next: initial.min(max),
+            max,
+        }
+    }
+}
+
+impl Iterator for Backoff {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next;
+        self.next = current.saturating_mul(2).min(self.max);
+        Some(current)
+    }
+}
+
+impl<C> Rendezvous<C> {
+    /// Executes the rendezvous process by polling with an exponential backoff until all guards
+    /// are dropped or the `deadline` passes.
+    ///
+    /// The completion state is checked, then the thread sleeps for `initial`; the sleep interval
+    /// doubles after every unsuccessful check until it reaches `max`, e.g. `1ms, 2ms, 4ms, 8ms, 8ms,
+    /// ...` for an `initial` interval of 1ms and a `max` interval of 8ms. Sleeps never extend past
+    /// the deadline. This keeps short waits responsive while reducing the polling overhead of
+    /// long ones; completion is detected at most one interval (bounded by `max`) late.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::thread;
+    /// use std::time::{Duration, Instant};
+    /// use rendezvous::Rendezvous;
+    ///
+    /// let mut rendezvous = Rendezvous::new();
+    /// let guard = rendezvous.fork_guard();
+    /// thread::spawn(move || {
+    ///     thread::sleep(Duration::from_millis(50));
+    ///     drop(guard);
+    /// });
+    ///
+    /// let deadline = Instant::now() + Duration::from_secs(1);
+    /// let result = rendezvous.rendezvous_backoff(
+    ///     Duration::from_millis(1),
+    ///     Duration::from_millis(20),
+    ///     deadline,
+    /// );
+    /// assert_eq!(result, Ok(()));
+    /// ```
+    pub fn rendezvous_backoff(
+        &mut self,
+        initial: Duration,
+        max: Duration,
+        deadline: Instant,
+    ) -> Result<(), RendezvousTimeoutError> {
+        let _span = self.wait_span("rendezvous_backoff");
+        let started = Instant::now();
+        let mut result = Ok(());
+        if let Some(channel) = self.release() {
+            let mut backoff = Backoff::new(initial, max);
+            loop {
+                match channel.try_recv() {
+                    Ok(_) => continue,
+                    Err(TryRecvError::Disconnected) => break,
+                    Err(TryRecvError::Empty) => {}
+                }
+                let now = Instant::now();
+                if now >= deadline {
+                    #[cfg(any(feature = "log", feature = "tracing"))]
+                    {
+                        debug!(
+                            "A timeout occurred during a rendezvous with backoff{}",
+                            self.log_context()
+                        );
+                    }
+                    result = Err(RendezvousTimeoutError::Timeout);
+                    break;
+                }
+                let interval = backoff.next().unwrap_or(max);
+                thread::sleep(interval.min(deadline - now));
+            }
+        }
+        self.record_wait(started);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_doubles_up_to_max() {
+        let schedule: Vec<_> = Backoff::new(Duration::from_millis(1), Duration::from_millis(8))
+            .take(6)
+            .map(|d| d.as_millis())
+            .collect();
+        assert_eq!(schedule, vec![1, 2, 4, 8, 8, 8]);
+    }
+
+    #[test]
+    fn backoff_initial_is_capped_by_max() {
+        let mut backoff = Backoff::new(Duration::from_secs(1), Duration::from_millis(5));
+        assert_eq!(backoff.next(), Some(Duration::from_millis(5)));
+    }
+
+    #[test]
+    fn completion_detected_promptly() {
+        let mut rendezvous = Rendezvous::new();
+        let guard = rendezvous.fork_guard();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(100));
+            drop(guard);
+        });
+
+        // Completing before the deadline shows that the growing interval did not skip past
+        // the drop of the guard.
+        let result = rendezvous.rendezvous_backoff(
+            Duration::from_millis(1),
+            Duration::from_millis(16),
+            Instant::now() + Duration::from_secs(30),
+        );
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn times_out_at_deadline() {
+        let mut rendezvous = Rendezvous::new();
+        let guard = rendezvous.fork_guard();
+
+        let started = Instant::now();
+        let result = rendezvous.rendezvous_backoff(
+            Duration::from_millis(1),
+            Duration::from_millis(100),
+            started + Duration::from_millis(50),
+        );
+        assert_eq!(result, Err(RendezvousTimeoutError::Timeout));
+        assert!(started.elapsed() >= Duration::from_millis(50));
+        drop(guard);
+    }
+}
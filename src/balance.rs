@@ -0,0 +1,108 @@
+//! Checking that every forked guard signaled its completion exactly once.
+
+use crate::{Rendezvous, Shared};
+use std::sync::atomic::Ordering;
+
+impl Shared {
+    /// Returns the numbers of forked and completed guards if they diverge.
+    ///
+    /// Transferred guards keep completing after the rendezvous itself completed, so no
+    /// imbalance is reported for them.
+    pub(crate) fn imbalance(&self) -> Option<(usize, usize)> {
+        if self.is_transferred() {
+            return None;
+        }
+        let forked = self.forked.load(Ordering::SeqCst);
+        let completed = self.completed.load(Ordering::SeqCst);
+        (forked != completed).then_some((forked, completed))
+    }
+}
+
+impl<C> Rendezvous<C> {
+    /// Checks that the number of completion signals received equals the number of guards
+    /// forked, once the rendezvous completed while being dropped.
+    ///
+    /// A divergence indicates a lost or duplicate completion signal, i.e. a bug in the
+    /// signaling path rather than in user code. It is logged as an error with the `log` or
+    /// `tracing` feature, and printed to the standard error otherwise. Since this runs while
+    /// dropping the rendezvous, it never panics.
+    pub(crate) fn check_balance(&self) {
+        let Some(channel) = self.channel.get() else {
+            return;
+        };
+        let Some((forked, completed)) = channel.shared.imbalance() else {
+            return;
+        };
+        #[cfg(any(feature = "log", feature = "tracing"))]
+        {
+            error!(
+                "Implementation error: {forked} rendezvous guards forked, but {completed} completed{}",
+                self.log_context()
+            );
+        }
+        #[cfg(not(any(feature = "log", feature = "tracing")))]
+        {
+            eprintln!(
+                "Implementation error: {forked} rendezvous guards forked, but {completed} completed"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn balanced_guards_report_no_imbalance() {
+        #[cfg(feature = "log")]
+        crate::test_log::init();
+
+        let mut rendezvous = Rendezvous::with_context(String::from("balance-3c9e"));
+        let guard = rendezvous.fork_guard();
+        let nested = guard.fork();
+        let armed = rendezvous.fork_guard();
+        armed.arm_completion();
+        rendezvous.complete_armed();
+        rendezvous.fork_guard().fail("ignored");
+        thread::spawn(move || drop((guard, nested)));
+        drop(armed);
+
+        rendezvous.rendezvous_internal();
+        assert_eq!(rendezvous.shared().imbalance(), None);
+        drop(rendezvous);
+
+        #[cfg(feature = "log")]
+        assert!(crate::test_log::messages_containing("balance-3c9e")
+            .iter()
+            .all(|(_, message)| !message.contains("Implementation error")));
+    }
+
+    #[test]
+    fn divergent_counts_are_reported() {
+        let shared = Shared::default();
+        shared.forked.store(2, Ordering::SeqCst);
+        shared.completed.store(1, Ordering::SeqCst);
+        assert_eq!(shared.imbalance(), Some((2, 1)));
+    }
+
+    #[test]
+    fn imbalance_is_logged_without_panicking() {
+        #[cfg(feature = "log")]
+        crate::test_log::init();
+
+        let rendezvous = Rendezvous::with_context(String::from("balance-7f1a"));
+        rendezvous.fork_guard().completed();
+        // Simulates a lost completion signal.
+        rendezvous.shared().completed.fetch_sub(1, Ordering::SeqCst);
+        rendezvous.check_balance();
+
+        #[cfg(feature = "log")]
+        assert!(crate::test_log::messages_containing("balance-7f1a")
+            .iter()
+            .any(|(level, message)| *level == log::Level::Error
+                && message.starts_with("Implementation error: 1 rendezvous guards forked, but 0")));
+        rendezvous.shared().completed.fetch_add(1, Ordering::SeqCst);
+    }
+}
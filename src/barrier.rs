@@ -0,0 +1,94 @@
+//! Guards that are released only after passing a [`Barrier`].
+
+use crate::{Rendezvous, RendezvousGuard};
+use std::sync::{Arc, Barrier};
+
+/// A guard that, when dropped, first waits on a [`Barrier`] and only then releases its
+/// [`RendezvousGuard`]. Created using [`Rendezvous::barrier_guard`].
+pub struct BarrierGuard {
+    /// The guard released after the barrier was passed.
+    guard: Option<RendezvousGuard>,
+    /// The barrier to wait on before releasing the guard.
+    barrier: Arc<Barrier>,
+}
+
+impl<C> Rendezvous<C> {
+    /// Forks a guard that is released only after its holder has passed `barrier`.
+    ///
+    /// Dropping the returned [`BarrierGuard`] blocks on [`Barrier::wait`] and releases the
+    /// rendezvous guard afterwards. This synchronizes a group of workers to a common point
+    /// before the rendezvous can proceed.
+    ///
+    /// <div class="warning">
+    /// Dropping a barrier guard blocks until the barrier is passed; a barrier sized for more
+    /// participants than there are barrier guards deadlocks.
+    /// </div>
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::sync::{Arc, Barrier};
+    /// use std::thread;
+    /// use rendezvous::Rendezvous;
+    ///
+    /// let rendezvous = Rendezvous::new();
+    /// let barrier = Arc::new(Barrier::new(2));
+    ///
+    /// for _ in 0..2 {
+    ///     let guard = rendezvous.barrier_guard(barrier.clone());
+    ///     thread::spawn(move || drop(guard));
+    /// }
+    ///
+    /// rendezvous.rendezvous();
+    /// ```
+    pub fn barrier_guard(&self, barrier: Arc<Barrier>) -> BarrierGuard {
+        BarrierGuard {
+            guard: Some(self.fork_guard()),
+            barrier,
+        }
+    }
+}
+
+impl BarrierGuard {
+    /// Returns the rendezvous guard held by this barrier guard.
+    pub fn guard(&self) -> &RendezvousGuard {
+        self.guard.as_ref().expect("guard is only taken on drop")
+    }
+}
+
+impl Drop for BarrierGuard {
+    fn drop(&mut self) {
+        self.barrier.wait();
+        drop(self.guard.take());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RendezvousTimeoutError;
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn guards_release_only_after_all_reach_barrier() {
+        let mut rendezvous = Rendezvous::new();
+        let barrier = Arc::new(Barrier::new(3));
+
+        let mut guards: Vec<_> = (0..3)
+            .map(|_| rendezvous.barrier_guard(barrier.clone()))
+            .collect();
+        let last = guards.pop().unwrap();
+
+        for guard in guards {
+            thread::spawn(move || drop(guard));
+        }
+
+        let result = rendezvous.rendezvous_timeout(Duration::from_millis(100));
+        assert_eq!(result, Err(RendezvousTimeoutError::Timeout));
+
+        thread::spawn(move || drop(last));
+        let result = rendezvous.rendezvous_timeout(Duration::from_secs(5));
+        assert_eq!(result, Ok(()));
+    }
+}
@@ -0,0 +1,116 @@
+//! Deadlines measured on a clock that keeps running while the system is suspended.
+
+use crate::{Channel, Rendezvous, RendezvousTimeoutError};
+use std::sync::mpsc::RecvTimeoutError;
+use std::time::{Duration, Instant};
+
+/// The longest single wait on the channel, bounding how late a deadline that passed during a
+/// suspend is noticed after resuming.
+const SLICE: Duration = Duration::from_secs(1);
+
+/// A point in time on `CLOCK_BOOTTIME`, i.e. the time since boot including suspended time.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+struct BootInstant(Duration);
+
+impl BootInstant {
+    /// Returns the current point in time.
+    fn now() -> Self {
+        let mut time = libc::timespec {
+            tv_sec: 0,
+            tv_nsec: 0,
+        };
+        // SAFETY: `time` is a valid, writable timespec.
+        let result = unsafe { libc::clock_gettime(libc::CLOCK_BOOTTIME, &mut time) };
+        assert_eq!(result, 0, "CLOCK_BOOTTIME is supported since Linux 2.6.39");
+        Self(Duration::new(time.tv_sec as u64, time.tv_nsec as u32))
+    }
+
+    /// Translates `deadline` to this clock, given the current points in time `now` and
+    /// `boot_now` on both clocks.
+    fn from_instant(deadline: Instant, now: Instant, boot_now: Self) -> Self {
+        Self(boot_now.0 + deadline.saturating_duration_since(now))
+    }
+
+    /// Returns the time remaining until `self`, or zero if it has passed.
+    fn remaining(self) -> Duration {
+        self.0.saturating_sub(Self::now().0)
+    }
+}
+
+impl Channel {
+    /// Blocks until all senders are dropped or the `deadline` passes, discarding any
+    /// signals received meanwhile.
+    fn wait_boottime(&self, deadline: BootInstant) -> Result<(), RendezvousTimeoutError> {
+        loop {
+            let remaining = deadline.remaining();
+            match self.recv_timeout(remaining.min(SLICE)) {
+                Ok(_) => continue,
+                Err(RecvTimeoutError::Disconnected) => return Ok(()),
+                Err(RecvTimeoutError::Timeout) if remaining <= SLICE => {
+                    if deadline.remaining().is_zero() {
+                        return Err(RendezvousTimeoutError::Timeout);
+                    }
+                }
+                Err(RecvTimeoutError::Timeout) => continue,
+            }
+        }
+    }
+}
+
+impl<C> Rendezvous<C> {
+    /// Executes the rendezvous process, giving up once `deadline` has passed on
+    /// `CLOCK_BOOTTIME`.
+    pub(crate) fn rendezvous_boottime(
+        &mut self,
+        deadline: Instant,
+    ) -> Result<(), RendezvousTimeoutError> {
+        let started = Instant::now();
+        let deadline = BootInstant::from_instant(deadline, started, BootInstant::now());
+        let result = match self.release() {
+            Some(channel) => channel.wait_boottime(deadline),
+            None => Ok(()),
+        };
+        self.record_wait(started);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RendezvousBuilder;
+
+    #[test]
+    fn deadlines_are_translated_to_boot_time() {
+        let now = Instant::now();
+        let boot_now = BootInstant(Duration::from_secs(100));
+        let deadline = BootInstant::from_instant(now + Duration::from_secs(30), now, boot_now);
+        assert_eq!(deadline, BootInstant(Duration::from_secs(130)));
+
+        let passed = BootInstant::from_instant(now, now + Duration::from_secs(1), boot_now);
+        assert_eq!(passed, boot_now);
+    }
+
+    #[test]
+    fn boot_time_includes_monotonic_time() {
+        let mut monotonic = libc::timespec {
+            tv_sec: 0,
+            tv_nsec: 0,
+        };
+        // SAFETY: `monotonic` is a valid, writable timespec.
+        unsafe { libc::clock_gettime(libc::CLOCK_MONOTONIC, &mut monotonic) };
+        let boot = BootInstant::now();
+        assert!(boot.0 >= Duration::new(monotonic.tv_sec as u64, monotonic.tv_nsec as u32));
+    }
+
+    #[test]
+    fn deadline_is_enforced_on_boot_time() {
+        let mut rendezvous = RendezvousBuilder::new().boottime_deadlines().build();
+        let guard = rendezvous.fork_guard();
+        let started = Instant::now();
+        let result = rendezvous.rendezvous_deadline(started + Duration::from_millis(20));
+        assert_eq!(result, Err(RendezvousTimeoutError::Timeout));
+        assert!(started.elapsed() >= Duration::from_millis(20));
+        drop(guard);
+    }
+}
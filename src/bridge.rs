@@ -0,0 +1,225 @@
+//! Bridging an existing `mpsc` channel into a rendezvous.
+
+use crate::{lock, Rendezvous, Signal};
+use std::collections::VecDeque;
+use std::sync::mpsc::{self, RecvError, RecvTimeoutError, TryRecvError};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// The signals sent into a bridged channel, which itself only carries `()`.
+type Mailbox = Arc<Mutex<VecDeque<Signal>>>;
+
+/// The sending half of the channel backing a [`Rendezvous`].
+#[derive(Clone)]
+pub(crate) enum SignalSender {
+    /// A channel created by the rendezvous, carrying the signals themselves.
+    Native(mpsc::Sender<Signal>),
+    /// A channel passed to [`Rendezvous::from_channel`]; each signal is put into the mailbox
+    /// and announced by sending `()`.
+    Bridged(mpsc::Sender<()>, Mailbox),
+}
+
+/// The receiving half of the channel backing a [`Rendezvous`]; see [`SignalSender`].
+pub(crate) enum SignalReceiver {
+    /// A channel created by the rendezvous.
+    Native(mpsc::Receiver<Signal>),
+    /// A channel passed to [`Rendezvous::from_channel`].
+    Bridged(mpsc::Receiver<()>, Mailbox),
+}
+
+/// Creates a channel carrying signals.
+pub(crate) fn channel() -> (SignalSender, SignalReceiver) {
+    let (tx, rx) = mpsc::channel();
+    (SignalSender::Native(tx), SignalReceiver::Native(rx))
+}
+
+impl SignalSender {
+    /// Sends `signal` to the receiver, if it is still alive.
+    pub(crate) fn send(&self, signal: Signal) {
+        match self {
+            Self::Native(tx) => {
+                tx.send(signal).ok();
+            }
+            Self::Bridged(tx, mailbox) => {
+                // Queued before announcing it, so the announcement always finds it; an earlier
+                // announcement may deliver it first, leaving this one to find nothing.
+                lock(mailbox).push_back(signal);
+                tx.send(()).ok();
+            }
+        }
+    }
+}
+
+impl SignalReceiver {
+    /// Receives the next signal, like [`mpsc::Receiver::recv`].
+    ///
+    /// Messages on a bridged channel that announce no signal, i.e. those sent by external
+    /// senders, are discarded.
+    pub(crate) fn recv(&self) -> Result<Signal, RecvError> {
+        match self {
+            Self::Native(rx) => rx.recv(),
+            Self::Bridged(rx, mailbox) => loop {
+                rx.recv()?;
+                if let Some(signal) = lock(mailbox).pop_front() {
+                    return Ok(signal);
+                }
+            },
+        }
+    }
+
+    /// Receives the next signal if one is available, like [`mpsc::Receiver::try_recv`].
+    pub(crate) fn try_recv(&self) -> Result<Signal, TryRecvError> {
+        match self {
+            Self::Native(rx) => rx.try_recv(),
+            Self::Bridged(rx, mailbox) => loop {
+                rx.try_recv()?;
+                if let Some(signal) = lock(mailbox).pop_front() {
+                    return Ok(signal);
+                }
+            },
+        }
+    }
+
+    /// Receives the next signal within `timeout`, like [`mpsc::Receiver::recv_timeout`].
+    pub(crate) fn recv_timeout(&self, timeout: Duration) -> Result<Signal, RecvTimeoutError> {
+        match self {
+            Self::Native(rx) => rx.recv_timeout(timeout),
+            Self::Bridged(rx, mailbox) => {
+                let deadline = Instant::now() + timeout;
+                loop {
+                    rx.recv_timeout(deadline.saturating_duration_since(Instant::now()))?;
+                    if let Some(signal) = lock(mailbox).pop_front() {
+                        return Ok(signal);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Rendezvous {
+    /// Creates a rendezvous from an existing channel pair, using `tx` as the original sender.
+    ///
+    /// The rendezvous behaves like one created using [`Rendezvous::new`]: guards are forked off
+    /// `tx`, and waiting drops it and blocks on `rx` until all senders are dropped. Messages
+    /// sent on the channel by other senders are received and discarded while waiting.
+    ///
+    /// <div class="warning">
+    /// Every other sender of the channel, e.g. one cloned off `tx` before calling this,
+    /// keeps the rendezvous open until dropped, without being tracked as a guard. This allows
+    /// external code to take part without knowing about the rendezvous, but also blocks the
+    /// rendezvous indefinitely if a sender is leaked. Such senders are not counted by
+    /// [`Rendezvous::remaining_guards`], nor observed by [`Rendezvous::is_ready`] and
+    /// [`Rendezvous::poll_complete`].
+    /// </div>
+    ///
+    /// [Resetting](Rendezvous::reset) the rendezvous replaces the channel with a new one.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::sync::mpsc;
+    /// use std::thread;
+    /// use rendezvous::Rendezvous;
+    ///
+    /// let (tx, rx) = mpsc::channel::<()>();
+    /// let external = tx.clone();
+    /// thread::spawn(move || {
+    ///     // ... interoperating code holding a plain sender ...
+    ///     drop(external);
+    /// });
+    ///
+    /// let rendezvous = Rendezvous::from_channel(tx, rx);
+    /// let guard = rendezvous.fork_guard();
+    /// drop(guard);
+    /// rendezvous.rendezvous();
+    /// ```
+    pub fn from_channel(tx: mpsc::Sender<()>, rx: mpsc::Receiver<()>) -> Self {
+        let rendezvous = Self::new();
+        let mailbox = Mailbox::default();
+        rendezvous.channel.get_or_init(|| {
+            rendezvous.new_channel(
+                SignalSender::Bridged(tx, mailbox.clone()),
+                SignalReceiver::Bridged(rx, mailbox),
+            )
+        });
+        rendezvous
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RendezvousTimeoutError;
+    use std::thread;
+
+    #[test]
+    fn external_senders_keep_the_rendezvous_open() {
+        let (tx, rx) = mpsc::channel();
+        let external = tx.clone();
+        let mut rendezvous = Rendezvous::from_channel(tx, rx);
+        assert_eq!(rendezvous.remaining_guards(), 0);
+        let guard = rendezvous.fork_guard();
+        drop(guard);
+
+        external.send(()).unwrap();
+        assert_eq!(
+            rendezvous.rendezvous_timeout(Duration::from_millis(10)),
+            Err(RendezvousTimeoutError::Timeout)
+        );
+
+        thread::spawn(move || {
+            external.send(()).unwrap();
+            drop(external);
+        });
+        assert_eq!(
+            rendezvous.rendezvous_timeout(Duration::from_secs(5)),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn channel_without_external_senders_behaves_like_new() {
+        let (tx, rx) = mpsc::channel();
+        let rendezvous = Rendezvous::from_channel(tx, rx);
+        assert_eq!(rendezvous.remaining_guards(), 0);
+        let guard = rendezvous.fork_guard();
+        thread::spawn(move || drop(guard));
+        rendezvous.rendezvous();
+    }
+
+    #[test]
+    fn waiting_blocks_on_external_senders_without_guards() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        let (tx, rx) = mpsc::channel();
+        let external = tx.clone();
+        let rendezvous = Rendezvous::from_channel(tx, rx);
+        let dropped = Arc::new(AtomicBool::new(false));
+        thread::spawn({
+            let dropped = dropped.clone();
+            move || {
+                thread::sleep(Duration::from_millis(20));
+                dropped.store(true, Ordering::SeqCst);
+                drop(external);
+            }
+        });
+        rendezvous.rendezvous();
+        assert!(dropped.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn signals_are_received_alongside_external_messages() {
+        let (tx, rx) = mpsc::channel();
+        let external = tx.clone();
+        let mut rendezvous = Rendezvous::from_channel(tx, rx);
+        let failing = rendezvous.fork_guard();
+        let failing_id = failing.id();
+
+        external.send(()).unwrap();
+        thread::spawn(move || failing.fail("failed"));
+        let failure = rendezvous.rendezvous_fail_fast().unwrap_err();
+        assert_eq!(failure.guard(), failing_id);
+        drop(external);
+    }
+}
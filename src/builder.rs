@@ -0,0 +1,567 @@
+//! Configuring a [`Rendezvous`] before creating it.
+
+use crate::{ForkAfterWait, Rendezvous};
+#[cfg(any(feature = "log", feature = "tracing"))]
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Whether per-guard bookkeeping is skipped unless selected otherwise; see
+/// [`RendezvousBuilder::minimal`].
+const MINIMAL_BY_DEFAULT: bool = cfg!(all(not(debug_assertions), not(feature = "diagnostics")));
+
+/// Options a [`Rendezvous`] was built with.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct Options {
+    /// Whether per-guard bookkeeping is skipped, if selected explicitly; see
+    /// [`RendezvousBuilder::minimal`].
+    pub minimal: Option<bool>,
+    /// Whether the lifetimes of completed guards are retained; see
+    /// [`RendezvousBuilder::audit`].
+    pub audit: bool,
+    /// The maximum number of live guards and reservations; see
+    /// [`RendezvousBuilder::max_guards`].
+    pub max_guards: Option<usize>,
+    /// What forking does after the rendezvous was waited on; see
+    /// [`RendezvousBuilder::on_fork_after_wait`].
+    pub fork_after_wait: ForkAfterWait,
+    /// The number of pooled threads guarded tasks run on; see
+    /// [`RendezvousBuilder::thread_pool`].
+    pub thread_pool: Option<usize>,
+    /// The timeout of [`Rendezvous::rendezvous_or_default_timeout`]; see
+    /// [`RendezvousBuilder::default_timeout`].
+    pub default_timeout: Option<Duration>,
+    /// Whether dropping the rendezvous without waiting on it first is not reported; see
+    /// [`RendezvousBuilder::warn_on_drop`].
+    pub quiet_drop: bool,
+    /// Whether delivered values are released in guard id order; see
+    /// [`RendezvousBuilder::deterministic_order`].
+    pub deterministic_order: bool,
+    /// The number of guards expected to be forked; see
+    /// [`RendezvousBuilder::expected_guards`].
+    pub expected_guards: Option<usize>,
+    /// Whether deadlines are measured on `CLOCK_BOOTTIME`; see
+    /// [`RendezvousBuilder::boottime_deadlines`].
+    #[cfg(all(feature = "boottime", target_os = "linux"))]
+    pub boottime: bool,
+    /// The name reported in the global registry; see [`RendezvousBuilder::name`].
+    #[cfg(feature = "global-registry")]
+    pub name: Option<String>,
+    /// How long dropping the rendezvous waits before aborting the process; see
+    /// [`RendezvousBuilder::drop_timeout`].
+    #[cfg(feature = "fatal-leaks")]
+    pub drop_timeout: Option<Duration>,
+    /// The maximum number of per-guard trace messages per second; see
+    /// [`RendezvousBuilder::log_throttle`].
+    #[cfg(any(feature = "log", feature = "tracing"))]
+    pub log_throttle: Option<u32>,
+    /// The sorted upper bounds of the latency buckets guard lifetimes are logged in; see
+    /// [`RendezvousBuilder::latency_buckets`].
+    #[cfg(any(feature = "log", feature = "tracing"))]
+    pub latency_buckets: Option<Arc<[Duration]>>,
+    /// How long a wait may pass without completions before a stall is logged; see
+    /// [`RendezvousBuilder::stall_after`].
+    #[cfg(any(feature = "log", feature = "tracing"))]
+    pub stall_after: Option<Duration>,
+    /// How long a wait may block without completions before a deadlock report is logged; see
+    /// [`RendezvousBuilder::deadlock_threshold`].
+    #[cfg(feature = "deadlock-detection")]
+    pub deadlock_threshold: Option<Duration>,
+}
+
+impl Options {
+    /// Returns whether per-guard bookkeeping is skipped.
+    pub fn is_minimal(&self) -> bool {
+        self.minimal
+            .unwrap_or(MINIMAL_BY_DEFAULT && !self.requires_tracking())
+    }
+
+    /// Returns whether options were selected that imply full tracking unless the minimal mode
+    /// was selected explicitly.
+    fn requires_tracking(&self) -> bool {
+        #[cfg(any(feature = "log", feature = "tracing"))]
+        if self.latency_buckets.is_some() {
+            return true;
+        }
+        self.audit
+    }
+}
+
+/// A builder for configuring a [`Rendezvous`].
+///
+/// ## Example
+///
+/// ```
+/// use rendezvous::RendezvousBuilder;
+///
+/// let rendezvous = RendezvousBuilder::new().minimal().build();
+/// let guard = rendezvous.fork_guard();
+/// assert_eq!(rendezvous.remaining_guards(), 1);
+/// drop(guard);
+/// rendezvous.rendezvous();
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct RendezvousBuilder {
+    /// The options to build the rendezvous with.
+    options: Options,
+}
+
+impl RendezvousBuilder {
+    /// Creates a new builder using the same defaults as [`Rendezvous::new`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Selects the minimal mode, which skips all per-guard bookkeeping.
+    ///
+    /// In minimal mode, forking and dropping a guard only updates atomic counters and the
+    /// underlying channel, and does not allocate. Guard labels are discarded, and
+    /// introspection that requires per-guard records is unavailable: e.g.
+    /// [`Rendezvous::ramp_window`] and [`Rendezvous::last_wakeup_latency`] return `None`, and
+    /// timeout snapshots list no guards.
+    /// [`Rendezvous::remaining_guards`] and the rendezvous itself behave as usual.
+    ///
+    /// Release builds without the `diagnostics` feature use the minimal mode by default, unless
+    /// [full tracking](Self::full_tracking) or an option relying on it, such as
+    /// [auditing](Self::audit), is selected.
+    pub fn minimal(mut self) -> Self {
+        self.options.minimal = Some(true);
+        self
+    }
+
+    /// Selects full per-guard bookkeeping, the default in debug builds and with the
+    /// `diagnostics` feature. See [`RendezvousBuilder::minimal`].
+    pub fn full_tracking(mut self) -> Self {
+        self.options.minimal = Some(false);
+        self
+    }
+
+    /// Rate-limits the per-guard fork and drop trace messages to at most `max_per_sec` per second.
+    ///
+    /// Under high churn, tracing every guard floods the logs. With a throttle, excess messages
+    /// are suppressed and periodically coalesced into a summary such as
+    /// `Suppressed 4821 fork events and 4790 drop events in the last second`. The throttle
+    /// applies per rendezvous instance; other log messages are not affected.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use rendezvous::RendezvousBuilder;
+    ///
+    /// let rendezvous = RendezvousBuilder::new().log_throttle(100).build();
+    /// let guards: Vec<_> = (0..10_000).map(|_| rendezvous.fork_guard()).collect();
+    /// drop(guards);
+    /// rendezvous.rendezvous();
+    /// ```
+    #[cfg(any(feature = "log", feature = "tracing"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "log", feature = "tracing"))))]
+    pub fn log_throttle(mut self, max_per_sec: u32) -> Self {
+        self.options.log_throttle = Some(max_per_sec);
+        self
+    }
+
+    /// Retains the lifetimes of all completed guards for
+    /// [`Rendezvous::rendezvous_audited`].
+    ///
+    /// Auditing retains a record for every guard ever forked until the rendezvous is dropped or
+    /// [reset](Rendezvous::reset), so it is best suited for bounded workloads. It implies
+    /// [full tracking](Self::full_tracking), and has no effect if the [minimal](Self::minimal)
+    /// mode is selected explicitly.
+    pub fn audit(mut self) -> Self {
+        self.options.audit = true;
+        self
+    }
+
+    /// Limits the number of live guards and outstanding [reservations](Rendezvous::reserve) to
+    /// `max_guards`.
+    ///
+    /// [`Rendezvous::reserve`] returns `None` once the limit is reached. Forking a guard beyond
+    /// the limit, either off the rendezvous or off another guard, panics; use reservations to
+    /// check for capacity beforehand. Completed guards free their capacity again.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use rendezvous::RendezvousBuilder;
+    ///
+    /// let rendezvous = RendezvousBuilder::new().max_guards(1).build();
+    /// let guard = rendezvous.fork_guard();
+    /// assert!(rendezvous.reserve().is_none());
+    /// drop(guard);
+    /// assert!(rendezvous.reserve().is_some());
+    /// rendezvous.rendezvous();
+    /// ```
+    pub fn max_guards(mut self, max_guards: usize) -> Self {
+        self.options.max_guards = Some(max_guards);
+        self
+    }
+
+    /// Runs guarded tasks on a fixed pool of `size` threads instead of spawning a fresh thread
+    /// for every task.
+    ///
+    /// Tasks submitted using [`Rendezvous::submit_guarded`] and
+    /// [`Rendezvous::spawn_guarded_collecting`] are queued and run on the pool, each carrying
+    /// its guard; the rendezvous completes when all their guards are dropped, as usual. This
+    /// amortizes thread creation for high-frequency fan-out.
+    ///
+    /// The pool threads are spawned when the first task is submitted and are reused across
+    /// [rounds](Rendezvous::reset). Dropping the rendezvous closes the pool: the threads finish
+    /// the tasks already queued and then exit, without being joined.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `size` is zero.
+    pub fn thread_pool(mut self, size: usize) -> Self {
+        assert!(size > 0, "the thread pool requires at least one thread");
+        self.options.thread_pool = Some(size);
+        self
+    }
+
+    /// Selects what forking a guard off the rendezvous does after it was waited on.
+    ///
+    /// By default, forking after a wait panics. Libraries that prefer robustness over strictness
+    /// can select [`ForkAfterWait::Warn`], which logs a warning and returns a detached guard
+    /// that does not keep the rendezvous open, or [`ForkAfterWait::Error`], which makes
+    /// [`Rendezvous::try_fork_guard`] return an error.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use rendezvous::{ForkAfterWait, RendezvousBuilder};
+    ///
+    /// let mut rendezvous = RendezvousBuilder::new()
+    ///     .on_fork_after_wait(ForkAfterWait::Warn)
+    ///     .build();
+    /// rendezvous.rendezvous_timeout(Duration::from_secs(1)).unwrap();
+    ///
+    /// let guard = rendezvous.fork_guard();
+    /// assert!(guard.is_detached());
+    /// ```
+    pub fn on_fork_after_wait(mut self, policy: ForkAfterWait) -> Self {
+        self.options.fork_after_wait = policy;
+        self
+    }
+
+    /// Sets the timeout used by [`Rendezvous::rendezvous_or_default_timeout`].
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use rendezvous::{RendezvousBuilder, RendezvousTimeoutError};
+    ///
+    /// let mut rendezvous = RendezvousBuilder::new()
+    ///     .default_timeout(Duration::from_millis(10))
+    ///     .build();
+    /// let guard = rendezvous.fork_guard();
+    /// assert_eq!(
+    ///     rendezvous.rendezvous_or_default_timeout(),
+    ///     Err(RendezvousTimeoutError::Timeout)
+    /// );
+    /// drop(guard);
+    /// ```
+    pub fn default_timeout(mut self, timeout: Duration) -> Self {
+        self.options.default_timeout = Some(timeout);
+        self
+    }
+
+    /// Selects whether dropping the rendezvous without waiting on it first is reported.
+    ///
+    /// By default, debug builds with the `log` feature log an error in that case, since it
+    /// usually indicates that the rendezvous method was forgotten. Passing `false` suppresses
+    /// the error where relying on the implicit wait in [`Drop`] is intended.
+    pub fn warn_on_drop(mut self, warn: bool) -> Self {
+        self.options.quiet_drop = !warn;
+        self
+    }
+
+    /// Selects whether the values delivered by guards are released in guard id order,
+    /// regardless of the order the guards complete in.
+    ///
+    /// This makes the sequence of values collected by a
+    /// [`CollectingRendezvous`](crate::CollectingRendezvous) reproducible, e.g. in tests. The
+    /// value of a guard is buffered until all guards with lower ids completed; guards dropped
+    /// without a value only advance the order. Guards that do not report their completion, such as ones
+    /// completed using [`Rendezvous::complete_armed`], leave a gap that holds back all later
+    /// values. Waiting still completes once all guards are dropped, at which point any values
+    /// still buffered are released in id order.
+    pub fn deterministic_order(mut self, enabled: bool) -> Self {
+        self.options.deterministic_order = enabled;
+        self
+    }
+
+    /// Measures the deadline of [`Rendezvous::rendezvous_deadline`] on a clock that keeps
+    /// running while the system is suspended.
+    ///
+    /// [`Instant`](std::time::Instant) uses `CLOCK_MONOTONIC` on Linux, which stops while the
+    /// system is suspended, so a deadline 30 seconds ahead effectively pauses across a laptop
+    /// sleep. With this option, the deadline is translated to `CLOCK_BOOTTIME`, which includes
+    /// suspended time, and checked against it at least once per second; a deadline that passed
+    /// during a suspend is thus noticed within a second of resuming. Other timeouts, such as
+    /// the one of [`Rendezvous::rendezvous_timeout`], are not affected.
+    #[cfg(all(feature = "boottime", target_os = "linux"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "boottime", target_os = "linux"))))]
+    pub fn boottime_deadlines(mut self) -> Self {
+        self.options.boottime = true;
+        self
+    }
+
+    /// Aborts the process if guards are still outstanding `timeout` after the rendezvous started
+    /// waiting for them when dropped.
+    ///
+    /// Before aborting, the outstanding guards are written to standard error and, with the
+    /// `log` feature, logged as an error. Aborting produces a core dump where enabled, turning
+    /// a silent hang caused by a leaked guard into an actionable crash. Explicit waits, such as
+    /// [`Rendezvous::rendezvous_timeout`], are not affected.
+    ///
+    /// <div class="warning">
+    /// This is deliberately aggressive: the whole process is aborted without unwinding, and
+    /// no destructors run. Only use it where a crash is preferable to a hang.
+    /// </div>
+    #[cfg(feature = "fatal-leaks")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "fatal-leaks")))]
+    pub fn drop_timeout(mut self, timeout: Duration) -> Self {
+        self.options.drop_timeout = Some(timeout);
+        self
+    }
+
+    /// Logs the latency bucket each guard's lifetime, from its fork to its drop, fell into.
+    ///
+    /// The `bounds` separate the buckets; e.g. bounds of 10 ms and 100 ms define the buckets
+    /// `<10ms`, `10-100ms` and `>=100ms`. When a guard is dropped, a debug message naming its
+    /// bucket is logged, which allows deriving coarse latency histograms from logs alone.
+    /// The bounds are sorted and deduplicated. This implies
+    /// [full tracking](Self::full_tracking); if the [minimal](Self::minimal) mode is selected
+    /// explicitly, fork times are not tracked, so nothing is logged.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use rendezvous::RendezvousBuilder;
+    ///
+    /// let rendezvous = RendezvousBuilder::new()
+    ///     .latency_buckets(vec![Duration::from_millis(10), Duration::from_millis(100)])
+    ///     .build();
+    /// rendezvous.fork_guard().completed();
+    /// rendezvous.rendezvous();
+    /// ```
+    #[cfg(any(feature = "log", feature = "tracing"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "log", feature = "tracing"))))]
+    pub fn latency_buckets(mut self, mut bounds: Vec<Duration>) -> Self {
+        bounds.sort_unstable();
+        bounds.dedup();
+        self.options.latency_buckets = Some(bounds.into());
+        self
+    }
+
+    /// Names the rendezvous, identifying it in the snapshots returned by
+    /// [`dump_all`](crate::dump_all).
+    #[cfg(feature = "global-registry")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "global-registry")))]
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.options.name = Some(name.into());
+        self
+    }
+
+    /// Logs a warning whenever no guard completed for `window` while blocking in a rendezvous,
+    /// despite guards remaining.
+    ///
+    /// This distinguishes a steadily progressing rendezvous from a stalled one; see also
+    /// [`Rendezvous::completion_rate`]. The warning is logged once per stall, and again if the
+    /// rendezvous stalls anew after a completion. It applies to [`Rendezvous::rendezvous`],
+    /// [`Rendezvous::rendezvous_ref`] and dropping the rendezvous. A
+    /// [minimal](Self::minimal) rendezvous does not track completions, so stalls are measured
+    /// from the start of the wait.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use rendezvous::RendezvousBuilder;
+    ///
+    /// let rendezvous = RendezvousBuilder::new()
+    ///     .stall_after(Duration::from_secs(30))
+    ///     .build();
+    /// rendezvous.fork_guard().completed();
+    /// rendezvous.rendezvous();
+    /// ```
+    #[cfg(any(feature = "log", feature = "tracing"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "log", feature = "tracing"))))]
+    pub fn stall_after(mut self, window: Duration) -> Self {
+        self.options.stall_after = Some(window);
+        self
+    }
+
+    /// Sets the number of guards expected to be forked, which
+    /// [`Rendezvous::rendezvous_verified`] checks. See [`Rendezvous::expecting`].
+    pub fn expected_guards(mut self, n: usize) -> Self {
+        self.options.expected_guards = Some(n);
+        self
+    }
+
+    /// Watches blocking waits from a background thread, logging a deadlock report as an
+    /// error whenever a wait blocked for `threshold` without guard completions while guards
+    /// remain.
+    ///
+    /// The report lists the outstanding guards with their labels, ages, the threads they were
+    /// forked on and the backtraces of their forks, and flags guards forked on the waiting
+    /// thread itself, which is the classic deadlock of forking and waiting on the same thread.
+    /// Like [`RendezvousBuilder::stall_after`], this applies to [`Rendezvous::rendezvous`],
+    /// [`Rendezvous::rendezvous_ref`] and dropping the rendezvous. The report is a diagnostics
+    /// aid only: the wait keeps blocking. A [minimal](Self::minimal) rendezvous lists no
+    /// guards.
+    ///
+    /// Since capturing a backtrace on every fork is expensive, this only takes effect in
+    /// builds with debug assertions; in release builds, the threshold is ignored.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use rendezvous::RendezvousBuilder;
+    ///
+    /// let rendezvous = RendezvousBuilder::new()
+    ///     .deadlock_threshold(Duration::from_secs(10))
+    ///     .build();
+    /// rendezvous.fork_guard().completed();
+    /// rendezvous.rendezvous();
+    /// ```
+    #[cfg(feature = "deadlock-detection")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "deadlock-detection")))]
+    pub fn deadlock_threshold(mut self, threshold: Duration) -> Self {
+        self.options.deadlock_threshold = Some(threshold);
+        self
+    }
+
+    /// Creates the configured [`Rendezvous`].
+    pub fn build(self) -> Rendezvous {
+        Rendezvous::from_parts((), self.options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{RendezvousError, RendezvousTimeoutError};
+    use std::thread;
+
+    #[test]
+    fn minimal_rendezvous_synchronizes() {
+        let rendezvous = RendezvousBuilder::new().minimal().build();
+
+        let guard = rendezvous.fork_guard();
+        let nested = guard.fork();
+        assert_eq!(rendezvous.remaining_guards(), 2);
+
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(50));
+            drop(nested);
+            drop(guard);
+        });
+
+        rendezvous.rendezvous();
+    }
+
+    #[test]
+    fn minimal_rendezvous_skips_bookkeeping() {
+        let mut rendezvous = RendezvousBuilder::new().minimal().build();
+        let guard = rendezvous.fork_guard_labeled("skipped");
+        assert_eq!(rendezvous.ramp_window(), None);
+
+        let result = rendezvous.rendezvous_timeout_snapshot(Duration::from_millis(10));
+        assert_eq!(
+            result,
+            Err(RendezvousError::Timeout {
+                outstanding: Vec::new()
+            })
+        );
+        drop(guard);
+        assert_eq!(rendezvous.remaining_guards(), 0);
+        rendezvous.rendezvous_timeout(Duration::ZERO).unwrap();
+        assert_eq!(rendezvous.last_wakeup_latency(), None);
+    }
+
+    #[test]
+    fn minimal_rendezvous_supports_keyed_and_weighted_guards() {
+        let mut rendezvous = RendezvousBuilder::new().minimal().build();
+        let plain = rendezvous.fork_guard();
+        let keyed = rendezvous.fork_guard_keyed("key").unwrap();
+        assert!(rendezvous.fork_guard_keyed("key").is_none());
+        drop(keyed);
+        assert!(rendezvous.fork_guard_keyed("key").is_some());
+
+        let heavy = rendezvous.fork_guard_weighted(3);
+        drop(heavy);
+        assert_eq!(rendezvous.rendezvous_weight_quorum(4), Ok(()));
+        drop(plain);
+    }
+
+    #[test]
+    fn default_bookkeeping_follows_the_build_configuration() {
+        for (mut rendezvous, minimal) in [
+            (Rendezvous::new(), MINIMAL_BY_DEFAULT),
+            (RendezvousBuilder::new().audit().build(), false),
+            (RendezvousBuilder::new().full_tracking().build(), false),
+            (RendezvousBuilder::new().minimal().build(), true),
+        ] {
+            let guard = rendezvous.fork_guard_labeled("tracked");
+            let Err(RendezvousError::Timeout { outstanding }) =
+                rendezvous.rendezvous_timeout_snapshot(Duration::from_millis(10))
+            else {
+                panic!("expected a timeout");
+            };
+            assert_eq!(outstanding.is_empty(), minimal);
+
+            thread::spawn(move || drop(guard));
+            assert_eq!(
+                rendezvous.rendezvous_timeout(Duration::from_secs(5)),
+                Ok(())
+            );
+            assert_eq!(rendezvous.remaining_guards(), 0);
+        }
+    }
+
+    #[test]
+    fn default_timeout_applies_to_parameterless_wait() {
+        let mut rendezvous = RendezvousBuilder::new()
+            .default_timeout(Duration::from_millis(10))
+            .build();
+        let guard = rendezvous.fork_guard();
+        assert_eq!(
+            rendezvous.rendezvous_or_default_timeout(),
+            Err(RendezvousTimeoutError::Timeout)
+        );
+
+        thread::spawn(move || drop(guard));
+        let mut unbounded = RendezvousBuilder::new().build();
+        let guard = unbounded.fork_guard();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(20));
+            drop(guard);
+        });
+        assert_eq!(unbounded.rendezvous_or_default_timeout(), Ok(()));
+        assert_eq!(unbounded.remaining_guards(), 0);
+    }
+
+    #[test]
+    #[cfg(all(debug_assertions, feature = "log"))]
+    fn warn_on_drop_can_be_disabled() {
+        crate::test_log::init();
+
+        let mut loud = Rendezvous::with_context(String::from("loud-drop-8d21"));
+        loud.options = RendezvousBuilder::new().options;
+        drop(loud);
+        let mut quiet = Rendezvous::with_context(String::from("quiet-drop-8d21"));
+        quiet.options = RendezvousBuilder::new().warn_on_drop(false).options;
+        drop(quiet);
+
+        let not_invoked = |pattern| {
+            crate::test_log::messages_containing(pattern)
+                .iter()
+                .any(|(_, message)| message.contains("Rendezvous method not invoked"))
+        };
+        assert!(not_invoked("loud-drop-8d21"));
+        assert!(!not_invoked("quiet-drop-8d21"));
+    }
+}
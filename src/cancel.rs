@@ -0,0 +1,169 @@
+//! Cooperative cancellation of outstanding guards.
+
+use crate::{Rendezvous, RendezvousGuard};
+use std::sync::atomic::Ordering;
+
+impl<C> Rendezvous<C> {
+    /// Cancels the rendezvous, signaling all guard holders to bail out.
+    ///
+    /// Cancellation is cooperative, not preemptive: guard holders observe it using
+    /// [`RendezvousGuard::is_cancelled`] and are expected to stop their work and drop their
+    /// guards. The original sender is released, so no further guards can be forked off the
+    /// rendezvous; a subsequent rendezvous still waits for all guards to be dropped.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::thread;
+    /// use std::time::Duration;
+    /// use rendezvous::Rendezvous;
+    ///
+    /// let mut rendezvous = Rendezvous::new();
+    /// let guard = rendezvous.fork_guard();
+    /// thread::spawn(move || {
+    ///     while !guard.is_cancelled() {
+    ///         thread::sleep(Duration::from_millis(10));
+    ///     }
+    /// });
+    ///
+    /// rendezvous.cancel();
+    /// rendezvous.rendezvous();
+    /// ```
+    pub fn cancel(&mut self) {
+        #[cfg(any(feature = "log", feature = "tracing"))]
+        {
+            debug!("Cancelling rendezvous{}", self.log_context());
+        }
+        if let Some(channel) = self.release() {
+            channel.shared.cancel();
+        }
+    }
+
+    /// Returns whether the rendezvous was cancelled using [`Rendezvous::cancel`].
+    pub fn is_cancelled(&self) -> bool {
+        self.channel
+            .get()
+            .is_some_and(|channel| channel.shared.cancelled.load(Ordering::Acquire))
+    }
+}
+
+impl crate::Shared {
+    /// Sets the cancellation flag and wakes all tasks waiting for it.
+    pub(crate) fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Release);
+        #[cfg(feature = "tokio")]
+        self.cancel_notify.notify_waiters();
+    }
+}
+
+impl RendezvousGuard {
+    /// Returns whether the owning rendezvous was cancelled using [`Rendezvous::cancel`].
+    ///
+    /// Workers can poll this to stop their work early.
+    pub fn is_cancelled(&self) -> bool {
+        self.shared.cancelled.load(Ordering::Acquire)
+    }
+
+    /// Resolves once the owning rendezvous is cancelled using [`Rendezvous::cancel`].
+    ///
+    /// This allows asynchronous workers to select on cancellation:
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use rendezvous::Rendezvous;
+    ///
+    /// # tokio_test::block_on(async {
+    /// let mut rendezvous = Rendezvous::new();
+    /// let guard = rendezvous.fork_guard();
+    /// rendezvous.cancel();
+    ///
+    /// tokio::select! {
+    ///     _ = guard.cancelled() => { /* clean up */ }
+    ///     _ = std::future::pending::<()>() => unreachable!(),
+    /// }
+    /// # });
+    /// ```
+    #[cfg(feature = "tokio")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+    pub async fn cancelled(&self) {
+        loop {
+            let mut notified = std::pin::pin!(self.shared.cancel_notify.notified());
+            notified.as_mut().enable();
+            if self.is_cancelled() {
+                return;
+            }
+            notified.await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn guards_observe_cancellation() {
+        let mut rendezvous = Rendezvous::new();
+        let guard = rendezvous.fork_guard();
+        assert!(!guard.is_cancelled());
+        assert!(!rendezvous.is_cancelled());
+
+        let worker = thread::spawn(move || {
+            while !guard.is_cancelled() {
+                thread::sleep(Duration::from_millis(5));
+            }
+        });
+
+        rendezvous.cancel();
+        assert!(rendezvous.is_cancelled());
+        rendezvous.rendezvous();
+        worker.join().unwrap();
+    }
+
+    #[test]
+    fn rendezvous_waits_for_cancelled_guards_to_drop() {
+        use std::sync::atomic::AtomicBool;
+        use std::sync::Arc;
+
+        let mut rendezvous = Rendezvous::new();
+        let guard = rendezvous.fork_guard();
+        let cleaned_up = Arc::new(AtomicBool::new(false));
+        thread::spawn({
+            let cleaned_up = cleaned_up.clone();
+            move || {
+                while !guard.is_cancelled() {
+                    thread::yield_now();
+                }
+                // Bailing out still takes a while, e.g. to roll back partial work.
+                thread::sleep(Duration::from_millis(20));
+                cleaned_up.store(true, Ordering::SeqCst);
+                drop(guard);
+            }
+        });
+
+        rendezvous.cancel();
+        rendezvous.rendezvous();
+        assert!(cleaned_up.load(Ordering::SeqCst));
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn cancelled_resolves_in_select() {
+        let mut rendezvous = Rendezvous::new();
+        let guard = rendezvous.fork_guard();
+
+        let worker = tokio::spawn(async move {
+            tokio::select! {
+                _ = guard.cancelled() => true,
+                _ = tokio::time::sleep(Duration::from_secs(10)) => false,
+            }
+        });
+
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        rendezvous.cancel();
+        assert!(worker.await.unwrap(), "the cancellation branch runs");
+        rendezvous.rendezvous_async().await.unwrap();
+    }
+}
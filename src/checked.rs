@@ -0,0 +1,88 @@
+//! Surfacing guards that were dropped while their thread was panicking.
+
+use crate::{lock, Rendezvous, RendezvousError};
+
+impl<C> Rendezvous<C> {
+    /// Executes the rendezvous process, then fails if any guard was dropped while its thread
+    /// was panicking.
+    ///
+    /// A guard held by a panicking thread is dropped during unwinding, which completes it just
+    /// like a regular drop, so [`Rendezvous::rendezvous`] cannot tell a worker that died
+    /// mid-task from one that finished its work. This waits for all guards the same way, then
+    /// returns [`RendezvousError::GuardPanicked`] listing the guards dropped while panicking.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::thread;
+    /// use rendezvous::{Rendezvous, RendezvousError};
+    ///
+    /// let rendezvous = Rendezvous::new();
+    /// let guard = rendezvous.fork_guard();
+    /// let id = guard.id();
+    /// thread::spawn(move || {
+    ///     let _guard = guard;
+    ///     panic!("worker died");
+    /// });
+    ///
+    /// let result = rendezvous.rendezvous_checked();
+    /// assert_eq!(result, Err(RendezvousError::GuardPanicked { panicked: vec![id] }));
+    /// ```
+    pub fn rendezvous_checked(mut self) -> Result<(), RendezvousError> {
+        let _span = self.wait_span("rendezvous_checked");
+        self.rendezvous_internal();
+        let mut panicked = self
+            .channel
+            .get()
+            .map(|channel| lock(&channel.shared.panicked).clone())
+            .unwrap_or_default();
+        if panicked.is_empty() {
+            return Ok(());
+        }
+        panicked.sort();
+        #[cfg(any(feature = "log", feature = "tracing"))]
+        {
+            warn!(
+                "{} rendezvous guards were dropped while panicking{}",
+                panicked.len(),
+                self.log_context()
+            );
+        }
+        Err(RendezvousError::GuardPanicked { panicked })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn regular_drops_pass_the_check() {
+        let rendezvous = Rendezvous::new();
+        let guard = rendezvous.fork_guard();
+        thread::spawn(move || drop(guard));
+        assert_eq!(rendezvous.rendezvous_checked(), Ok(()));
+    }
+
+    #[test]
+    fn panicking_guards_fail_the_check() {
+        let rendezvous = Rendezvous::new();
+        let regular = rendezvous.fork_guard();
+        let first = rendezvous.fork_guard();
+        let second = first.fork();
+        let ids = vec![first.id(), second.id()];
+        thread::spawn(move || drop(regular));
+        for guard in [second, first] {
+            thread::spawn(move || {
+                let _guard = guard;
+                panic!("worker died");
+            });
+        }
+
+        let error = rendezvous.rendezvous_checked().unwrap_err();
+        assert_eq!(error, RendezvousError::GuardPanicked { panicked: ids });
+        assert_eq!(error.outstanding_tree(), None);
+        assert_eq!(error.to_string(), "2 guards panicked: #1, #2");
+    }
+}
@@ -0,0 +1,132 @@
+//! Exporting guard lifetimes in the Chrome Tracing format.
+
+use crate::{Rendezvous, Shared};
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+impl Shared {
+    /// Renders the lifetimes of all completed guards as a Chrome Tracing JSON document.
+    fn chrome_trace(&self) -> String {
+        let timings = self.audits();
+        let origin = timings.iter().map(|timing| timing.forked_at).min();
+
+        let mut json = String::from("{\"traceEvents\":[");
+        for (index, timing) in timings.iter().enumerate() {
+            let origin = origin.unwrap_or(timing.forked_at);
+            let start = timing.forked_at.saturating_duration_since(origin);
+            let duration = timing.lifetime();
+            let mut name = timing.id.to_string();
+            if let Some(label) = &timing.label {
+                write!(name, " {label}").ok();
+            }
+            if index > 0 {
+                json.push(',');
+            }
+            write!(
+                json,
+                "{{\"name\":\"{}\",\"cat\":\"guard\",\"ph\":\"X\",\"ts\":{},\"dur\":{},\"pid\":1,\"tid\":{}}}",
+                escape(&name),
+                start.as_micros(),
+                duration.as_micros(),
+                timing.id.as_u64()
+            )
+            .ok();
+        }
+        json.push_str("],\"displayTimeUnit\":\"ms\"}");
+        json
+    }
+}
+
+/// Escapes `value` for use in a JSON string.
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            c if c.is_control() => {
+                write!(escaped, "\\u{:04x}", u32::from(c)).ok();
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+impl<C> Rendezvous<C> {
+    /// Executes the rendezvous process, then writes a Chrome Tracing JSON file to `path` with
+    /// one duration event per guard, spanning from its fork to its completion.
+    ///
+    /// The file can be opened in `chrome://tracing` or [Perfetto](https://ui.perfetto.dev) to
+    /// visualize how the work of the guards overlapped. Each guard is shown on its own track,
+    /// named after its identifier and label. Timestamps are relative to the first fork.
+    /// A [minimal](crate::RendezvousBuilder::minimal) rendezvous does not track guards, so its
+    /// trace contains no events.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::path::Path;
+    /// use rendezvous::Rendezvous;
+    ///
+    /// let rendezvous = Rendezvous::new();
+    /// let guard = rendezvous.fork_guard_labeled("worker");
+    /// drop(guard);
+    /// rendezvous.rendezvous_chrome_trace(Path::new("rendezvous.json")).unwrap();
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "chrome-trace")))]
+    pub fn rendezvous_chrome_trace(mut self, path: &Path) -> io::Result<()> {
+        let _span = self.wait_span("rendezvous_chrome_trace");
+        let shared = self.channel.get().map(|channel| channel.shared.clone());
+        self.rendezvous_internal();
+        let json = match shared {
+            Some(shared) => shared.chrome_trace(),
+            None => Shared::default().chrome_trace(),
+        };
+        fs::write(path, json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn trace_contains_duration_events() {
+        let rendezvous = Rendezvous::new();
+        let first = rendezvous.fork_guard_labeled("first \"stage\"");
+        thread::sleep(Duration::from_millis(5));
+        let second = rendezvous.fork_guard();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(5));
+            drop(first);
+            thread::sleep(Duration::from_millis(5));
+            drop(second);
+        });
+
+        let path = std::env::temp_dir().join(format!(
+            "rendezvous-chrome-trace-{}.json",
+            std::process::id()
+        ));
+        rendezvous.rendezvous_chrome_trace(&path).unwrap();
+        let json = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).ok();
+
+        assert!(json.starts_with("{\"traceEvents\":[{"));
+        assert!(json.ends_with("}],\"displayTimeUnit\":\"ms\"}"));
+        assert_eq!(json.matches("\"ph\":\"X\"").count(), 2);
+        assert!(json.contains("\"name\":\"#0 first \\\"stage\\\"\""));
+        assert!(json.contains("\"ts\":0,"));
+        assert!(json.contains("\"name\":\"#1\""));
+        assert!(json.contains("\"tid\":1}"));
+    }
+
+    #[test]
+    fn escapes_control_characters() {
+        assert_eq!(escape("a\\b\n"), "a\\\\b\\u000a");
+    }
+}
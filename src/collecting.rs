@@ -0,0 +1,261 @@
+//! Completing guards with values and processing the values as they arrive.
+
+use crate::ordered::Reorder;
+use crate::{GuardId, Rendezvous, RendezvousBuilder, RendezvousGuard, Signal};
+use std::any::Any;
+use std::fmt::{Debug, Formatter};
+use std::marker::PhantomData;
+use std::time::Instant;
+
+/// A rendezvous whose guards deliver a value of type `T` on completion.
+///
+/// This is a [`Rendezvous`] collecting the values delivered using
+/// [`CollectingGuard::complete_with`]. Since the value type is part of both the rendezvous and
+/// its guards, a guard cannot deliver a value of another type. Guards dropped without
+/// delivering a value complete the rendezvous as usual but contribute no value.
+///
+/// ## Example
+///
+/// ```
+/// use std::thread;
+/// use rendezvous::CollectingRendezvous;
+///
+/// let rendezvous = CollectingRendezvous::new();
+/// let guard = rendezvous.fork_guard();
+/// thread::spawn(move || guard.complete_with("payload"));
+/// let silent = rendezvous.fork_guard();
+/// thread::spawn(move || drop(silent));
+///
+/// assert_eq!(rendezvous.rendezvous(), vec!["payload"]);
+/// ```
+pub struct CollectingRendezvous<T> {
+    /// The rendezvous the guards are forked off.
+    rendezvous: Rendezvous,
+    /// The type of the delivered values.
+    values: PhantomData<fn() -> T>,
+}
+
+/// A guard forked off a [`CollectingRendezvous`], delivering a value of type `T` on completion.
+///
+/// Dropping the guard without calling [`CollectingGuard::complete_with`] completes it without
+/// a value.
+pub struct CollectingGuard<T> {
+    /// The underlying guard.
+    guard: RendezvousGuard,
+    /// The type of the delivered value.
+    value: PhantomData<fn(T)>,
+}
+
+impl<T: Send + 'static> CollectingRendezvous<T> {
+    /// Creates a new collecting rendezvous using the default options.
+    pub fn new() -> Self {
+        RendezvousBuilder::new().build_collecting()
+    }
+
+    /// Forks a guard off the rendezvous; see [`Rendezvous::fork_guard`].
+    pub fn fork_guard(&self) -> CollectingGuard<T> {
+        CollectingGuard {
+            guard: self.rendezvous.fork_guard(),
+            value: PhantomData,
+        }
+    }
+
+    /// Returns the number of guards that were forked but not yet dropped.
+    pub fn remaining_guards(&self) -> usize {
+        self.rendezvous.remaining_guards()
+    }
+
+    /// Executes the rendezvous process, invoking `f` with every delivered value the moment it
+    /// arrives.
+    ///
+    /// Blocks until all guards are dropped, just like [`Rendezvous::rendezvous`]. The callback
+    /// is invoked on the waiting thread in the order the values arrived, with the identifier of
+    /// the delivering guard. Values are queued while the callback runs, so a slow callback
+    /// delays the processing of later values but never blocks the guards. With
+    /// [`RendezvousBuilder::deterministic_order`], the values are passed in guard id order
+    /// instead.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::thread;
+    /// use rendezvous::CollectingRendezvous;
+    ///
+    /// let rendezvous = CollectingRendezvous::new();
+    /// for i in 0..3 {
+    ///     let guard = rendezvous.fork_guard();
+    ///     thread::spawn(move || guard.complete_with(i * 10));
+    /// }
+    ///
+    /// let mut sum = 0;
+    /// rendezvous.rendezvous_each(|_id, value| sum += value);
+    /// assert_eq!(sum, 30);
+    /// ```
+    pub fn rendezvous_each(mut self, f: impl FnMut(GuardId, T)) {
+        self.rendezvous.for_each_value(f);
+    }
+
+    /// Executes the rendezvous process and returns all delivered values, in the order they
+    /// arrived; see [`CollectingRendezvous::rendezvous_each`].
+    pub fn rendezvous(self) -> Vec<T> {
+        let mut values = Vec::new();
+        self.rendezvous_each(|_id, value| values.push(value));
+        values
+    }
+}
+
+impl<T: Send + 'static> Default for CollectingRendezvous<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Debug for CollectingRendezvous<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("CollectingRendezvous")
+            .field(&self.rendezvous)
+            .finish()
+    }
+}
+
+impl<T: Send + 'static> CollectingGuard<T> {
+    /// Returns the identifier of this guard; see [`RendezvousGuard::id`].
+    pub fn id(&self) -> GuardId {
+        self.guard.id()
+    }
+
+    /// Forks another guard off this guard; see [`RendezvousGuard::fork`].
+    pub fn fork(&self) -> Self {
+        Self {
+            guard: self.guard.fork(),
+            value: PhantomData,
+        }
+    }
+
+    /// Completes the guard, delivering `value` to the owning [`CollectingRendezvous`].
+    pub fn complete_with(self, value: T) {
+        self.guard.deliver(value);
+    }
+}
+
+impl<T> Debug for CollectingGuard<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("CollectingGuard").field(&self.guard).finish()
+    }
+}
+
+impl RendezvousBuilder {
+    /// Creates the configured [`CollectingRendezvous`].
+    pub fn build_collecting<T: Send + 'static>(self) -> CollectingRendezvous<T> {
+        CollectingRendezvous {
+            rendezvous: self.build(),
+            values: PhantomData,
+        }
+    }
+}
+
+impl RendezvousGuard {
+    /// Completes the guard, delivering `value` to the owning [`Rendezvous`].
+    pub(crate) fn deliver<T: Send + 'static>(self, value: T) {
+        if let Some(tx) = self.sender() {
+            tx.send(Signal::Value(self.id, Box::new(value)));
+        }
+    }
+}
+
+impl<C> Rendezvous<C> {
+    /// Executes the rendezvous process, invoking `f` with every value delivered using
+    /// [`RendezvousGuard::deliver`]; see [`CollectingRendezvous::rendezvous_each`].
+    ///
+    /// All values must be of type `T`, which [`CollectingGuard`] guarantees.
+    pub(crate) fn for_each_value<T: 'static>(&mut self, mut f: impl FnMut(GuardId, T)) {
+        let started = Instant::now();
+        if let Some(channel) = self.release() {
+            if channel.shared.options.deterministic_order {
+                let mut reorder = Reorder::default();
+                while let Ok(signal) = channel.recv() {
+                    match signal {
+                        Signal::Value(id, value) => reorder.value(id, downcast(value)),
+                        Signal::Completed(id) => reorder.completed(id),
+                        Signal::Ping(_) | Signal::Failed(_) => continue,
+                    }
+                    reorder.release(&mut f);
+                }
+                reorder.flush(&mut f);
+            } else {
+                while let Ok(signal) = channel.recv() {
+                    if let Signal::Value(id, value) = signal {
+                        f(id, downcast(value));
+                    }
+                }
+            }
+        }
+        self.record_wait(started);
+    }
+}
+
+/// Recovers a value delivered using [`RendezvousGuard::deliver`].
+fn downcast<T: 'static>(value: Box<dyn Any + Send>) -> T {
+    *value
+        .downcast::<T>()
+        .expect("typed guards deliver values of the collected type")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc;
+    use std::thread;
+
+    #[test]
+    fn values_are_processed_as_they_arrive() {
+        let rendezvous = CollectingRendezvous::new();
+        let first = rendezvous.fork_guard();
+        let second = rendezvous.fork_guard();
+        let ids = (first.id(), second.id());
+
+        // The second value is only delivered once the first one was processed.
+        let (processed_tx, processed_rx) = mpsc::channel();
+        thread::spawn(move || {
+            first.complete_with("first");
+            processed_rx.recv().unwrap();
+            second.complete_with("second");
+        });
+
+        let mut received = Vec::new();
+        rendezvous.rendezvous_each(|id, name| {
+            received.push((id, name));
+            // The worker is gone once it delivered the last value.
+            processed_tx.send(()).ok();
+        });
+        assert_eq!(received, vec![(ids.0, "first"), (ids.1, "second")]);
+    }
+
+    #[test]
+    fn collected_values_follow_arrival_order() {
+        let rendezvous = CollectingRendezvous::new();
+        let guards: Vec<_> = (1..=3).map(|_| rendezvous.fork_guard()).collect();
+        let silent = rendezvous.fork_guard();
+        thread::spawn(move || {
+            let mut guards = guards.into_iter();
+            let (one, two, three) = (guards.next(), guards.next(), guards.next());
+            two.unwrap().complete_with(2_u32);
+            drop(silent);
+            three.unwrap().complete_with(3);
+            one.unwrap().complete_with(1);
+        });
+
+        assert_eq!(rendezvous.rendezvous(), vec![2, 3, 1]);
+    }
+
+    #[test]
+    fn forked_guards_deliver_values() {
+        let rendezvous = CollectingRendezvous::new();
+        let guard = rendezvous.fork_guard();
+        let forked = guard.fork();
+        assert_eq!(rendezvous.remaining_guards(), 2);
+        guard.complete_with(1);
+        forked.complete_with(2);
+        assert_eq!(rendezvous.rendezvous(), vec![1, 2]);
+    }
+}
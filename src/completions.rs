@@ -0,0 +1,170 @@
+//! Observing guards completing one at a time.
+
+use crate::Rendezvous;
+use std::iter::FusedIterator;
+use std::sync::atomic::Ordering;
+use std::time::Instant;
+
+/// An iterator yielding once for every completed guard; see [`Rendezvous::completions`].
+#[derive(Debug)]
+pub struct Completions<'a, C = ()> {
+    /// The rendezvous whose guards are observed.
+    rendezvous: &'a mut Rendezvous<C>,
+    /// The number of completions yielded so far.
+    yielded: usize,
+    /// When the iteration started.
+    started: Instant,
+    /// Whether all guards completed.
+    done: bool,
+}
+
+impl<C> Completions<'_, C> {
+    /// Returns the number of guards that were forked but not yet dropped.
+    ///
+    /// Since the iterator borrows the rendezvous, this stands in for
+    /// [`Rendezvous::remaining_guards`] while iterating.
+    pub fn remaining_guards(&self) -> usize {
+        self.rendezvous.remaining_guards()
+    }
+
+    /// Returns the number of guards that completed but were not yet yielded.
+    fn unyielded(&self) -> usize {
+        self.rendezvous.channel.get().map_or(0, |channel| {
+            channel.shared.completed.load(Ordering::SeqCst) - self.yielded
+        })
+    }
+}
+
+impl<C> Iterator for Completions<'_, C> {
+    type Item = ();
+
+    fn next(&mut self) -> Option<()> {
+        loop {
+            if self.unyielded() > 0 {
+                self.yielded += 1;
+                return Some(());
+            }
+            if self.done {
+                return None;
+            }
+            let disconnected = self
+                .rendezvous
+                .channel
+                .get()
+                .map_or(true, |channel| channel.recv().is_err());
+            if disconnected {
+                self.done = true;
+                self.rendezvous.record_wait(self.started);
+            }
+        }
+    }
+}
+
+impl<C> FusedIterator for Completions<'_, C> {}
+
+impl<C> Rendezvous<C> {
+    /// Executes the rendezvous process incrementally, yielding once for every guard that
+    /// completes.
+    ///
+    /// This releases the original sender like [`Rendezvous::rendezvous`], after which no
+    /// further guards can be forked off the rendezvous itself. The iterator first yields for
+    /// every guard that already completed, then blocks until the next guard completes, and
+    /// ends once all guards completed. This allows reporting progress, e.g. of `N` workers,
+    /// instead of blocking until all of them are done.
+    ///
+    /// Dropping the iterator early leaves the rendezvous attempted but not completed; it is
+    /// completed when waited on again or dropped.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::thread;
+    /// use rendezvous::Rendezvous;
+    ///
+    /// let mut rendezvous = Rendezvous::new();
+    /// for _ in 0..3 {
+    ///     let guard = rendezvous.fork_guard();
+    ///     thread::spawn(move || drop(guard));
+    /// }
+    ///
+    /// let mut finished = 0;
+    /// for () in rendezvous.completions() {
+    ///     finished += 1;
+    ///     println!("{finished} of 3 workers finished");
+    /// }
+    /// assert_eq!(finished, 3);
+    /// ```
+    pub fn completions(&mut self) -> Completions<'_, C> {
+        if let Some(channel) = self.channel.get() {
+            channel.shared.iterated.store(true, Ordering::Release);
+        }
+        let started = Instant::now();
+        self.release();
+        Completions {
+            rendezvous: self,
+            yielded: 0,
+            started,
+            done: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc;
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn yields_once_per_guard() {
+        let mut rendezvous = Rendezvous::new();
+        let early = rendezvous.fork_guard();
+        let guards: Vec<_> = (0..4).map(|_| rendezvous.fork_guard()).collect();
+        drop(early);
+
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            for guard in guards {
+                rx.recv().unwrap();
+                drop(guard);
+            }
+        });
+
+        let mut completions = rendezvous.completions();
+        assert_eq!(completions.next(), Some(()));
+        assert_eq!(completions.remaining_guards(), 4);
+        for remaining in (0..4).rev() {
+            tx.send(()).unwrap();
+            assert_eq!(completions.next(), Some(()));
+            assert_eq!(completions.remaining_guards(), remaining);
+        }
+        assert_eq!(completions.next(), None);
+        assert_eq!(completions.next(), None);
+    }
+
+    #[test]
+    fn rendezvous_without_guards_yields_nothing() {
+        let mut rendezvous = Rendezvous::new();
+        assert_eq!(rendezvous.completions().count(), 0);
+
+        let mut rendezvous = Rendezvous::new();
+        rendezvous.fork_guard().completed();
+        assert_eq!(rendezvous.completions().count(), 1);
+    }
+
+    #[test]
+    fn forked_guards_are_observed_as_they_complete() {
+        let mut rendezvous = Rendezvous::new();
+        let guard = rendezvous.fork_guard();
+        thread::spawn(move || {
+            let forked = guard.fork();
+            thread::sleep(Duration::from_millis(10));
+            drop(guard);
+            thread::sleep(Duration::from_millis(10));
+            drop(forked);
+        });
+        assert_eq!(rendezvous.completions().count(), 2);
+        assert_eq!(rendezvous.remaining_guards(), 0);
+    }
+}
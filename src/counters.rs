@@ -0,0 +1,128 @@
+//! Reading the guard counters of a rendezvous without taking any lock.
+
+use crate::Rendezvous;
+use std::sync::atomic::Ordering;
+
+/// The guard counters of a rendezvous, read using [`Rendezvous::lock_free_snapshot`].
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub struct RendezvousCounters {
+    /// The [round](Rendezvous::round) of the rendezvous.
+    pub round: u64,
+    /// The number of guards forked in the current round.
+    pub forked: usize,
+    /// The number of guards dropped in the current round.
+    pub completed: usize,
+    /// The number of guards forked but not yet dropped.
+    pub remaining: usize,
+}
+
+impl<C> Rendezvous<C> {
+    /// Reads the guard counters without taking any lock.
+    ///
+    /// The counters are atomics updated by every fork and drop, so reading them never
+    /// contends with workers forking or dropping guards, and a monitoring thread can poll them
+    /// at high frequency. Each counter is exact at the moment it was read; since the completed
+    /// count is read before the forked count, the snapshot never reports more completed than
+    /// forked guards. For per-guard details, which require locking, see
+    /// [`Rendezvous::rendezvous_timeout_snapshot`].
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use rendezvous::Rendezvous;
+    ///
+    /// let rendezvous = Rendezvous::new();
+    /// let guard = rendezvous.fork_guard();
+    /// rendezvous.fork_guard().completed();
+    ///
+    /// let counters = rendezvous.lock_free_snapshot();
+    /// assert_eq!((counters.forked, counters.completed, counters.remaining), (2, 1, 1));
+    /// # drop(guard);
+    /// ```
+    pub fn lock_free_snapshot(&self) -> RendezvousCounters {
+        let Some(channel) = self.channel.get() else {
+            return RendezvousCounters {
+                round: self.round,
+                ..RendezvousCounters::default()
+            };
+        };
+        let shared = &channel.shared;
+        let completed = shared.completed.load(Ordering::SeqCst);
+        let forked = shared.forked.load(Ordering::SeqCst);
+        RendezvousCounters {
+            round: shared.round,
+            forked,
+            completed,
+            remaining: if shared.is_transferred() {
+                0
+            } else {
+                forked.saturating_sub(completed)
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lock;
+    use std::sync::atomic::AtomicBool;
+    use std::sync::Arc;
+    use std::thread;
+
+    #[test]
+    fn counters_reflect_forks_and_drops() {
+        let rendezvous = Rendezvous::new();
+        assert_eq!(
+            rendezvous.lock_free_snapshot(),
+            RendezvousCounters::default()
+        );
+
+        let guard = rendezvous.fork_guard();
+        let nested = guard.fork();
+        drop(guard);
+        let counters = rendezvous.lock_free_snapshot();
+        assert_eq!(
+            counters,
+            RendezvousCounters {
+                round: 0,
+                forked: 2,
+                completed: 1,
+                remaining: 1
+            }
+        );
+        drop(nested);
+        rendezvous.rendezvous();
+    }
+
+    #[test]
+    fn reads_do_not_block_forking() {
+        let rendezvous = Rendezvous::new();
+        let holder = rendezvous.fork_guard();
+
+        // Reading succeeds while the guard registry is locked.
+        let registry = lock(&holder.shared.registry);
+        assert_eq!(rendezvous.lock_free_snapshot().remaining, 1);
+        drop(registry);
+
+        let done = Arc::new(AtomicBool::new(false));
+        let worker = thread::spawn({
+            let done = done.clone();
+            let holder = holder.fork();
+            move || {
+                for _ in 0..1_000 {
+                    drop(holder.fork());
+                }
+                done.store(true, Ordering::SeqCst);
+            }
+        });
+        while !done.load(Ordering::SeqCst) {
+            let counters = rendezvous.lock_free_snapshot();
+            assert!(counters.completed <= counters.forked);
+        }
+        worker.join().unwrap();
+        assert_eq!(rendezvous.lock_free_snapshot().forked, 1_002);
+        drop(holder);
+        rendezvous.rendezvous();
+    }
+}
@@ -0,0 +1,162 @@
+//! Waiting for a rendezvous within a CPU-time budget of the waiting thread.
+
+use crate::{Channel, Rendezvous, RendezvousTimeoutError};
+use std::sync::mpsc::TryRecvError;
+use std::time::{Duration, Instant};
+
+impl Channel {
+    /// Busy-polls the channel until all senders are dropped or the calling thread has
+    /// consumed `budget` of CPU time.
+    fn wait_cpu_budget(&self, budget: Duration) -> Result<(), RendezvousTimeoutError> {
+        let clock = ThreadClock::start();
+        loop {
+            match self.try_recv() {
+                Ok(_) => continue,
+                Err(TryRecvError::Disconnected) => return Ok(()),
+                Err(TryRecvError::Empty) => {}
+            }
+            if clock.elapsed() >= budget {
+                return Err(RendezvousTimeoutError::Timeout);
+            }
+            std::hint::spin_loop();
+        }
+    }
+}
+
+impl<C> Rendezvous<C> {
+    /// Executes the rendezvous process, giving up once the waiting thread has consumed
+    /// `budget` of CPU time.
+    ///
+    /// Unlike [`rendezvous_timeout`](Self::rendezvous_timeout), which bounds the wall-clock time
+    /// spent waiting, this bounds the CPU time charged to the calling thread, which is useful
+    /// for batch jobs scheduled under a CPU quota. The wait is a busy-polling loop, i.e. a spin
+    /// strategy; a thread that blocks consumes next to no CPU time and would never exhaust its
+    /// budget. Consequently, the budget trips roughly after `budget` of wall-clock time on an
+    /// uncontended core, and later if the thread is preempted.
+    ///
+    /// ## Platform support
+    ///
+    /// On Unix platforms the thread CPU time is queried via `clock_gettime` with
+    /// `CLOCK_THREAD_CPUTIME_ID`. On other platforms, or if the query fails, the wall-clock time
+    /// spent polling is used instead.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use rendezvous::{Rendezvous, RendezvousTimeoutError};
+    ///
+    /// let mut rendezvous = Rendezvous::new();
+    /// let guard = rendezvous.fork_guard();
+    /// assert_eq!(
+    ///     rendezvous.rendezvous_cpu_budget(Duration::from_millis(10)),
+    ///     Err(RendezvousTimeoutError::Timeout)
+    /// );
+    /// drop(guard);
+    /// assert_eq!(rendezvous.rendezvous_cpu_budget(Duration::from_millis(10)), Ok(()));
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "cpu-budget")))]
+    pub fn rendezvous_cpu_budget(
+        &mut self,
+        budget: Duration,
+    ) -> Result<(), RendezvousTimeoutError> {
+        let _span = self.wait_span("rendezvous_cpu_budget");
+        let started = Instant::now();
+        let result = match self.release() {
+            Some(channel) => channel.wait_cpu_budget(budget),
+            None => Ok(()),
+        };
+        self.record_wait(started);
+        #[cfg(any(feature = "log", feature = "tracing"))]
+        if result.is_err() {
+            debug!(
+                "The CPU budget was exhausted during a rendezvous{}",
+                self.log_context()
+            );
+        }
+        result
+    }
+}
+
+/// Measures the CPU time consumed by the current thread since construction.
+enum ThreadClock {
+    /// The thread CPU time at construction.
+    #[cfg(unix)]
+    Cpu(Duration),
+    /// The wall-clock time at construction, used where thread CPU time is unavailable.
+    Wall(Instant),
+}
+
+impl ThreadClock {
+    fn start() -> Self {
+        #[cfg(unix)]
+        if let Some(now) = thread_cpu_time() {
+            return Self::Cpu(now);
+        }
+        Self::Wall(Instant::now())
+    }
+
+    fn elapsed(&self) -> Duration {
+        match self {
+            #[cfg(unix)]
+            Self::Cpu(start) => thread_cpu_time()
+                .map(|now| now.saturating_sub(*start))
+                .unwrap_or(Duration::MAX),
+            Self::Wall(start) => start.elapsed(),
+        }
+    }
+}
+
+/// Returns the CPU time consumed by the current thread.
+#[cfg(unix)]
+fn thread_cpu_time() -> Option<Duration> {
+    let mut ts = libc::timespec {
+        tv_sec: 0,
+        tv_nsec: 0,
+    };
+    // SAFETY: `ts` is a valid, writable timespec for the duration of the call.
+    let result = unsafe { libc::clock_gettime(libc::CLOCK_THREAD_CPUTIME_ID, &mut ts) };
+    if result != 0 {
+        return None;
+    }
+    Some(Duration::new(ts.tv_sec as u64, ts.tv_nsec as u32))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    #[cfg(unix)]
+    fn thread_cpu_time_advances_when_spinning() {
+        let clock = ThreadClock::start();
+        assert!(matches!(clock, ThreadClock::Cpu(_)));
+        while clock.elapsed() < Duration::from_millis(5) {
+            std::hint::spin_loop();
+        }
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn budget_exhausted_while_spinning() {
+        let mut rendezvous = Rendezvous::new();
+        let guard = rendezvous.fork_guard();
+        assert_eq!(
+            rendezvous.rendezvous_cpu_budget(Duration::from_millis(20)),
+            Err(RendezvousTimeoutError::Timeout)
+        );
+        drop(guard);
+    }
+
+    #[test]
+    fn completes_within_budget() {
+        let mut rendezvous = Rendezvous::new();
+        let guard = rendezvous.fork_guard();
+        thread::spawn(move || drop(guard));
+        assert_eq!(
+            rendezvous.rendezvous_cpu_budget(Duration::from_secs(10)),
+            Ok(())
+        );
+    }
+}
@@ -0,0 +1,108 @@
+//! Exporting guard lifetimes as CSV.
+
+use crate::{GuardAudit, Rendezvous};
+use std::io::{self, Write};
+use std::time::Instant;
+
+/// The header row of the CSV export.
+const HEADER: &str = "id,label,forked_at_ms,completed_at_ms,lifetime_ms,panicked";
+
+/// Writes one CSV row per audited guard, with times relative to the earliest fork.
+fn write_csv<W: Write>(mut writer: W, audits: &[GuardAudit]) -> io::Result<()> {
+    writeln!(writer, "{HEADER}")?;
+    let Some(origin) = audits.iter().map(|audit| audit.forked_at).min() else {
+        return writer.flush();
+    };
+    let millis = |at: Instant| at.saturating_duration_since(origin).as_secs_f64() * 1000.0;
+    for audit in audits {
+        writeln!(
+            writer,
+            "{},{},{:.3},{:.3},{:.3},{}",
+            audit.id.as_u64(),
+            escape(audit.label.as_deref().unwrap_or_default()),
+            millis(audit.forked_at),
+            millis(audit.completed_at),
+            audit.lifetime().as_secs_f64() * 1000.0,
+            audit.panicked
+        )?;
+    }
+    writer.flush()
+}
+
+/// Quotes `value` if it contains characters that are special in CSV.
+fn escape(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_owned()
+    }
+}
+
+impl<C> Rendezvous<C> {
+    /// Executes the rendezvous process, then writes one CSV row per guard to `writer`.
+    ///
+    /// The columns are `id,label,forked_at_ms,completed_at_ms,lifetime_ms,panicked`, preceded
+    /// by a header row. Times are given in milliseconds relative to the earliest fork. Like
+    /// [`rendezvous_audited`](Self::rendezvous_audited), this requires the rendezvous to be
+    /// built with [`RendezvousBuilder::audit`](crate::RendezvousBuilder::audit); otherwise,
+    /// only the header is written.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use rendezvous::RendezvousBuilder;
+    ///
+    /// let rendezvous = RendezvousBuilder::new().audit().build();
+    /// rendezvous.fork_guard_labeled("worker").completed();
+    ///
+    /// let mut csv = Vec::new();
+    /// rendezvous.rendezvous_csv(&mut csv).unwrap();
+    /// let csv = String::from_utf8(csv).unwrap();
+    /// assert!(csv.lines().nth(1).unwrap().starts_with("0,worker,0.000,"));
+    /// ```
+    pub fn rendezvous_csv<W: Write>(self, writer: W) -> io::Result<()> {
+        let _span = self.wait_span("rendezvous_csv");
+        let report = self.rendezvous_audited();
+        write_csv(writer, &report.guards)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RendezvousBuilder;
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn writes_header_and_rows() {
+        let rendezvous = RendezvousBuilder::new().audit().build();
+        let first = rendezvous.fork_guard_labeled("parse, validate");
+        let second = rendezvous.fork_guard();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(10));
+            drop(first);
+            thread::sleep(Duration::from_millis(10));
+            drop(second);
+        });
+
+        let mut csv = Vec::new();
+        rendezvous.rendezvous_csv(&mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        let lines: Vec<_> = csv.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], HEADER);
+        assert!(lines[1].starts_with("0,\"parse, validate\",0.000,"));
+        assert!(lines[2].starts_with("1,,"));
+
+        for line in &lines[1..] {
+            let columns: Vec<_> = line.rsplitn(5, ',').collect();
+            let lifetime: f64 = columns[1].parse().unwrap();
+            let completed: f64 = columns[2].parse().unwrap();
+            let forked: f64 = columns[3].parse().unwrap();
+            assert_eq!(columns[0], "false");
+            assert!(lifetime >= 10.0);
+            assert!((completed - forked - lifetime).abs() < 0.01);
+        }
+    }
+}
@@ -0,0 +1,98 @@
+//! Propagating a shutdown deadline to guards.
+
+use crate::{lock, Rendezvous, RendezvousGuard, RendezvousTimeoutError};
+use std::time::Instant;
+
+impl<C> Rendezvous<C> {
+    /// Publishes `deadline` to all guards, telling workers by when they must finish.
+    ///
+    /// Workers can query the deadline using [`RendezvousGuard::deadline`] and truncate their
+    /// work accordingly. Setting a deadline does not by itself limit the wait; use
+    /// [`rendezvous_deadline`](Self::rendezvous_deadline) for that. Setting a deadline again
+    /// replaces the previous one.
+    pub fn set_deadline(&self, deadline: Instant) {
+        *lock(&self.shared().deadline) = Some(deadline);
+    }
+
+    /// Publishes `deadline` to all guards, then executes the rendezvous process, giving up
+    /// once the deadline has passed.
+    ///
+    /// The deadline is measured on the clock of [`Instant`], which may stop while the system
+    /// is suspended; see [`RendezvousBuilder::boottime_deadlines`] for an alternative.
+    ///
+    /// [`RendezvousBuilder::boottime_deadlines`]: crate::RendezvousBuilder::boottime_deadlines
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::thread;
+    /// use std::time::{Duration, Instant};
+    /// use rendezvous::Rendezvous;
+    ///
+    /// let mut rendezvous = Rendezvous::new();
+    /// let guard = rendezvous.fork_guard();
+    /// thread::spawn(move || {
+    ///     while guard.deadline().is_none() {
+    ///         thread::yield_now();
+    ///     }
+    ///     // Wrap up before the deadline passes.
+    ///     drop(guard);
+    /// });
+    ///
+    /// let deadline = Instant::now() + Duration::from_secs(5);
+    /// assert_eq!(rendezvous.rendezvous_deadline(deadline), Ok(()));
+    /// ```
+    pub fn rendezvous_deadline(&mut self, deadline: Instant) -> Result<(), RendezvousTimeoutError> {
+        let _span = self.wait_span("rendezvous_deadline");
+        if self.channel.get().is_some() {
+            self.set_deadline(deadline);
+        }
+        #[cfg(all(feature = "boottime", target_os = "linux"))]
+        if self.options.boottime {
+            return self.rendezvous_boottime(deadline);
+        }
+        self.rendezvous_timeout(deadline.saturating_duration_since(Instant::now()))
+    }
+}
+
+impl RendezvousGuard {
+    /// Returns the deadline by which the work of this guard must be finished, if one was set
+    /// using [`Rendezvous::set_deadline`].
+    pub fn deadline(&self) -> Option<Instant> {
+        *lock(&self.shared.deadline)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn guards_read_the_deadline() {
+        let rendezvous = Rendezvous::new();
+        let guard = rendezvous.fork_guard();
+        assert_eq!(guard.deadline(), None);
+
+        let deadline = Instant::now() + Duration::from_secs(1);
+        rendezvous.set_deadline(deadline);
+        assert_eq!(guard.deadline(), Some(deadline));
+        assert_eq!(guard.fork().deadline(), Some(deadline));
+
+        drop(guard);
+        rendezvous.rendezvous();
+    }
+
+    #[test]
+    fn rendezvous_gives_up_at_the_deadline() {
+        let mut rendezvous = Rendezvous::new();
+        let guard = rendezvous.fork_guard();
+        let deadline = Instant::now() + Duration::from_millis(10);
+        assert_eq!(
+            rendezvous.rendezvous_deadline(deadline),
+            Err(RendezvousTimeoutError::Timeout)
+        );
+        assert_eq!(guard.deadline(), Some(deadline));
+        drop(guard);
+    }
+}
@@ -0,0 +1,209 @@
+//! Reporting rendezvous waits that appear to be deadlocked.
+
+use crate::{audit, Shared};
+use std::backtrace::Backtrace;
+use std::fmt::Write;
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::Arc;
+use std::thread::{self, ThreadId};
+use std::time::{Duration, Instant};
+
+/// Where a guard was forked, recorded for deadlock reports.
+pub(crate) struct ForkSite {
+    /// The thread the guard was forked on.
+    thread: ThreadId,
+    /// The name of the thread the guard was forked on, or its identifier if it is unnamed.
+    thread_name: String,
+    /// The backtrace of the fork.
+    backtrace: Backtrace,
+}
+
+impl ForkSite {
+    /// Captures the fork site of a guard forked on the current thread.
+    pub(crate) fn capture() -> Self {
+        Self {
+            thread: thread::current().id(),
+            thread_name: audit::current_thread_name(),
+            backtrace: Backtrace::force_capture(),
+        }
+    }
+}
+
+/// A background thread watching a blocking wait; stops watching when dropped.
+pub(crate) struct Watchdog {
+    /// Dropped to stop the watchdog.
+    _done: Sender<()>,
+}
+
+impl Watchdog {
+    /// Spawns a watchdog logging a deadlock report whenever the wait on `shared` that starts now
+    /// saw no completion for `threshold` while guards remain.
+    pub(crate) fn spawn(shared: &Arc<Shared>, threshold: Duration) -> Self {
+        let (done, stopped) = mpsc::channel();
+        let shared = Arc::clone(shared);
+        let waiter = (thread::current().id(), audit::current_thread_name());
+        let started = Instant::now();
+        thread::Builder::new()
+            .name("rendezvous-watchdog".into())
+            .spawn(move || {
+                let mut reported_at = None;
+                while let Err(RecvTimeoutError::Timeout) = stopped.recv_timeout(threshold) {
+                    let progress = shared
+                        .last_completion()
+                        .map_or(started, |last| last.max(started));
+                    if progress.elapsed() < threshold
+                        || reported_at == Some(progress)
+                        || shared.live_guards() == 0
+                    {
+                        continue;
+                    }
+                    reported_at = Some(progress);
+                    error!("{}", shared.deadlock_report(&waiter, started.elapsed()));
+                }
+            })
+            .expect("failed to spawn the watchdog thread");
+        Self { _done: done }
+    }
+}
+
+impl Shared {
+    /// Returns the threshold of the deadlock watchdog, which only runs in builds with debug
+    /// assertions.
+    pub(crate) fn deadlock_threshold(&self) -> Option<Duration> {
+        self.options
+            .deadlock_threshold
+            .filter(|_| cfg!(debug_assertions))
+    }
+
+    /// Returns whether the fork sites of guards are recorded for deadlock reports.
+    pub(crate) fn detects_deadlocks(&self) -> bool {
+        self.deadlock_threshold().is_some()
+    }
+
+    /// Renders a report of a wait on thread `waiter` that was blocked for `blocked`, listing
+    /// the outstanding guards along with the threads and backtraces of their forks.
+    fn deadlock_report(&self, waiter: &(ThreadId, String), blocked: Duration) -> String {
+        let (waiter, waiter_name) = waiter;
+        let mut report = format!(
+            "Possible rendezvous deadlock: thread '{waiter_name}' blocked for {blocked:?} \
+             without completions, {} guards remaining [round {}]",
+            self.live_guards(),
+            self.round
+        );
+        let sites = self.fork_sites();
+        for guard in self.snapshot() {
+            write!(report, "\n  {}", guard.id).ok();
+            if let Some(label) = &guard.label {
+                write!(report, " '{label}'").ok();
+            }
+            write!(report, " forked {:?} ago", guard.age).ok();
+            let Some(site) = sites.get(&guard.id) else {
+                continue;
+            };
+            write!(report, " on thread '{}'", site.thread_name).ok();
+            if site.thread == *waiter {
+                write!(report, " (the waiting thread)").ok();
+            }
+            write!(report, "\n    fork backtrace:").ok();
+            for line in site.backtrace.to_string().lines() {
+                write!(report, "\n    {line}").ok();
+            }
+        }
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_log, RendezvousBuilder};
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn watchdog_reports_stuck_guards() {
+        test_log::init();
+
+        let rendezvous = RendezvousBuilder::new()
+            .deadlock_threshold(Duration::from_millis(100))
+            .build();
+        let stuck = rendezvous.fork_guard_labeled("deadlock-7e21");
+        let waiter = thread::Builder::new()
+            .name("deadlock-waiter".into())
+            .spawn(move || rendezvous.rendezvous())
+            .unwrap();
+
+        thread::sleep(Duration::from_millis(400));
+        let reports = test_log::messages_containing("'deadlock-7e21'");
+        drop(stuck);
+        waiter.join().unwrap();
+
+        assert!(!reports.is_empty());
+        let (level, report) = &reports[0];
+        assert_eq!(*level, log::Level::Error);
+        assert!(report.starts_with("Possible rendezvous deadlock: thread 'deadlock-waiter'"));
+        assert!(report.contains("1 guards remaining"));
+        assert!(report.contains("#0 'deadlock-7e21' forked"));
+        assert!(report.contains("on thread '"));
+        assert!(!report.contains("(the waiting thread)"));
+        assert!(report.contains("fork backtrace:"));
+        assert!(report.contains("watchdog_reports_stuck_guards"));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn guards_forked_on_the_waiting_thread_are_flagged() {
+        test_log::init();
+
+        let rendezvous = RendezvousBuilder::new()
+            .deadlock_threshold(Duration::from_millis(100))
+            .build();
+        let guard = rendezvous.fork_guard_labeled("deadlock-91c4");
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(400));
+            drop(guard);
+        });
+        rendezvous.rendezvous();
+
+        let reports = test_log::messages_containing("'deadlock-91c4'");
+        assert!(!reports.is_empty());
+        assert!(reports[0]
+            .1
+            .contains("(the waiting thread)\n    fork backtrace:"));
+    }
+
+    #[test]
+    fn waiting_threads_are_told_apart_by_id() {
+        test_log::init();
+
+        let rendezvous = RendezvousBuilder::new()
+            .deadlock_threshold(Duration::from_millis(100))
+            .build();
+        let name = "deadlock-twin";
+        let guard = thread::Builder::new()
+            .name(name.into())
+            .spawn({
+                let rendezvous = rendezvous.clone();
+                move || rendezvous.fork_guard_labeled("deadlock-0b5d")
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+        let waiter = thread::Builder::new()
+            .name(name.into())
+            .spawn(move || rendezvous.rendezvous())
+            .unwrap();
+        thread::sleep(Duration::from_millis(400));
+        drop(guard);
+        waiter.join().unwrap();
+
+        let reports = test_log::messages_containing("'deadlock-0b5d'");
+        if cfg!(debug_assertions) {
+            assert!(!reports.is_empty());
+            assert!(reports[0].1.contains("on thread 'deadlock-twin'"));
+            assert!(!reports[0].1.contains("(the waiting thread)"));
+        } else {
+            assert!(reports.is_empty());
+        }
+    }
+}
@@ -0,0 +1,80 @@
+//! [`Debug`] implementations that never block.
+
+use crate::{Rendezvous, RendezvousGuard};
+use std::fmt::{Debug, Formatter};
+use std::sync::TryLockError;
+
+impl<C> Debug for Rendezvous<C> {
+    /// Formats whether the rendezvous still holds its sender and the number of outstanding
+    /// guards. Nothing is locked in a blocking way, so formatting never deadlocks.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let has_sender = !self.released
+            && self
+                .channel
+                .get()
+                .map_or(true, |channel| match channel.tx.try_lock() {
+                    Ok(tx) => tx.is_some(),
+                    Err(TryLockError::Poisoned(poison)) => poison.into_inner().is_some(),
+                    // Released concurrently by a clone waiting on the rendezvous.
+                    Err(TryLockError::WouldBlock) => false,
+                });
+        f.debug_struct("Rendezvous")
+            .field("round", &self.round)
+            .field("has_sender", &has_sender)
+            .field("remaining_guards", &self.remaining_guards())
+            .finish()
+    }
+}
+
+impl Debug for RendezvousGuard {
+    /// Formats the identifier of the guard and its label, if any. The label is omitted while
+    /// the guard registry is locked, so formatting never blocks.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut debug = f.debug_struct("RendezvousGuard");
+        debug.field("id", &self.id);
+        if let Some(label) = self.shared.try_label(self.id) {
+            debug.field("label", &label);
+        }
+        debug.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RendezvousBuilder;
+
+    #[test]
+    fn debug_output_describes_state() {
+        #[derive(Debug)]
+        #[allow(dead_code)]
+        struct Holder {
+            rendezvous: Rendezvous,
+            guards: Vec<RendezvousGuard>,
+        }
+
+        let rendezvous = RendezvousBuilder::new().full_tracking().build();
+        let guards = vec![
+            rendezvous.fork_guard_labeled("ingest"),
+            rendezvous.fork_guard(),
+        ];
+        assert_eq!(
+            format!("{rendezvous:?}"),
+            "Rendezvous { round: 0, has_sender: true, remaining_guards: 2 }"
+        );
+        assert_eq!(
+            format!("{guards:?}"),
+            "[RendezvousGuard { id: GuardId(0), label: \"ingest\" }, RendezvousGuard { id: GuardId(1) }]"
+        );
+
+        let holder = Holder { rendezvous, guards };
+        assert!(format!("{holder:?}").starts_with("Holder { rendezvous: Rendezvous {"));
+        drop(holder.guards);
+        let mut rendezvous = holder.rendezvous;
+        rendezvous.rendezvous_ref();
+        assert_eq!(
+            format!("{rendezvous:?}"),
+            "Rendezvous { round: 0, has_sender: false, remaining_guards: 0 }"
+        );
+    }
+}
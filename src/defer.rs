@@ -0,0 +1,117 @@
+//! Releasing guards at scope exit, in the style of `scopeguard::defer!`.
+
+use crate::RendezvousGuard;
+use std::ops::{Deref, DerefMut};
+
+/// Releases a [`RendezvousGuard`] when dropped; created using
+/// [`RendezvousGuard::defer_release`].
+///
+/// The guard remains accessible through [`Deref`] until the scope exits.
+#[must_use = "the guard is released immediately if the deferred release is not bound"]
+pub struct DeferredRelease<'a> {
+    /// The guard to release.
+    guard: &'a mut RendezvousGuard,
+}
+
+impl Deref for DeferredRelease<'_> {
+    type Target = RendezvousGuard;
+
+    fn deref(&self) -> &Self::Target {
+        self.guard
+    }
+}
+
+impl DerefMut for DeferredRelease<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.guard
+    }
+}
+
+impl Drop for DeferredRelease<'_> {
+    fn drop(&mut self) {
+        let detached = RendezvousGuard::detached(self.guard.shared.clone());
+        drop(std::mem::replace(self.guard, detached));
+    }
+}
+
+impl RendezvousGuard {
+    /// Defers releasing this guard to the end of the current scope.
+    ///
+    /// When the returned value is dropped, e.g. at scope exit, on an early return, or while
+    /// unwinding from a panic, the guard is released as if it had been dropped. Afterwards,
+    /// the guard is [detached](Self::is_detached) and no longer keeps the rendezvous open.
+    /// This allows releasing a guard that is owned elsewhere, such as a field of a longer-lived
+    /// struct, without moving it into a separate variable.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use rendezvous::{Rendezvous, RendezvousGuard};
+    ///
+    /// fn process(guard: &mut RendezvousGuard, input: Option<u32>) -> Option<u32> {
+    ///     let _release = guard.defer_release();
+    ///     let value = input?;
+    ///     Some(value * 2)
+    /// }
+    ///
+    /// let rendezvous = Rendezvous::new();
+    /// let mut guard = rendezvous.fork_guard();
+    /// assert_eq!(process(&mut guard, None), None);
+    /// assert!(guard.is_detached());
+    /// rendezvous.rendezvous();
+    /// ```
+    pub fn defer_release(&mut self) -> DeferredRelease<'_> {
+        DeferredRelease { guard: self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Rendezvous, RendezvousGuard};
+    use std::panic::{self, AssertUnwindSafe};
+    use std::time::Duration;
+
+    struct Worker {
+        guard: RendezvousGuard,
+    }
+
+    impl Worker {
+        fn run(&mut self, fail_early: bool) -> Result<(), &'static str> {
+            let release = self.guard.defer_release();
+            assert!(!release.is_detached());
+            if fail_early {
+                return Err("failed early");
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn deferred_release_on_early_return() {
+        let mut rendezvous = Rendezvous::new();
+        let mut worker = Worker {
+            guard: rendezvous.fork_guard(),
+        };
+        assert_eq!(worker.run(true), Err("failed early"));
+        assert!(worker.guard.is_detached());
+        assert_eq!(rendezvous.remaining_guards(), 0);
+        assert_eq!(
+            rendezvous.rendezvous_timeout(Duration::from_secs(1)),
+            Ok(())
+        );
+        drop(worker);
+    }
+
+    #[test]
+    fn deferred_release_on_panic() {
+        let rendezvous = Rendezvous::new();
+        let mut guard = rendezvous.fork_guard();
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            let _release = guard.defer_release();
+            panic!("worker failed");
+        }));
+        assert!(result.is_err());
+        assert_eq!(rendezvous.remaining_guards(), 0);
+        rendezvous.rendezvous();
+    }
+}
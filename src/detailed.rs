@@ -0,0 +1,102 @@
+//! Timeouts reporting how many guards were outstanding.
+
+use crate::Rendezvous;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+/// Error returned by [`Rendezvous::rendezvous_timeout_detailed`], carrying the guard counts at
+/// the moment of the timeout.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct DetailedTimeoutError {
+    /// The number of guards still outstanding.
+    pub outstanding: usize,
+    /// The number of guards forked in the current [round](Rendezvous::round).
+    pub forked: usize,
+}
+
+impl Display for DetailedTimeoutError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Rendezvous timed out with {} of {} guards still outstanding",
+            self.outstanding, self.forked
+        )
+    }
+}
+
+impl Error for DetailedTimeoutError {}
+
+impl<C> Rendezvous<C> {
+    /// Executes the rendezvous process with a timeout, reporting the number of outstanding
+    /// guards if the timeout occurs.
+    ///
+    /// This behaves like [`Rendezvous::rendezvous_timeout`]. The counts are read from the
+    /// guard counters at the moment of the timeout, see [`Rendezvous::lock_free_snapshot`], so
+    /// they are available for a [minimal](crate::RendezvousBuilder::minimal) rendezvous, too.
+    /// For the identities and labels of the outstanding guards, see
+    /// [`Rendezvous::rendezvous_timeout_snapshot`].
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use rendezvous::{DetailedTimeoutError, Rendezvous};
+    ///
+    /// let mut rendezvous = Rendezvous::new();
+    /// let guard = rendezvous.fork_guard();
+    /// rendezvous.fork_guard().completed();
+    ///
+    /// let error = rendezvous
+    ///     .rendezvous_timeout_detailed(Duration::from_millis(10))
+    ///     .unwrap_err();
+    /// assert_eq!(error, DetailedTimeoutError { outstanding: 1, forked: 2 });
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "Rendezvous timed out with 1 of 2 guards still outstanding"
+    /// );
+    /// drop(guard);
+    /// ```
+    pub fn rendezvous_timeout_detailed(
+        &mut self,
+        timeout: Duration,
+    ) -> Result<(), DetailedTimeoutError> {
+        let _span = self.wait_span("rendezvous_timeout_detailed");
+        self.rendezvous_timeout(timeout).map_err(|_| {
+            let counters = self.lock_free_snapshot();
+            DetailedTimeoutError {
+                outstanding: counters.remaining,
+                forked: counters.forked,
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RendezvousBuilder;
+    use std::thread;
+
+    #[test]
+    fn timeout_reports_outstanding_guards() {
+        let mut rendezvous = RendezvousBuilder::new().minimal().build();
+        let mut guards: Vec<_> = (0..5).map(|_| rendezvous.fork_guard()).collect();
+        let alive = guards.split_off(2);
+        drop(guards);
+
+        assert_eq!(
+            rendezvous.rendezvous_timeout_detailed(Duration::from_millis(10)),
+            Err(DetailedTimeoutError {
+                outstanding: 3,
+                forked: 5
+            })
+        );
+
+        thread::spawn(move || drop(alive));
+        assert_eq!(
+            rendezvous.rendezvous_timeout_detailed(Duration::from_secs(5)),
+            Ok(())
+        );
+    }
+}
@@ -0,0 +1,58 @@
+//! Deterministic, single-threaded execution of guarded tasks for testing.
+
+use crate::{Rendezvous, RendezvousGuard};
+
+/// Runs each task to completion on the current thread, in order, then performs the rendezvous.
+///
+/// Every task receives its own [`RendezvousGuard`] forked before the task runs. A task runs
+/// fully, dropping its guard, before the next task starts. This exercises the fork, drop and
+/// wait API deterministically in unit tests.
+///
+/// <div class="warning">
+/// This helper is intended for testing logic, not concurrency: no two tasks ever run at the same
+/// time. A task that keeps its guard alive (e.g. by leaking it) deadlocks the final rendezvous.
+/// </div>
+///
+/// ## Example
+///
+/// ```
+/// use std::cell::RefCell;
+/// use rendezvous::{run_deterministic, RendezvousGuard};
+///
+/// let order = RefCell::new(Vec::new());
+/// run_deterministic(vec![
+///     Box::new(|_guard: RendezvousGuard| order.borrow_mut().push(1)),
+///     Box::new(|_guard: RendezvousGuard| order.borrow_mut().push(2)),
+/// ]);
+/// assert_eq!(order.into_inner(), vec![1, 2]);
+/// ```
+pub fn run_deterministic<'a>(tasks: Vec<Box<dyn FnOnce(RendezvousGuard) + 'a>>) {
+    let rendezvous = Rendezvous::new();
+    for task in tasks {
+        task(rendezvous.fork_guard());
+    }
+    rendezvous.rendezvous();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    #[test]
+    fn runs_all_tasks_in_order() {
+        let order = RefCell::new(Vec::new());
+        let tasks: Vec<Box<dyn FnOnce(RendezvousGuard)>> = (0..3)
+            .map(|i| {
+                let order = &order;
+                Box::new(move |guard: RendezvousGuard| {
+                    order.borrow_mut().push(i);
+                    guard.completed();
+                }) as Box<dyn FnOnce(RendezvousGuard)>
+            })
+            .collect();
+
+        run_deterministic(tasks);
+        assert_eq!(order.into_inner(), vec![0, 1, 2]);
+    }
+}
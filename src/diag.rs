@@ -0,0 +1,58 @@
+//! Emitting diagnostics through `log` and `tracing`, whichever are enabled.
+//!
+//! The macros mirror the ones of both crates and forward their arguments to each enabled one,
+//! so the same events are emitted regardless of which ecosystem the user standardized on.
+
+// Not every level is used in every configuration, e.g. in release builds.
+#![allow(unused_macros)]
+
+/// Emits an event at the given level through `log` and `tracing`, whichever are enabled.
+macro_rules! event {
+    ($level:ident, $($arg:tt)+) => {{
+        #[cfg(feature = "log")]
+        log::$level!($($arg)+);
+        #[cfg(feature = "tracing")]
+        tracing::$level!($($arg)+);
+    }};
+}
+
+macro_rules! trace {
+    ($($arg:tt)+) => { event!(trace, $($arg)+) };
+}
+
+macro_rules! debug {
+    ($($arg:tt)+) => { event!(debug, $($arg)+) };
+}
+
+macro_rules! info {
+    ($($arg:tt)+) => { event!(info, $($arg)+) };
+}
+
+macro_rules! warn {
+    ($($arg:tt)+) => { event!(warn, $($arg)+) };
+}
+
+macro_rules! error {
+    ($($arg:tt)+) => { event!(error, $($arg)+) };
+}
+
+/// Returns whether events at the given level are emitted through `log` or `tracing`, to skip
+/// formatting messages nobody receives.
+macro_rules! enabled {
+    (trace) => { enabled!(@ Trace, TRACE) };
+    (debug) => { enabled!(@ Debug, DEBUG) };
+    (info) => { enabled!(@ Info, INFO) };
+    (@ $log:ident, $tracing:ident) => {{
+        #[allow(unused_mut)]
+        let mut enabled = false;
+        #[cfg(feature = "log")]
+        {
+            enabled |= log::log_enabled!(log::Level::$log);
+        }
+        #[cfg(feature = "tracing")]
+        {
+            enabled |= tracing::enabled!(tracing::Level::$tracing);
+        }
+        enabled
+    }};
+}
@@ -0,0 +1,102 @@
+//! A fingerprint of the set of completed guards.
+
+use crate::{lock, GuardId, Rendezvous};
+use std::sync::atomic::Ordering;
+
+/// The FNV-1a offset basis.
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// The FNV-1a prime.
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+impl<C> Rendezvous<C> {
+    /// Returns a stable digest of the set of guards that completed in the current round.
+    ///
+    /// The digest is a 64-bit FNV-1a hash over the sorted identifiers of all completed guards,
+    /// so it does not depend on the order in which guards were dropped. Two runs that complete
+    /// the same guard identifiers produce the same digest, across processes and platforms,
+    /// which makes it a compact fingerprint for audit logs. It is not a cryptographic hash.
+    ///
+    /// Call it after a rendezvous, e.g. using [`rendezvous_ref`](Self::rendezvous_ref), to
+    /// fingerprint the complete set of guards. For a
+    /// [minimal](crate::RendezvousBuilder::minimal) rendezvous outstanding guards are not
+    /// tracked, so the digest is only meaningful once the rendezvous has completed.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use rendezvous::Rendezvous;
+    ///
+    /// let mut first = Rendezvous::new();
+    /// let (a, b) = (first.fork_guard(), first.fork_guard());
+    /// drop(a);
+    /// drop(b);
+    /// first.rendezvous_ref();
+    ///
+    /// let mut second = Rendezvous::new();
+    /// let (a, b) = (second.fork_guard(), second.fork_guard());
+    /// drop(b);
+    /// drop(a);
+    /// second.rendezvous_ref();
+    ///
+    /// assert_eq!(first.completion_digest(), second.completion_digest());
+    /// ```
+    pub fn completion_digest(&self) -> u64 {
+        let Some(channel) = self.channel.get() else {
+            return FNV_OFFSET;
+        };
+        let shared = &channel.shared;
+        let forked = shared.next_id.load(Ordering::Acquire);
+        let registry = lock(&shared.registry);
+        (0..forked)
+            .map(GuardId)
+            .filter(|id| !registry.contains_key(id))
+            .flat_map(|id| id.as_u64().to_le_bytes())
+            .fold(FNV_OFFSET, |hash, byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RendezvousBuilder;
+    use std::thread;
+
+    /// Forks `count` guards and drops them in the order given by `order`.
+    fn digest_of(count: usize, order: &[usize]) -> u64 {
+        let mut rendezvous = Rendezvous::new();
+        let mut guards: Vec<_> = (0..count).map(|_| Some(rendezvous.fork_guard())).collect();
+        for &index in order {
+            let guard = guards[index].take();
+            thread::spawn(move || drop(guard)).join().unwrap();
+        }
+        rendezvous.rendezvous_ref();
+        rendezvous.completion_digest()
+    }
+
+    #[test]
+    fn digest_is_independent_of_completion_order() {
+        let forward = digest_of(4, &[0, 1, 2, 3]);
+        let backward = digest_of(4, &[3, 1, 0, 2]);
+        assert_eq!(forward, backward);
+        assert_ne!(forward, digest_of(3, &[0, 1, 2]));
+    }
+
+    #[test]
+    fn digest_excludes_outstanding_guards() {
+        let rendezvous = RendezvousBuilder::new().full_tracking().build();
+        assert_eq!(rendezvous.completion_digest(), FNV_OFFSET);
+
+        let first = rendezvous.fork_guard();
+        let second = rendezvous.fork_guard();
+        let before = rendezvous.completion_digest();
+        drop(second);
+        let partial = rendezvous.completion_digest();
+        assert_eq!(before, FNV_OFFSET);
+        assert_ne!(partial, before);
+        drop(first);
+        rendezvous.rendezvous();
+    }
+}
@@ -0,0 +1,111 @@
+//! Bridging guard lifecycle events into asynchronous code.
+
+use crate::{lock, GuardId, Rendezvous, Shared};
+use std::sync::atomic::Ordering;
+use tokio::sync::mpsc;
+
+/// A guard lifecycle event; see [`Rendezvous::async_event_channel`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum RendezvousEvent {
+    /// A guard was forked.
+    Forked(GuardId),
+    /// A guard was dropped.
+    Completed(GuardId),
+}
+
+impl<C> Rendezvous<C> {
+    /// Returns a channel receiving an event whenever a guard of this rendezvous is forked or
+    /// dropped.
+    ///
+    /// Guards send events without blocking, from whichever thread forks or drops them, so
+    /// synchronous workers can be observed by an asynchronous supervisor. Only guards forked
+    /// after this call are reported. The channel is closed once the rendezvous and all its
+    /// guards were dropped, or the rendezvous was [reset](Rendezvous::reset).
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::thread;
+    /// use rendezvous::{Rendezvous, RendezvousEvent};
+    ///
+    /// # tokio_test::block_on(async {
+    /// let rendezvous = Rendezvous::new();
+    /// let mut events = rendezvous.async_event_channel();
+    /// let guard = rendezvous.fork_guard();
+    /// let id = guard.id();
+    /// thread::spawn(move || drop(guard));
+    ///
+    /// assert_eq!(events.recv().await, Some(RendezvousEvent::Forked(id)));
+    /// assert_eq!(events.recv().await, Some(RendezvousEvent::Completed(id)));
+    /// # rendezvous.rendezvous();
+    /// # });
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+    pub fn async_event_channel(&self) -> mpsc::UnboundedReceiver<RendezvousEvent> {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let shared = self.shared();
+        lock(&shared.event_subscribers).push(tx);
+        shared.has_event_subscribers.store(true, Ordering::Release);
+        rx
+    }
+}
+
+impl Shared {
+    /// Sends `event` to all subscribers of [`Rendezvous::async_event_channel`], dropping the
+    /// ones whose receiver was closed.
+    pub(crate) fn publish_event(&self, event: RendezvousEvent) {
+        // Skips the lock unless an event channel was ever requested.
+        if !self.has_event_subscribers.load(Ordering::Acquire) {
+            return;
+        }
+        lock(&self.event_subscribers).retain(|tx| tx.send(event).is_ok());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[tokio::test]
+    async fn async_task_receives_sync_guard_events() {
+        let rendezvous = Rendezvous::new();
+        let mut events = rendezvous.async_event_channel();
+        let supervisor = tokio::spawn(async move {
+            let mut received = Vec::new();
+            while let Some(event) = events.recv().await {
+                received.push(event);
+            }
+            received
+        });
+
+        let first = rendezvous.fork_guard();
+        let second = first.fork();
+        let ids = (first.id(), second.id());
+        thread::spawn(move || {
+            drop(second);
+            drop(first);
+        })
+        .join()
+        .unwrap();
+        rendezvous.rendezvous();
+
+        assert_eq!(
+            supervisor.await.unwrap(),
+            vec![
+                RendezvousEvent::Forked(ids.0),
+                RendezvousEvent::Forked(ids.1),
+                RendezvousEvent::Completed(ids.1),
+                RendezvousEvent::Completed(ids.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn closed_receivers_are_dropped() {
+        let rendezvous = Rendezvous::new();
+        drop(rendezvous.async_event_channel());
+        rendezvous.fork_guard().completed();
+        assert!(lock(&rendezvous.shared().event_subscribers).is_empty());
+    }
+}
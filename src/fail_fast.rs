@@ -0,0 +1,171 @@
+//! Failing guards and waiting until the first failure.
+
+use crate::{GuardId, Rendezvous, RendezvousGuard, Signal};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::time::Instant;
+
+/// A failure reported by a guard using [`RendezvousGuard::fail`].
+#[derive(Debug)]
+pub struct GuardFailure {
+    /// The guard that failed.
+    guard: GuardId,
+    /// The error reported by the guard.
+    error: Box<dyn Error + Send + Sync>,
+}
+
+impl GuardFailure {
+    /// Returns the identifier of the guard that failed.
+    pub fn guard(&self) -> GuardId {
+        self.guard
+    }
+
+    /// Returns the error reported by the guard.
+    pub fn error(&self) -> &(dyn Error + Send + Sync + 'static) {
+        self.error.as_ref()
+    }
+
+    /// Consumes the failure, returning the error reported by the guard.
+    pub fn into_error(self) -> Box<dyn Error + Send + Sync> {
+        self.error
+    }
+}
+
+impl Display for GuardFailure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Rendezvous guard {} failed: {}", self.guard, self.error)
+    }
+}
+
+impl Error for GuardFailure {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(self.error.as_ref())
+    }
+}
+
+impl RendezvousGuard {
+    /// Completes the guard with a failure.
+    ///
+    /// The failure is reported to a waiter using [`Rendezvous::rendezvous_fail_fast`]; all
+    /// other rendezvous methods treat a failed guard like a completed one.
+    pub fn fail<E>(self, error: E)
+    where
+        E: Into<Box<dyn Error + Send + Sync>>,
+    {
+        let failure = GuardFailure {
+            guard: self.id,
+            error: error.into(),
+        };
+        #[cfg(any(feature = "log", feature = "tracing"))]
+        {
+            debug!("{failure} [round {}]", self.shared.round);
+        }
+        if let Some(tx) = self.sender() {
+            tx.send(Signal::Failed(failure));
+        }
+    }
+}
+
+impl<C> Rendezvous<C> {
+    /// Executes the rendezvous process, returning early with the first failure reported
+    /// by any guard using [`RendezvousGuard::fail`].
+    ///
+    /// If no guard fails, this blocks until all guards are dropped, just like
+    /// [`Rendezvous::rendezvous`].
+    ///
+    /// <div class="warning">
+    /// After a failure is returned, the remaining guards keep running but are no longer
+    /// waited on: the rendezvous is abandoned, and dropping it does not block.
+    /// </div>
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::thread;
+    /// use std::time::Duration;
+    /// use rendezvous::Rendezvous;
+    ///
+    /// let mut rendezvous = Rendezvous::new();
+    ///
+    /// let slow = rendezvous.fork_guard();
+    /// thread::spawn(move || {
+    ///     thread::sleep(Duration::from_millis(200));
+    ///     drop(slow);
+    /// });
+    ///
+    /// let failing = rendezvous.fork_guard();
+    /// thread::spawn(move || failing.fail("broken input"));
+    ///
+    /// let failure = rendezvous.rendezvous_fail_fast().unwrap_err();
+    /// assert_eq!(failure.error().to_string(), "broken input");
+    /// ```
+    pub fn rendezvous_fail_fast(&mut self) -> Result<(), GuardFailure> {
+        let _span = self.wait_span("rendezvous_fail_fast");
+        let started = Instant::now();
+        let mut result = Ok(());
+        if let Some(channel) = self.release() {
+            while let Ok(signal) = channel.recv() {
+                if let Signal::Failed(failure) = signal {
+                    result = Err(failure);
+                    break;
+                }
+            }
+        }
+        self.record_wait(started);
+        if result.is_err() {
+            self.abandoned = true;
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc;
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn first_failure_returns_promptly() {
+        let mut rendezvous = Rendezvous::new();
+
+        let completing = rendezvous.fork_guard();
+        thread::spawn(move || drop(completing));
+        // Held until the end of the test, so returning at all shows that the wait did not
+        // block on it.
+        let straggler = rendezvous.fork_guard();
+
+        let failing = rendezvous.fork_guard();
+        let failing_id = failing.id();
+        thread::spawn(move || failing.fail("failed early"));
+
+        let failure = rendezvous.rendezvous_fail_fast().unwrap_err();
+        assert_eq!(failure.guard(), failing_id);
+        assert_eq!(failure.error().to_string(), "failed early");
+
+        // Dropping the abandoned rendezvous must not wait for the straggler.
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            drop(rendezvous);
+            tx.send(()).unwrap();
+        });
+        assert_eq!(rx.recv_timeout(Duration::from_secs(30)), Ok(()));
+        drop(straggler);
+    }
+
+    #[test]
+    fn completes_without_failures() {
+        let mut rendezvous = Rendezvous::new();
+        let guard = rendezvous.fork_guard();
+        thread::spawn(move || drop(guard));
+        assert!(rendezvous.rendezvous_fail_fast().is_ok());
+    }
+
+    #[test]
+    fn failed_guards_complete_plain_rendezvous() {
+        let rendezvous = Rendezvous::new();
+        rendezvous.fork_guard().fail("ignored");
+        rendezvous.rendezvous();
+    }
+}
@@ -0,0 +1,113 @@
+//! Skipping the channel when all guards were dropped before the wait.
+
+use crate::{Rendezvous, Shared};
+use std::sync::atomic::Ordering;
+
+impl Shared {
+    /// Called after the sender of a completed guard was dropped.
+    pub(crate) fn on_settled(&self) {
+        self.settled.fetch_add(1, Ordering::SeqCst);
+    }
+
+    /// Returns whether every guard forked so far has dropped its sender.
+    ///
+    /// Unlike a live count of zero, this only holds once the guards have finished dropping,
+    /// so none of their bookkeeping is still in flight when a wait returns early.
+    pub(crate) fn is_settled(&self) -> bool {
+        self.settled.load(Ordering::SeqCst) == self.forked.load(Ordering::SeqCst)
+    }
+}
+
+impl<C> Rendezvous<C> {
+    /// Returns whether waiting for the rendezvous would complete immediately, i.e. whether all
+    /// guards forked so far were dropped.
+    ///
+    /// Unlike waiting, this does not release the sender held by the rendezvous itself, so guards
+    /// can still be forked afterwards, after which the rendezvous is no longer ready.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use rendezvous::Rendezvous;
+    ///
+    /// let rendezvous = Rendezvous::new();
+    /// let guard = rendezvous.fork_guard();
+    /// assert!(!rendezvous.is_ready());
+    ///
+    /// drop(guard);
+    /// assert!(rendezvous.is_ready());
+    /// rendezvous.rendezvous();
+    /// ```
+    pub fn is_ready(&self) -> bool {
+        self.channel.get().map_or(true, |channel| {
+            channel.shared.is_transferred() || channel.shared.is_settled()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Rendezvous;
+    use std::sync::atomic::Ordering;
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn completed_guards_skip_the_channel() {
+        let rendezvous = Rendezvous::new();
+        let guard = rendezvous.fork_guard();
+        drop(guard.fork());
+        drop(guard);
+
+        let shared = rendezvous.shared().clone();
+        rendezvous.rendezvous();
+        assert_eq!(shared.blocking_waits.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn outstanding_guards_wait_on_the_channel() {
+        let rendezvous = Rendezvous::new();
+        let guard = rendezvous.fork_guard();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(20));
+            drop(guard);
+        });
+
+        let shared = rendezvous.shared().clone();
+        rendezvous.rendezvous();
+        assert_eq!(shared.blocking_waits.load(Ordering::SeqCst), 1);
+        assert!(shared.is_settled());
+    }
+
+    #[test]
+    fn is_ready_keeps_the_rendezvous_open() {
+        let mut rendezvous = Rendezvous::new();
+        assert!(rendezvous.is_ready());
+        drop(rendezvous.fork_guard());
+        assert!(rendezvous.is_ready());
+
+        let guard = rendezvous.fork_guard();
+        assert!(!rendezvous.is_ready());
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(20));
+            drop(guard);
+        });
+        assert_eq!(
+            rendezvous.rendezvous_timeout(Duration::from_secs(5)),
+            Ok(())
+        );
+        assert!(rendezvous.is_ready());
+    }
+
+    #[test]
+    fn armed_guards_settle_once_completed() {
+        let rendezvous = Rendezvous::new();
+        let guard = rendezvous.fork_guard();
+        guard.arm_completion();
+        assert!(!rendezvous.shared().is_settled());
+        rendezvous.complete_armed();
+        assert!(rendezvous.shared().is_settled());
+        drop(guard);
+        assert!(rendezvous.shared().is_settled());
+    }
+}
@@ -0,0 +1,77 @@
+//! Aborting the process instead of hanging on leaked guards.
+
+use crate::Rendezvous;
+use std::time::Duration;
+
+impl<C> Rendezvous<C> {
+    /// Waits for all guards up to `timeout` when dropped, aborting the process if guards are
+    /// still outstanding afterwards.
+    pub(crate) fn drop_or_abort(&mut self, timeout: Duration) {
+        let Err(error) = self.rendezvous_timeout_snapshot(timeout) else {
+            return;
+        };
+        let message =
+            format!("Rendezvous guards leaked past the drop timeout of {timeout:?}: {error}");
+        #[cfg(any(feature = "log", feature = "tracing"))]
+        {
+            error!("{message}{}", self.log_context());
+            #[cfg(feature = "log")]
+            log::logger().flush();
+        }
+        eprintln!("{message}");
+        std::process::abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::RendezvousBuilder;
+    use std::process::Command;
+    use std::time::Duration;
+
+    /// The environment variable marking the subprocess that leaks a guard.
+    const CHILD: &str = "RENDEZVOUS_FATAL_LEAKS_CHILD";
+
+    #[test]
+    fn leaked_guard_aborts_the_process() {
+        if std::env::var_os(CHILD).is_some() {
+            let rendezvous = RendezvousBuilder::new()
+                .drop_timeout(Duration::from_millis(10))
+                .build();
+            let guard = rendezvous.fork_guard_labeled("leaked-worker");
+            std::mem::forget(guard);
+            drop(rendezvous);
+            unreachable!("the process should have been aborted");
+        }
+
+        let output = Command::new(std::env::current_exe().unwrap())
+            .args([
+                "--exact",
+                "fatal_leaks::tests::leaked_guard_aborts_the_process",
+            ])
+            .args(["--nocapture", "--test-threads=1"])
+            .env(CHILD, "1")
+            .output()
+            .unwrap();
+
+        assert!(!output.status.success());
+        #[cfg(unix)]
+        {
+            use std::os::unix::process::ExitStatusExt;
+            assert_eq!(output.status.signal(), Some(libc::SIGABRT));
+        }
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.contains("leaked past the drop timeout"));
+        assert!(stderr.contains("'leaked-worker'"));
+    }
+
+    #[test]
+    fn completed_rendezvous_does_not_abort() {
+        let rendezvous = RendezvousBuilder::new()
+            .drop_timeout(Duration::from_secs(5))
+            .build();
+        let guard = rendezvous.fork_guard();
+        std::thread::spawn(move || drop(guard));
+        drop(rendezvous);
+    }
+}
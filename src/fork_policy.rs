@@ -0,0 +1,194 @@
+//! Policies for forking guards after the rendezvous was waited on.
+
+use crate::{Rendezvous, RendezvousGuard, Shared};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::sync::{Arc, Mutex};
+
+/// What forking a guard off a [`Rendezvous`] does after the rendezvous was waited on; see
+/// [`RendezvousBuilder::on_fork_after_wait`](crate::RendezvousBuilder::on_fork_after_wait).
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ForkAfterWait {
+    /// Forking panics, and [`Rendezvous::try_fork_guard`] returns
+    /// [`ForkError::AlreadyRendezvoused`]. This is the default.
+    #[default]
+    Panic,
+    /// Forking logs a warning and returns a detached guard that does not keep the rendezvous
+    /// open; see [`RendezvousGuard::is_detached`].
+    Warn,
+    /// Like [`ForkAfterWait::Panic`], but the panic message points at
+    /// [`Rendezvous::try_fork_guard`] for handling the error instead.
+    Error,
+}
+
+/// Error that may occur when forking a guard using [`Rendezvous::try_fork_guard`].
+#[derive(Debug, Eq, PartialEq)]
+pub enum ForkError {
+    /// The rendezvous was already waited on.
+    AlreadyRendezvoused,
+}
+
+impl Display for ForkError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ForkError::AlreadyRendezvoused => {
+                write!(f, "Fork called after the rendezvous was waited on")
+            }
+        }
+    }
+}
+
+impl Error for ForkError {}
+
+impl<C> Rendezvous<C> {
+    /// Forks a guard off the [`Rendezvous`] channel, or returns an error if the rendezvous was
+    /// already waited on.
+    ///
+    /// This lets code wrapping a rendezvous handle forking after the wait gracefully. With the
+    /// [`ForkAfterWait::Warn`] policy, forking after the wait returns a detached guard instead
+    /// of an error. Otherwise, this behaves exactly like [`Rendezvous::fork_guard`], which
+    /// panics instead of returning an error.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use rendezvous::{ForkError, Rendezvous};
+    ///
+    /// let mut rendezvous = Rendezvous::new();
+    /// rendezvous.rendezvous_timeout(Duration::from_secs(1)).unwrap();
+    /// assert_eq!(rendezvous.try_fork_guard().err(), Some(ForkError::AlreadyRendezvoused));
+    /// ```
+    pub fn try_fork_guard(&self) -> Result<RendezvousGuard, ForkError> {
+        self.try_fork_guard_with(None)
+    }
+
+    /// Applies the [`ForkAfterWait`] policy to a fork after the rendezvous was waited on.
+    pub(crate) fn fork_after_wait(&self) -> Result<RendezvousGuard, ForkError> {
+        match self.options.fork_after_wait {
+            ForkAfterWait::Panic | ForkAfterWait::Error => Err(ForkError::AlreadyRendezvoused),
+            ForkAfterWait::Warn => {
+                #[cfg(any(feature = "log", feature = "tracing"))]
+                {
+                    warn!(
+                        "Fork called after the rendezvous was waited on, returning a detached guard{}",
+                        self.log_context()
+                    );
+                }
+                let shared = self
+                    .channel
+                    .get()
+                    .map(|channel| channel.shared.clone())
+                    .unwrap_or_default();
+                Ok(RendezvousGuard::detached(shared))
+            }
+        }
+    }
+
+    /// Unwraps the result of a fork, panicking on error.
+    pub(crate) fn expect_forked(
+        &self,
+        fork: Result<RendezvousGuard, ForkError>,
+    ) -> RendezvousGuard {
+        fork.unwrap_or_else(|error| match self.options.fork_after_wait {
+            ForkAfterWait::Error => panic!("{error}; use try_fork_guard to handle this"),
+            _ => panic!("{error}"),
+        })
+    }
+}
+
+impl RendezvousGuard {
+    /// Creates a guard that is not connected to the rendezvous channel.
+    pub(crate) fn detached(shared: Arc<Shared>) -> Self {
+        Self {
+            tx: Mutex::new(None),
+            id: shared.next_id(),
+            shared,
+            priority: None,
+            detached: true,
+        }
+    }
+
+    /// Returns whether this guard is detached, i.e. was forked after the rendezvous was waited
+    /// on using the [`ForkAfterWait::Warn`] policy, or was released using
+    /// [`RendezvousGuard::defer_release`].
+    ///
+    /// A detached guard does not keep any rendezvous open; forking it returns another detached
+    /// guard.
+    pub fn is_detached(&self) -> bool {
+        self.detached
+    }
+
+    /// Forks another detached guard off this one.
+    pub(crate) fn fork_detached(&self) -> RendezvousGuard {
+        Self::detached(self.shared.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RendezvousBuilder;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    use std::time::Duration;
+
+    fn waited_on(policy: ForkAfterWait) -> Rendezvous {
+        let mut rendezvous = RendezvousBuilder::new().on_fork_after_wait(policy).build();
+        let guard = rendezvous.fork_guard();
+        drop(guard);
+        rendezvous
+            .rendezvous_timeout(Duration::from_secs(5))
+            .unwrap();
+        rendezvous
+    }
+
+    #[test]
+    fn warn_returns_detached_guard() {
+        let mut rendezvous = waited_on(ForkAfterWait::Warn);
+        let guard = rendezvous.fork_guard();
+        assert!(guard.is_detached());
+        let nested = guard.fork();
+        assert!(nested.is_detached());
+        assert_eq!(rendezvous.remaining_guards(), 0);
+
+        // The detached guards must not block the already-completed rendezvous.
+        assert_eq!(
+            rendezvous.rendezvous_timeout(Duration::from_millis(10)),
+            Ok(())
+        );
+        assert!(rendezvous.try_fork_guard().unwrap().is_detached());
+        drop(rendezvous);
+        drop((guard, nested));
+    }
+
+    #[test]
+    fn error_policy_fails_try_fork() {
+        let rendezvous = waited_on(ForkAfterWait::Error);
+        assert_eq!(
+            rendezvous.try_fork_guard().err(),
+            Some(ForkError::AlreadyRendezvoused)
+        );
+    }
+
+    #[test]
+    fn panic_policy_fails_try_fork_and_panics_on_fork() {
+        let rendezvous = waited_on(ForkAfterWait::Panic);
+        assert_eq!(
+            rendezvous.try_fork_guard().err(),
+            Some(ForkError::AlreadyRendezvoused)
+        );
+        let result = catch_unwind(AssertUnwindSafe(|| rendezvous.fork_guard()));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn try_fork_before_wait_succeeds() {
+        let rendezvous = RendezvousBuilder::new()
+            .on_fork_after_wait(ForkAfterWait::Error)
+            .build();
+        let guard = rendezvous.try_fork_guard().unwrap();
+        assert!(!guard.is_detached());
+        drop(guard);
+        rendezvous.rendezvous();
+    }
+}
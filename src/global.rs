@@ -0,0 +1,140 @@
+//! A process-global registry of all live rendezvous.
+
+use crate::{lock, GuardSnapshot, Shared};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, Weak};
+
+/// All registered rendezvous; entries of dropped rendezvous are pruned lazily.
+static REGISTRY: Mutex<Vec<Weak<Registration>>> = Mutex::new(Vec::new());
+
+/// The registration of a [`Rendezvous`](crate::Rendezvous) in the global registry, owned by
+/// the rendezvous.
+pub(crate) struct Registration {
+    /// The name the rendezvous was built with, if any.
+    name: Option<String>,
+    /// The current round of the rendezvous.
+    round: AtomicU64,
+    /// The shared state of the current round, if the channel was created.
+    shared: Mutex<Weak<Shared>>,
+}
+
+impl Registration {
+    /// Registers a new rendezvous named `name`.
+    pub(crate) fn register(name: Option<String>) -> Arc<Self> {
+        let registration = Arc::new(Self {
+            name,
+            round: AtomicU64::new(0),
+            shared: Mutex::new(Weak::new()),
+        });
+        let mut registry = lock(&REGISTRY);
+        registry.retain(|entry| entry.strong_count() > 0);
+        registry.push(Arc::downgrade(&registration));
+        registration
+    }
+
+    /// Records the current `round` and the shared state of its channel, if created.
+    pub(crate) fn update(&self, round: u64, shared: Option<&Arc<Shared>>) {
+        self.round.store(round, Ordering::Relaxed);
+        *lock(&self.shared) = shared.map_or_else(Weak::new, Arc::downgrade);
+    }
+
+    /// Takes a snapshot of the registered rendezvous.
+    fn snapshot(&self) -> RendezvousSnapshot {
+        let shared = lock(&self.shared).upgrade();
+        RendezvousSnapshot {
+            name: self.name.clone(),
+            round: self.round.load(Ordering::Relaxed),
+            forked: shared
+                .as_ref()
+                .map_or(0, |shared| shared.forked.load(Ordering::Acquire)),
+            remaining: shared.as_ref().map_or(0, |shared| shared.live_guards()),
+            outstanding: shared.map_or_else(Vec::new, |shared| shared.snapshot()),
+        }
+    }
+}
+
+/// A snapshot of a live [`Rendezvous`](crate::Rendezvous), taken using [`dump_all`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct RendezvousSnapshot {
+    /// The name the rendezvous was built with using
+    /// [`RendezvousBuilder::name`](crate::RendezvousBuilder::name), if any.
+    pub name: Option<String>,
+    /// The current [round](crate::Rendezvous::round) of the rendezvous.
+    pub round: u64,
+    /// The number of guards forked in the current round.
+    pub forked: usize,
+    /// The number of guards forked but not yet dropped.
+    pub remaining: usize,
+    /// Snapshots of all outstanding guards, ordered by id.
+    pub outstanding: Vec<GuardSnapshot>,
+}
+
+/// Returns snapshots of all currently live rendezvous in the process, in creation order.
+///
+/// Every rendezvous registers itself on creation and is removed once dropped. This is meant for
+/// debug endpoints diagnosing a hung process from a single call.
+///
+/// The registry costs one allocation and a lock of a process-global mutex per created
+/// rendezvous, which serializes the creation of rendezvous across threads; dumping locks the
+/// bookkeeping of every live rendezvous in turn. Since the snapshots are taken one after
+/// another, they are not a consistent view of the whole process.
+///
+/// ## Example
+///
+/// ```
+/// use rendezvous::RendezvousBuilder;
+///
+/// let rendezvous = RendezvousBuilder::new().name("ingest").build();
+/// let guard = rendezvous.fork_guard();
+///
+/// let ingest = rendezvous::dump_all()
+///     .into_iter()
+///     .find(|snapshot| snapshot.name.as_deref() == Some("ingest"))
+///     .unwrap();
+/// assert_eq!(ingest.remaining, 1);
+/// drop(guard);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "global-registry")))]
+pub fn dump_all() -> Vec<RendezvousSnapshot> {
+    let registrations: Vec<_> = lock(&REGISTRY).iter().filter_map(Weak::upgrade).collect();
+    registrations
+        .iter()
+        .map(|registration| registration.snapshot())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RendezvousBuilder;
+
+    fn find(name: &str) -> Option<RendezvousSnapshot> {
+        dump_all()
+            .into_iter()
+            .find(|snapshot| snapshot.name.as_deref() == Some(name))
+    }
+
+    #[test]
+    fn dump_lists_live_rendezvous() {
+        let mut first = RendezvousBuilder::new().name("dump-first-41a2").build();
+        let second = RendezvousBuilder::new().name("dump-second-41a2").build();
+        let guard = first.fork_guard_labeled("worker");
+
+        let snapshot = find("dump-first-41a2").unwrap();
+        assert_eq!(snapshot.remaining, 1);
+        assert_eq!(snapshot.outstanding[0].label.as_deref(), Some("worker"));
+        assert_eq!(find("dump-second-41a2").unwrap().forked, 0);
+
+        drop(guard);
+        first.rendezvous_ref();
+        first.reset().unwrap();
+        first.fork_guard().completed();
+        let snapshot = find("dump-first-41a2").unwrap();
+        assert_eq!((snapshot.round, snapshot.forked), (1, 1));
+
+        drop(first);
+        assert!(find("dump-first-41a2").is_none());
+        assert!(find("dump-second-41a2").is_some());
+        second.rendezvous();
+    }
+}
@@ -0,0 +1,277 @@
+//! Sharing a rendezvous point between several [`Rendezvous`] handles.
+
+use crate::bridge::SignalReceiver;
+use crate::{lock, Channel, Rendezvous};
+use std::cell::{OnceCell, RefCell};
+use std::ops::Deref;
+use std::sync::atomic::Ordering;
+use std::sync::{Arc, MutexGuard, PoisonError};
+use std::time::Instant;
+
+/// The receiver of a channel, held by the handle currently waiting on it.
+///
+/// Releasing the receiver wakes the handles waiting for it.
+pub(crate) struct ReceiverGuard<'a> {
+    /// The locked receiver.
+    rx: MutexGuard<'a, SignalReceiver>,
+    /// The channel the receiver belongs to.
+    channel: &'a Channel,
+}
+
+impl Deref for ReceiverGuard<'_> {
+    type Target = SignalReceiver;
+
+    fn deref(&self) -> &Self::Target {
+        &self.rx
+    }
+}
+
+impl Drop for ReceiverGuard<'_> {
+    fn drop(&mut self) {
+        *lock(&self.channel.rx_busy) = false;
+        // Waiters whose deadline passed leave without taking the receiver, so all are woken.
+        self.channel.rx_released.notify_all();
+    }
+}
+
+impl Channel {
+    /// Returns whether the original sender was released by any handle.
+    pub(crate) fn is_released(&self) -> bool {
+        lock(&self.tx).is_none()
+    }
+
+    /// Unregisters a dropped handle, returning whether it was the last one.
+    pub(crate) fn release_handle(&self) -> bool {
+        self.handles.fetch_sub(1, Ordering::AcqRel) == 1
+    }
+
+    /// Takes the receiver, blocking while another clone holds it.
+    pub(crate) fn lock_receiver(&self) -> ReceiverGuard<'_> {
+        let mut busy = lock(&self.rx_busy);
+        while *busy {
+            busy = self
+                .rx_released
+                .wait(busy)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+        self.take_receiver(busy)
+    }
+
+    /// Takes the receiver, giving up at `deadline` if another clone keeps holding it.
+    pub(crate) fn lock_receiver_until(&self, deadline: Instant) -> Option<ReceiverGuard<'_>> {
+        let mut busy = lock(&self.rx_busy);
+        while *busy {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return None;
+            }
+            busy = self
+                .rx_released
+                .wait_timeout(busy, remaining)
+                .unwrap_or_else(PoisonError::into_inner)
+                .0;
+        }
+        Some(self.take_receiver(busy))
+    }
+
+    /// Takes the receiver if no other clone holds it.
+    pub(crate) fn try_lock_receiver(&self) -> Option<ReceiverGuard<'_>> {
+        let busy = lock(&self.rx_busy);
+        if *busy {
+            return None;
+        }
+        Some(self.take_receiver(busy))
+    }
+
+    /// Takes the receiver, given the lock on its busy flag while the flag is unset.
+    fn take_receiver(&self, mut busy: MutexGuard<'_, bool>) -> ReceiverGuard<'_> {
+        *busy = true;
+        drop(busy);
+        ReceiverGuard {
+            rx: lock(&self.rx),
+            channel: self,
+        }
+    }
+}
+
+/// Creates another handle to the same rendezvous point.
+///
+/// Guards forked off any clone count towards the same rendezvous, and waiting on any clone
+/// waits for the guards forked off all of them. This allows handing the rendezvous to several
+/// coordinators that each fork guards independently.
+///
+/// The clones share a single original sender, which the first wait on any clone releases, so a
+/// wait only depends on the guards: other clones neither need to wait nor to be dropped for it
+/// to complete. Afterwards, forking off any clone behaves like forking after a wait, see
+/// [`RendezvousBuilder::on_fork_after_wait`](crate::RendezvousBuilder::on_fork_after_wait).
+/// If two clones wait concurrently, e.g. both call [`Rendezvous::rendezvous`], both block until
+/// all guards are dropped; their waits are serialized internally, so signals such as
+/// [pings](crate::RendezvousGuard::ping) and [failures](crate::RendezvousGuard::fail) are
+/// observed by only one of them. A timed
+/// wait still honors its timeout while another clone waits.
+///
+/// Dropping a clone without waiting does not block unless it is the last handle, which
+/// completes the rendezvous as usual. Per-handle state, such as spawned threads, the thread
+/// pool and wait statistics, is not shared; a [reset](Rendezvous::reset) is refused while
+/// other clones are alive.
+///
+/// ## Example
+///
+/// ```
+/// use std::thread;
+/// use rendezvous::Rendezvous;
+///
+/// let rendezvous = Rendezvous::new();
+/// let coordinator = rendezvous.clone();
+/// thread::spawn(move || {
+///     let guard = coordinator.fork_guard();
+///     thread::spawn(move || drop(guard));
+/// });
+///
+/// let guard = rendezvous.fork_guard();
+/// thread::spawn(move || drop(guard));
+/// rendezvous.rendezvous();
+/// ```
+impl<C> Clone for Rendezvous<C>
+where
+    C: Clone,
+{
+    fn clone(&self) -> Self {
+        let channel = Arc::clone(self.channel());
+        channel.handles.fetch_add(1, Ordering::AcqRel);
+        Self {
+            channel: OnceCell::from(channel),
+            options: self.options.clone(),
+            round: self.round,
+            released: false,
+            abandoned: false,
+            #[cfg(feature = "hist")]
+            wait_latencies: Default::default(),
+            wakeup_latency: None,
+            spawned: RefCell::new(Vec::new()),
+            pool: OnceCell::new(),
+            context: self.context.clone(),
+            #[cfg(any(feature = "log", feature = "tracing"))]
+            describe_context: self.describe_context,
+            #[cfg(feature = "global-registry")]
+            registration: self.registration.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{RendezvousTimeoutError, ResetError};
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn clones_share_the_rendezvous_point() {
+        let mut rendezvous = Rendezvous::new();
+        let clone = rendezvous.clone();
+        let first = rendezvous.fork_guard();
+        let second = clone.fork_guard();
+        assert_eq!(rendezvous.remaining_guards(), 2);
+        assert_eq!(clone.remaining_guards(), 2);
+
+        drop(first);
+        assert_eq!(
+            rendezvous.rendezvous_timeout(Duration::from_millis(10)),
+            Err(RendezvousTimeoutError::Timeout)
+        );
+
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(20));
+            drop(second);
+        });
+        clone.rendezvous();
+        assert_eq!(
+            rendezvous.rendezvous_timeout(Duration::from_secs(5)),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn waiting_does_not_depend_on_other_clones() {
+        let rendezvous = Rendezvous::new();
+        // Held for the entire test without waiting on it.
+        let idle = rendezvous.clone();
+        let guard = idle.fork_guard();
+        thread::spawn(move || drop(guard));
+
+        let mut waiting = rendezvous.clone();
+        assert_eq!(waiting.rendezvous_timeout(Duration::from_secs(30)), Ok(()));
+        rendezvous.rendezvous();
+        assert_eq!(idle.remaining_guards(), 0);
+    }
+
+    #[test]
+    fn concurrent_waits_on_clones_complete() {
+        let rendezvous = Rendezvous::new();
+        let guard = rendezvous.fork_guard();
+        let waiters: Vec<_> = (0..2)
+            .map(|_| {
+                let clone = rendezvous.clone();
+                thread::spawn(move || clone.rendezvous())
+            })
+            .collect();
+
+        thread::sleep(Duration::from_millis(20));
+        drop(guard);
+        for waiter in waiters {
+            waiter.join().unwrap();
+        }
+        rendezvous.rendezvous();
+    }
+
+    #[test]
+    fn timed_wait_honors_its_timeout_while_a_clone_waits() {
+        let mut rendezvous = Rendezvous::new();
+        let guard = rendezvous.fork_guard();
+        let clone = rendezvous.clone();
+        let waiter = thread::spawn(move || clone.rendezvous());
+        let channel = Arc::clone(rendezvous.channel());
+        while !*lock(&channel.rx_busy) {
+            thread::yield_now();
+        }
+
+        assert_eq!(
+            rendezvous.rendezvous_timeout(Duration::from_millis(20)),
+            Err(RendezvousTimeoutError::Timeout)
+        );
+
+        // The wait takes over the receiver once the clone releases it.
+        thread::spawn(move || drop(guard));
+        assert_eq!(
+            rendezvous.rendezvous_timeout(Duration::from_secs(30)),
+            Ok(())
+        );
+        waiter.join().unwrap();
+    }
+
+    #[test]
+    fn dropping_a_clone_does_not_block() {
+        let rendezvous = Rendezvous::new();
+        let guard = rendezvous.fork_guard();
+
+        let started = Instant::now();
+        drop(rendezvous.clone());
+        assert!(started.elapsed() < Duration::from_secs(1));
+
+        let late = rendezvous.fork_guard();
+        drop((guard, late));
+        rendezvous.rendezvous();
+    }
+
+    #[test]
+    fn reset_is_refused_while_clones_are_alive() {
+        let mut rendezvous = Rendezvous::new();
+        let clone = rendezvous.clone();
+        assert_eq!(rendezvous.reset(), Err(ResetError::Cloned));
+        drop(clone);
+        rendezvous.rendezvous_ref();
+        assert_eq!(rendezvous.reset(), Ok(()));
+        rendezvous.rendezvous();
+    }
+}
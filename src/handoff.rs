@@ -0,0 +1,136 @@
+//! Handing guards from a producer to a consumer through a channel.
+
+use crate::{Rendezvous, RendezvousGuard};
+use std::sync::mpsc::{self, SendError};
+
+/// The sending half of a guard channel created by [`Rendezvous::guard_channel`].
+///
+/// The sender holds a guard of its own to be able to fork further guards; the rendezvous
+/// is therefore kept open until the sender is dropped.
+#[derive(Clone)]
+pub struct GuardSender {
+    /// The guard new guards are forked off.
+    guard: RendezvousGuard,
+    /// The channel forked guards are sent to.
+    tx: mpsc::Sender<RendezvousGuard>,
+}
+
+/// The receiving half of a guard channel created by [`Rendezvous::guard_channel`].
+pub struct GuardReceiver {
+    /// The channel forked guards are received from.
+    rx: mpsc::Receiver<RendezvousGuard>,
+}
+
+impl<C> Rendezvous<C> {
+    /// Creates a channel for handing forked guards from a producer to a consumer.
+    ///
+    /// The [`GuardSender`] forks guards off this rendezvous and sends them to the
+    /// [`GuardReceiver`], which takes ownership of them. This decouples guard creation
+    /// from guard ownership across threads.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::thread;
+    /// use rendezvous::Rendezvous;
+    ///
+    /// let rendezvous = Rendezvous::new();
+    /// let (sender, receiver) = rendezvous.guard_channel();
+    ///
+    /// let consumer = thread::spawn(move || {
+    ///     for guard in receiver {
+    ///         guard.completed();
+    ///     }
+    /// });
+    ///
+    /// sender.fork_and_send().unwrap();
+    /// drop(sender);
+    ///
+    /// rendezvous.rendezvous();
+    /// consumer.join().unwrap();
+    /// ```
+    pub fn guard_channel(&self) -> (GuardSender, GuardReceiver) {
+        let (tx, rx) = mpsc::channel();
+        let sender = GuardSender {
+            guard: self.fork_guard(),
+            tx,
+        };
+        (sender, GuardReceiver { rx })
+    }
+}
+
+impl GuardSender {
+    /// Forks a guard and sends it to the [`GuardReceiver`].
+    ///
+    /// If the receiver was dropped, the guard is returned in the error; dropping it
+    /// releases it.
+    pub fn fork_and_send(&self) -> Result<(), SendError<RendezvousGuard>> {
+        self.tx.send(self.guard.fork())
+    }
+}
+
+impl GuardReceiver {
+    /// Blocks until a guard is received, or returns `None` once all senders are dropped.
+    pub fn recv(&self) -> Option<RendezvousGuard> {
+        self.rx.recv().ok()
+    }
+
+    /// Returns a guard if one is available without blocking.
+    pub fn try_recv(&self) -> Option<RendezvousGuard> {
+        self.rx.try_recv().ok()
+    }
+}
+
+impl IntoIterator for GuardReceiver {
+    type Item = RendezvousGuard;
+    type IntoIter = mpsc::IntoIter<RendezvousGuard>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.rx.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn guards_handed_to_consumer() {
+        let mut rendezvous = Rendezvous::new();
+        let (sender, receiver) = rendezvous.guard_channel();
+
+        let producer = thread::spawn(move || {
+            sender.fork_and_send().unwrap();
+            sender.fork_and_send().unwrap();
+        });
+
+        let consumer = thread::spawn(move || {
+            let mut received = 0;
+            while let Some(guard) = receiver.recv() {
+                thread::sleep(Duration::from_millis(10));
+                drop(guard);
+                received += 1;
+            }
+            received
+        });
+
+        producer.join().unwrap();
+        assert_eq!(
+            rendezvous.rendezvous_timeout(Duration::from_secs(5)),
+            Ok(())
+        );
+        assert_eq!(consumer.join().unwrap(), 2);
+    }
+
+    #[test]
+    fn send_fails_after_receiver_dropped() {
+        let rendezvous = Rendezvous::new();
+        let (sender, receiver) = rendezvous.guard_channel();
+        drop(receiver);
+        assert!(sender.fork_and_send().is_err());
+        drop(sender);
+        rendezvous.rendezvous();
+    }
+}
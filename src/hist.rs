@@ -0,0 +1,150 @@
+//! A lightweight, log-linear latency histogram used to track wait latencies.
+
+use crate::Rendezvous;
+use std::time::Duration;
+
+/// The number of bits used for the linear sub-buckets within each power of two.
+const SUB_BUCKET_BITS: u32 = 4;
+
+/// The number of linear sub-buckets within each power of two.
+const SUB_BUCKETS: usize = 1 << SUB_BUCKET_BITS;
+
+/// The total number of buckets required to cover all `u64` nanosecond values.
+const BUCKETS: usize = (64 - SUB_BUCKET_BITS as usize + 1) * SUB_BUCKETS;
+
+/// A histogram of durations with a relative precision of 1/16 (about 6%).
+///
+/// Values are recorded in nanoseconds. Values below 16ns are recorded exactly; larger values
+/// are grouped into 16 linear sub-buckets per power of two, similar to an HDR histogram.
+/// The buckets are only allocated once the first value is recorded.
+#[derive(Default)]
+pub(crate) struct LatencyHistogram {
+    /// The bucket counts; empty until the first value is recorded.
+    counts: Vec<u64>,
+    /// The total number of recorded values.
+    total: u64,
+}
+
+impl LatencyHistogram {
+    /// Records a single duration.
+    pub fn record(&mut self, duration: Duration) {
+        if self.counts.is_empty() {
+            self.counts = vec![0; BUCKETS];
+        }
+        let nanos = u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX);
+        self.counts[bucket_index(nanos)] += 1;
+        self.total += 1;
+    }
+
+    /// Returns the value at the given percentile, where `p` is in the range `0.0..=1.0`.
+    ///
+    /// Returns `None` if no value was recorded.
+    pub fn percentile(&self, p: f64) -> Option<Duration> {
+        if self.total == 0 {
+            return None;
+        }
+        let p = p.clamp(0.0, 1.0);
+        let rank = ((p * self.total as f64).ceil() as u64).max(1);
+        let mut seen = 0;
+        for (index, count) in self.counts.iter().enumerate() {
+            seen += count;
+            if seen >= rank {
+                return Some(Duration::from_nanos(bucket_value(index)));
+            }
+        }
+        None
+    }
+}
+
+/// Returns the bucket index for a value.
+fn bucket_index(value: u64) -> usize {
+    if value < SUB_BUCKETS as u64 {
+        return value as usize;
+    }
+    let exponent = 63 - value.leading_zeros();
+    let shift = exponent - SUB_BUCKET_BITS;
+    let sub_bucket = ((value >> shift) as usize) & (SUB_BUCKETS - 1);
+    (exponent - SUB_BUCKET_BITS + 1) as usize * SUB_BUCKETS + sub_bucket
+}
+
+/// Returns the lowest value recorded into the bucket at `index`.
+fn bucket_value(index: usize) -> u64 {
+    if index < SUB_BUCKETS {
+        return index as u64;
+    }
+    let exponent = (index / SUB_BUCKETS) as u32 + SUB_BUCKET_BITS - 1;
+    let sub_bucket = (index % SUB_BUCKETS) as u64;
+    (SUB_BUCKETS as u64 + sub_bucket) << (exponent - SUB_BUCKET_BITS)
+}
+
+impl<C> Rendezvous<C> {
+    /// Returns the wait latency at percentile `p` (in the range `0.0..=1.0`) over all waits
+    /// performed on this instance, or `None` if no wait was performed yet.
+    ///
+    /// Every call to a waiting method taking `&mut self`, such as
+    /// [`Rendezvous::rendezvous_timeout`], records one sample, regardless of whether the wait
+    /// completed or timed out. Values outside the range of `p` are clamped. The reported
+    /// latency is the lower bound of its histogram bucket, which is accurate to about 6%.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use rendezvous::Rendezvous;
+    ///
+    /// let mut rendezvous = Rendezvous::new();
+    /// assert_eq!(rendezvous.wait_latency_percentile(0.5), None);
+    ///
+    /// rendezvous.rendezvous_timeout(Duration::from_secs(1)).ok();
+    /// assert!(rendezvous.wait_latency_percentile(0.5).is_some());
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "hist")))]
+    pub fn wait_latency_percentile(&self, p: f64) -> Option<Duration> {
+        self.wait_latencies.percentile(p)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bucket_index_and_value_round_trip() {
+        for value in [0, 1, 15, 16, 17, 31, 32, 1_000, 123_456_789, u64::MAX] {
+            let lower = bucket_value(bucket_index(value));
+            assert!(lower <= value);
+            assert!(value - lower <= value / SUB_BUCKETS as u64);
+        }
+        assert!(bucket_index(u64::MAX) < BUCKETS);
+    }
+
+    #[test]
+    fn percentile_of_recorded_values() {
+        let mut histogram = LatencyHistogram::default();
+        assert_eq!(histogram.percentile(0.5), None);
+
+        for millis in 1..=100 {
+            histogram.record(Duration::from_millis(millis));
+        }
+        let median = histogram.percentile(0.5).unwrap();
+        assert!(median >= Duration::from_millis(47) && median <= Duration::from_millis(50));
+        let max = histogram.percentile(1.0).unwrap();
+        assert!(max >= Duration::from_millis(94) && max <= Duration::from_millis(100));
+    }
+
+    #[test]
+    fn wait_latency_percentile_over_rounds() {
+        let mut rendezvous = Rendezvous::new();
+        let guard = rendezvous.fork_guard();
+        for _ in 0..5 {
+            rendezvous
+                .rendezvous_timeout(Duration::from_millis(20))
+                .ok();
+        }
+        drop(guard);
+
+        let median = rendezvous.wait_latency_percentile(0.5).unwrap();
+        assert!(median >= Duration::from_millis(18), "{median:?}");
+        assert!(median < Duration::from_millis(200), "{median:?}");
+    }
+}
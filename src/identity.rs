@@ -0,0 +1,63 @@
+//! Comparing and hashing guards by identity.
+
+use crate::RendezvousGuard;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+impl PartialEq for RendezvousGuard {
+    /// Two guards are equal if they are the same guard, i.e. belong to the same rendezvous and
+    /// have the same [id](RendezvousGuard::id).
+    ///
+    /// Since guards are not [`Copy`], equality only arises when comparing a guard with
+    /// itself, e.g. through references stored in collections.
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id && Arc::ptr_eq(&self.shared, &other.shared)
+    }
+}
+
+impl Eq for RendezvousGuard {}
+
+impl Hash for RendezvousGuard {
+    /// Hashes the [id](RendezvousGuard::id) of the guard.
+    ///
+    /// The id never changes, so guards are suitable as keys despite their interior mutability;
+    /// Clippy's `mutable_key_type` lint can safely be allowed for them.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Rendezvous;
+    use std::collections::HashMap;
+
+    #[test]
+    // The hash only covers the immutable id, not the interior mutability of the guard.
+    #[allow(clippy::mutable_key_type)]
+    fn guards_key_a_map_by_identity() {
+        let rendezvous = Rendezvous::new();
+        let guard = rendezvous.fork_guard();
+        let nested = guard.fork();
+        assert_ne!(guard.id(), nested.id());
+        assert_ne!(guard, nested);
+        assert_eq!(guard, guard);
+
+        let other = Rendezvous::new();
+        let foreign = other.fork_guard();
+        assert_eq!(guard.id(), foreign.id());
+        assert_ne!(guard, foreign);
+
+        let mut workers = HashMap::new();
+        workers.insert(&guard, "parent");
+        workers.insert(&nested, "child");
+        workers.insert(&foreign, "foreign");
+        assert_eq!(workers.len(), 3);
+        assert_eq!(workers[&nested], "child");
+
+        drop(workers);
+        drop((guard, nested, foreign));
+        rendezvous.rendezvous();
+        other.rendezvous();
+    }
+}
@@ -0,0 +1,171 @@
+//! Deduplicating guards by a logical key.
+
+use crate::{lock, GuardId, Rendezvous, RendezvousGuard, Shared};
+use std::any::Any;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::Ordering;
+
+/// A type-erased guard key.
+trait DynKey: Send {
+    /// Compares this key to another, type-erased key.
+    fn eq_dyn(&self, other: &dyn DynKey) -> bool;
+
+    /// Returns this key as [`Any`] for downcasting.
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<K> DynKey for K
+where
+    K: Hash + Eq + Send + 'static,
+{
+    fn eq_dyn(&self, other: &dyn DynKey) -> bool {
+        other.as_any().downcast_ref::<K>() == Some(self)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// The keys of outstanding guards sharing the same hash.
+type KeyBucket = Vec<(GuardId, Box<dyn DynKey>)>;
+
+/// The keys of all outstanding keyed guards.
+#[derive(Default)]
+pub(crate) struct KeyRegistry {
+    /// The keys of outstanding guards, bucketed by their hash.
+    buckets: HashMap<u64, KeyBucket>,
+    /// The key hash of each outstanding keyed guard.
+    hashes: HashMap<GuardId, u64>,
+}
+
+impl KeyRegistry {
+    /// Returns whether an outstanding guard holds `key`.
+    fn contains(&self, hash: u64, key: &dyn DynKey) -> bool {
+        self.buckets
+            .get(&hash)
+            .is_some_and(|bucket| bucket.iter().any(|(_, other)| other.eq_dyn(key)))
+    }
+
+    /// Associates `key` with the guard `id`.
+    fn insert(&mut self, id: GuardId, hash: u64, key: Box<dyn DynKey>) {
+        self.buckets.entry(hash).or_default().push((id, key));
+        self.hashes.insert(id, hash);
+    }
+
+    /// Frees the key held by the guard `id`, if any.
+    fn remove(&mut self, id: GuardId) {
+        let Some(hash) = self.hashes.remove(&id) else {
+            return;
+        };
+        if let Some(bucket) = self.buckets.get_mut(&hash) {
+            bucket.retain(|(guard, _)| *guard != id);
+            if bucket.is_empty() {
+                self.buckets.remove(&hash);
+            }
+        }
+    }
+}
+
+/// Computes the hash of a key, including its type.
+fn hash_key<K: Hash + 'static>(key: &K) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    std::any::TypeId::of::<K>().hash(&mut hasher);
+    key.hash(&mut hasher);
+    hasher.finish()
+}
+
+impl<C> Rendezvous<C> {
+    /// Forks a guard associated with a logical `key`, unless an outstanding guard already
+    /// holds an equal key.
+    ///
+    /// Returns `None` if a live guard with that key exists. The key is freed when the guard
+    /// is dropped, so the key can be forked again afterwards. This enforces at most one guard in
+    /// flight per key, e.g. in idempotent task systems. Keys of different types never compare
+    /// equal.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use rendezvous::Rendezvous;
+    ///
+    /// let rendezvous = Rendezvous::new();
+    /// let guard = rendezvous.fork_guard_keyed("task-1").unwrap();
+    /// assert!(rendezvous.fork_guard_keyed("task-1").is_none());
+    ///
+    /// drop(guard);
+    /// assert!(rendezvous.fork_guard_keyed("task-1").is_some());
+    /// # rendezvous.rendezvous();
+    /// ```
+    pub fn fork_guard_keyed<K>(&self, key: K) -> Option<RendezvousGuard>
+    where
+        K: Hash + Eq + Send + 'static,
+    {
+        let hash = hash_key(&key);
+        let shared = self.shared();
+        let mut keys = lock(&shared.keys);
+        if keys.contains(hash, &key) {
+            #[cfg(any(feature = "log", feature = "tracing"))]
+            {
+                trace!(
+                    "Rendezvous guard key is already in use{}",
+                    self.log_context()
+                );
+            }
+            return None;
+        }
+        let guard = self.fork_guard();
+        shared.keyed.store(true, Ordering::Release);
+        keys.insert(guard.id(), hash, Box::new(key));
+        Some(guard)
+    }
+}
+
+impl Shared {
+    /// Frees the key held by the guard `id`, if any.
+    ///
+    /// This takes no lock unless a keyed guard was ever forked.
+    pub(crate) fn release_key(&self, id: GuardId) {
+        if self.keyed.load(Ordering::Acquire) {
+            lock(&self.keys).remove(id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keyed_guards_are_deduplicated() {
+        let rendezvous = Rendezvous::new();
+
+        let first = rendezvous.fork_guard_keyed(42_u32).unwrap();
+        assert!(rendezvous.fork_guard_keyed(42_u32).is_none());
+
+        // Different keys and different key types do not collide.
+        let other = rendezvous.fork_guard_keyed(43_u32).unwrap();
+        let other_type = rendezvous.fork_guard_keyed(42_u64).unwrap();
+
+        drop(first);
+        let again = rendezvous.fork_guard_keyed(42_u32).unwrap();
+
+        drop((other, other_type, again));
+        rendezvous.rendezvous();
+    }
+
+    #[test]
+    fn key_of_forked_child_is_not_inherited() {
+        let rendezvous = Rendezvous::new();
+        let parent = rendezvous.fork_guard_keyed("key").unwrap();
+        let child = parent.fork();
+        drop(parent);
+
+        // The key is freed with the guard that held it, not with its children.
+        let again = rendezvous.fork_guard_keyed("key").unwrap();
+        drop((child, again));
+        rendezvous.rendezvous();
+    }
+}
@@ -0,0 +1,98 @@
+//! Logging the latency bucket of each guard's lifetime.
+
+use crate::{GuardId, Shared};
+use std::time::Duration;
+
+impl Shared {
+    /// Logs the latency bucket the lifetime of the guard `id` fell into, if buckets are
+    /// configured.
+    pub(crate) fn log_latency_bucket(&self, id: GuardId) {
+        let Some(bounds) = &self.options.latency_buckets else {
+            return;
+        };
+        if !enabled!(debug) {
+            return;
+        }
+        let Some(forked_at) = self.forked_at(id) else {
+            return;
+        };
+        let label = bucket_label(bounds, forked_at.elapsed());
+        debug!(
+            "Rendezvous guard {id} completed in the {label} bucket [round {}]",
+            self.round
+        );
+    }
+}
+
+/// Returns the label of the bucket `lifetime` falls into, given the sorted bucket `bounds`.
+fn bucket_label(bounds: &[Duration], lifetime: Duration) -> String {
+    let index = bounds.partition_point(|bound| *bound <= lifetime);
+    match (
+        index.checked_sub(1).map(|lower| bounds[lower]),
+        bounds.get(index),
+    ) {
+        (None, Some(upper)) => format!("<{upper:?}"),
+        (Some(lower), Some(upper)) => range_label(lower, *upper),
+        (Some(lower), None) => format!(">={lower:?}"),
+        (None, None) => String::from("all"),
+    }
+}
+
+/// Renders the range from `lower` to `upper`, sharing the unit if both use the same one.
+fn range_label(lower: Duration, upper: Duration) -> String {
+    let (lower, upper) = (format!("{lower:?}"), format!("{upper:?}"));
+    let unit = |value: &str| {
+        value
+            .trim_start_matches(|c: char| c.is_ascii_digit() || c == '.')
+            .to_owned()
+    };
+    let (lower_unit, upper_unit) = (unit(&lower), unit(&upper));
+    if lower_unit == upper_unit {
+        format!("{}-{upper}", lower.trim_end_matches(lower_unit.as_str()))
+    } else {
+        format!("{lower}-{upper}")
+    }
+}
+
+#[cfg(all(test, feature = "log"))]
+mod tests {
+    use super::*;
+    use crate::{test_log, RendezvousBuilder};
+    use std::thread;
+
+    #[test]
+    fn labels_buckets() {
+        let bounds = [Duration::from_millis(10), Duration::from_millis(100)];
+        let label = |millis| bucket_label(&bounds, Duration::from_millis(millis));
+        assert_eq!(label(5), "<10ms");
+        assert_eq!(label(10), "10-100ms");
+        assert_eq!(label(50), "10-100ms");
+        assert_eq!(label(100), ">=100ms");
+
+        let bounds = [Duration::from_micros(500), Duration::from_secs(1)];
+        assert_eq!(bucket_label(&bounds, Duration::from_millis(1)), "500µs-1s");
+        assert_eq!(bucket_label(&[], Duration::from_millis(1)), "all");
+    }
+
+    #[test]
+    fn guard_drop_logs_its_bucket() {
+        test_log::init();
+
+        let rendezvous = RendezvousBuilder::new()
+            .latency_buckets(vec![Duration::from_millis(100), Duration::from_millis(10)])
+            .build();
+        let guard = rendezvous.fork_guard();
+        thread::sleep(Duration::from_millis(50));
+        drop(guard);
+        rendezvous.rendezvous();
+
+        let messages = test_log::messages_containing("completed in the 10-100ms bucket");
+        assert_eq!(
+            messages,
+            vec![(
+                log::Level::Debug,
+                String::from("Rendezvous guard #0 completed in the 10-100ms bucket [round 0]")
+            )]
+        );
+    }
+}
@@ -0,0 +1,121 @@
+//! An append-only, user-owned log of guard lifecycle events.
+
+use crate::{lock, GuardId, Rendezvous, Shared};
+use std::sync::atomic::Ordering;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+/// A user-owned ledger that guard lifecycle events are appended to.
+pub type Ledger = Arc<Mutex<Vec<LedgerEntry>>>;
+
+/// A lifecycle event recorded in a [`Ledger`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum LedgerEvent {
+    /// A guard was forked.
+    Forked,
+    /// A guard was dropped.
+    Completed,
+}
+
+/// An entry of a [`Ledger`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct LedgerEntry {
+    /// The event that occurred.
+    pub event: LedgerEvent,
+    /// The guard the event occurred on.
+    pub guard_id: GuardId,
+    /// The round of the rendezvous the guard belongs to; see [`Rendezvous::round`].
+    pub round: u64,
+    /// The point in time the event occurred at.
+    pub at: Instant,
+}
+
+impl<C> Rendezvous<C> {
+    /// Appends a timestamped [`LedgerEntry`] to `ledger` whenever a guard of this rendezvous
+    /// is forked or dropped.
+    ///
+    /// Unlike internal bookkeeping, the ledger is owned by the caller and survives the
+    /// rendezvous being dropped. Only guards forked after this call are recorded; setting a
+    /// ledger again replaces the previous one. Guards are recorded in
+    /// [minimal](crate::RendezvousBuilder::minimal) mode, too.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::sync::{Arc, Mutex};
+    /// use rendezvous::{LedgerEvent, Rendezvous};
+    ///
+    /// let ledger = Arc::new(Mutex::new(Vec::new()));
+    ///
+    /// let rendezvous = Rendezvous::new();
+    /// rendezvous.with_ledger(ledger.clone());
+    /// rendezvous.fork_guard().completed();
+    /// rendezvous.rendezvous();
+    ///
+    /// let ledger = ledger.lock().unwrap();
+    /// assert_eq!(ledger[0].event, LedgerEvent::Forked);
+    /// assert_eq!(ledger[1].event, LedgerEvent::Completed);
+    /// ```
+    pub fn with_ledger(&self, ledger: Ledger) {
+        let shared = self.shared();
+        *lock(&shared.ledger) = Some(ledger);
+        shared.ledgered.store(true, Ordering::Release);
+    }
+}
+
+impl Shared {
+    /// Appends an entry to the ledger, if one is set.
+    pub(crate) fn record(&self, event: LedgerEvent, guard_id: GuardId) {
+        if !self.ledgered.load(Ordering::Acquire) {
+            return;
+        }
+        let ledger = self
+            .ledger
+            .lock()
+            .unwrap_or_else(|poison| poison.into_inner());
+        if let Some(ledger) = ledger.as_ref() {
+            ledger
+                .lock()
+                .unwrap_or_else(|poison| poison.into_inner())
+                .push(LedgerEntry {
+                    event,
+                    guard_id,
+                    round: self.round,
+                    at: Instant::now(),
+                });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ledger_records_forks_and_drops_in_order() {
+        let ledger = Arc::new(Mutex::new(Vec::new()));
+
+        let rendezvous = Rendezvous::new();
+        rendezvous.with_ledger(ledger.clone());
+
+        let first = rendezvous.fork_guard();
+        let second = first.fork();
+        let (first_id, second_id) = (first.id(), second.id());
+        drop(second);
+        drop(first);
+        rendezvous.rendezvous();
+
+        let ledger = ledger.lock().unwrap();
+        let events: Vec<_> = ledger.iter().map(|e| (e.event, e.guard_id)).collect();
+        assert_eq!(
+            events,
+            vec![
+                (LedgerEvent::Forked, first_id),
+                (LedgerEvent::Forked, second_id),
+                (LedgerEvent::Completed, second_id),
+                (LedgerEvent::Completed, first_id),
+            ]
+        );
+        assert!(ledger.windows(2).all(|pair| pair[0].at <= pair[1].at));
+    }
+}
@@ -9,16 +9,52 @@
 //!
 //! This version of the crate only supports synchronous code due to the dropping semantics.
 //!
+//! The crate requires `std`: waits block the calling thread on `std::sync` primitives, and most
+//! features rely on clocks and threads. A `no_std` backend is not planned, as waiting without
+//! `std` would have to busy-wait or take a platform-specific parking function, which would
+//! change the semantics of every blocking method.
+//!
 //! ## Crate Features
 //!
-//! * `log` - Enables support for the `log` crate.
-//! * `tokio` - Enables the `rendezvous_async` method to asynchronously wait for the rendezvous
-//!             points to be reached.
+//! * `log` - Enables support for the `log` crate, including
+//!   [`RendezvousBuilder::log_throttle`] to rate-limit per-guard trace messages, and a
+//!   summary line logged when [`Rendezvous::rendezvous`] completes.
+//! * `tracing` - Emits the same events as `log` through the `tracing` crate, and wraps every
+//!   wait in a `rendezvous` span recording the wait duration. Both features can be enabled
+//!   independently of each other.
+//! * `tokio` - Enables the `rendezvous_async` and `rendezvous_timeout_async` methods to
+//!   asynchronously wait for the rendezvous points to be reached, the `progress_stream` method to observe progress asynchronously, and
+//!   the `attach_future` method to complete a guard with an asynchronous task, and the
+//!   `async_event_channel` method to observe guard lifecycle events asynchronously.
+//! * `boottime` - Enables [`RendezvousBuilder::boottime_deadlines`] to measure deadlines on a
+//!   clock that keeps running while the system is suspended (Linux only).
+//! * `chrome-trace` - Enables [`Rendezvous::rendezvous_chrome_trace`] to export the lifetimes
+//!   of all guards as a Chrome Tracing file.
+//! * `cpu-budget` - Enables [`Rendezvous::rendezvous_cpu_budget`] to bound a wait by the CPU
+//!   time of the waiting thread.
+//! * `deadlock-detection` - Enables [`RendezvousBuilder::deadlock_threshold`] to log a report
+//!   of the outstanding guards when a wait appears to be deadlocked, in debug builds only.
+//!   Implies `log`.
+//! * `diagnostics` - Tracks every guard in release builds, too. Without it, release builds use
+//!   the [minimal](RendezvousBuilder::minimal) mode unless
+//!   [full tracking](RendezvousBuilder::full_tracking) is selected.
+//! * `fatal-leaks` - Enables [`RendezvousBuilder::drop_timeout`] to abort the process when
+//!   guards are still outstanding after dropping a rendezvous for a while.
+//! * `global-registry` - Enables [`dump_all`] to take snapshots of all live rendezvous in the
+//!   process.
+//! * `hist` - Enables [`Rendezvous::wait_latency_percentile`] to query wait latencies
+//!   recorded in a lightweight histogram.
+//! * `rayon` - Enables `spawn_rayon` and `install_rayon_scope` to gate tasks running on the
+//!   `rayon` thread pool.
+//! * `signal` - Enables [`Rendezvous::rendezvous_interruptible_signal`] to interrupt a wait on
+//!   `SIGINT` (Unix only).
+//! * `test-util` - Enables [`run_deterministic`] to exercise guarded code on a single thread
+//!   in a controlled order.
 //!
 //! ## Example usage
 //!
 //! ```rust
-//! use std::sync::{Arc, Mutex};
+//! use std::sync::{Arc, Mutex, MutexGuard};
 //! use std::thread;
 //! use std::time::Duration;
 //! use rendezvous::{Rendezvous, RendezvousGuard};
@@ -58,32 +94,551 @@
 // the `docsrs` configuration attribute is defined
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
-#[cfg(feature = "log")]
-use log::{debug, error, trace};
+// Declared first, so that its macros are visible in all other modules.
+#[cfg(any(feature = "log", feature = "tracing"))]
+#[macro_use]
+mod diag;
+
+mod armed;
+#[cfg(feature = "tokio")]
+mod attach;
+mod audit;
+mod backoff;
+#[cfg(debug_assertions)]
+mod balance;
+mod barrier;
+#[cfg(all(feature = "boottime", target_os = "linux"))]
+mod boottime;
+mod bridge;
+mod builder;
+mod cancel;
+mod checked;
+#[cfg(feature = "chrome-trace")]
+mod chrome_trace;
+mod collecting;
+mod completions;
+mod counters;
+#[cfg(feature = "cpu-budget")]
+mod cpu_budget;
+mod csv;
+mod deadline;
+#[cfg(feature = "deadlock-detection")]
+mod deadlock;
+mod debug;
+mod defer;
+mod detailed;
+#[cfg(feature = "test-util")]
+mod deterministic;
+mod digest;
+#[cfg(feature = "tokio")]
+mod events;
+mod fail_fast;
+mod fast_path;
+#[cfg(feature = "fatal-leaks")]
+mod fatal_leaks;
+mod fork_policy;
+#[cfg(feature = "global-registry")]
+mod global;
+mod handles;
+mod handoff;
+#[cfg(feature = "hist")]
+mod hist;
+mod identity;
+mod keyed;
+#[cfg(any(feature = "log", feature = "tracing"))]
+mod latency_buckets;
+mod ledger;
+mod multi;
+mod one_shot;
+mod ordered;
+mod partial;
+mod pending;
+mod phases;
+mod ping;
+mod poll;
+mod pool;
+#[cfg(feature = "tokio")]
+mod progress;
+mod range;
+mod rate;
+mod raw;
+#[cfg(feature = "rayon")]
+mod rayon_scope;
+mod receipt;
+mod registry;
+mod releasing;
+mod reserve;
+mod round;
+mod scope;
+mod shutdown;
+#[cfg(all(feature = "signal", unix))]
+mod signal;
+mod skew;
+mod slots;
+mod span;
+mod spawn;
+mod straggler;
+#[cfg(any(feature = "log", feature = "tracing"))]
+mod summary;
+#[cfg(all(test, feature = "log"))]
+mod test_log;
+#[cfg(any(feature = "log", feature = "tracing"))]
+mod throttle;
+mod tiers;
+mod transfer;
+mod two_phase;
+pub mod typestate;
+mod verify;
+mod wait_handle;
+mod wakeup;
+mod weights;
+
+pub use audit::{AuditReport, GuardAudit};
+pub use barrier::BarrierGuard;
+pub use builder::RendezvousBuilder;
+pub use collecting::{CollectingGuard, CollectingRendezvous};
+pub use completions::Completions;
+pub use counters::RendezvousCounters;
+pub use defer::DeferredRelease;
+pub use detailed::DetailedTimeoutError;
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+pub use events::RendezvousEvent;
+pub use fail_fast::GuardFailure;
+pub use fork_policy::{ForkAfterWait, ForkError};
+#[cfg(feature = "global-registry")]
+#[cfg_attr(docsrs, doc(cfg(feature = "global-registry")))]
+pub use global::{dump_all, RendezvousSnapshot};
+pub use handoff::{GuardReceiver, GuardSender};
+pub use ledger::{Ledger, LedgerEntry, LedgerEvent};
+pub use multi::MultiGuard;
+pub use one_shot::OneShotRendezvous;
+pub use pending::RendezvousPending;
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+pub use progress::ProgressStream;
+pub use raw::RawGuardToken;
+pub use receipt::GuardReceipt;
+pub use registry::GuardSnapshot;
+pub use reserve::Reservation;
+pub use scope::ScopedRendezvous;
+pub use shutdown::ShutdownReport;
+#[cfg(all(feature = "signal", unix))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "signal", unix))))]
+pub use signal::RendezvousOutcome;
+pub use two_phase::TwoPhaseRendezvous;
+pub use verify::VerifyError;
+pub use wait_handle::{WaitHandle, WaitOutcome};
+
+#[cfg(feature = "test-util")]
+#[cfg_attr(docsrs, doc(cfg(feature = "test-util")))]
+pub use deterministic::run_deterministic;
 
 #[cfg(feature = "tokio")]
 use tokio::task::{self, JoinError};
 
+use bridge::{SignalReceiver, SignalSender};
+use builder::Options;
+use registry::GuardRecord;
+use std::cell::{OnceCell, RefCell};
+use std::collections::{HashMap, HashSet};
 use std::error::Error;
-use std::fmt::{Display, Formatter};
-use std::sync::mpsc;
+use std::fmt::{Debug, Display, Formatter};
+use std::ops::ControlFlow;
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU64, AtomicUsize, Ordering};
 use std::sync::mpsc::RecvTimeoutError;
-use std::time::Duration;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+/// How often the state of the guards is checked by waits polling for a condition; see
+/// [`Channel::poll_until`].
+const POLL_INTERVAL: Duration = Duration::from_millis(5);
 
 /// [`Rendezvous`] is a synchronization primitive that allows two threads to rendezvous
 /// at a certain point in the code before proceeding.
-pub struct Rendezvous {
+///
+/// The underlying channel is only created when the first guard is forked; a rendezvous
+/// that never forks a guard completes without ever touching a channel.
+///
+/// A rendezvous can carry an arbitrary context value of type `C`, such as a request id,
+/// set using [`Rendezvous::with_context`]. By default, the context is `()`.
+pub struct Rendezvous<C = ()> {
+    /// The channel used for the rendezvous process, shared with all clones of this rendezvous.
+    /// Lazily initialized by the first call to [`Rendezvous::fork_guard`].
+    channel: OnceCell<Arc<Channel>>,
+    /// The options this rendezvous was built with.
+    options: Options,
+    /// The current round; incremented by [`Rendezvous::reset`].
+    round: u64,
+    /// Whether the original sender was released, i.e. whether a rendezvous was attempted.
+    released: bool,
+    /// Whether waiting for outstanding guards was abandoned, e.g. after a guard failed.
+    /// An abandoned rendezvous does not block when dropped.
+    abandoned: bool,
+    /// The durations of all waits performed on this instance.
+    #[cfg(feature = "hist")]
+    wait_latencies: hist::LatencyHistogram,
+    /// The wakeup latency of the last completed wait; see [`Rendezvous::last_wakeup_latency`].
+    wakeup_latency: Option<Duration>,
+    /// Joins of the threads spawned using [`Rendezvous::spawn_guarded_collecting`].
+    spawned: RefCell<Vec<spawn::Joiner>>,
+    /// The thread pool guarded tasks are submitted to; see
+    /// [`RendezvousBuilder::thread_pool`].
+    pool: OnceCell<pool::ThreadPool>,
+    /// The user-provided context.
+    context: C,
+    /// Formats the context for log messages; `None` if no context was provided.
+    #[cfg(any(feature = "log", feature = "tracing"))]
+    describe_context: Option<fn(&C, &mut Formatter<'_>) -> std::fmt::Result>,
+    /// The registration of this rendezvous in the global registry.
+    #[cfg(feature = "global-registry")]
+    registration: Arc<global::Registration>,
+}
+
+/// Renders the context, if any, and the round of a [`Rendezvous`] as a log message suffix.
+#[cfg(any(feature = "log", feature = "tracing"))]
+struct LogContext<'a, C>(&'a Rendezvous<C>);
+
+#[cfg(any(feature = "log", feature = "tracing"))]
+impl<C> Display for LogContext<'_, C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, " [")?;
+        if let Some(describe) = self.0.describe_context {
+            describe(&self.0.context, f)?;
+            write!(f, ", ")?;
+        }
+        write!(f, "round {}]", self.0.round)
+    }
+}
+
+/// The channel backing a [`Rendezvous`].
+struct Channel {
     /// The receiver used for the rendezvous process. If all senders are dropped, the
     /// receiver allows the [`Rendezvous::rendezvous`] method to pass.
-    rx: mpsc::Receiver<()>,
+    /// Locked by the handle currently waiting; see [`Channel::lock_receiver`].
+    rx: Mutex<SignalReceiver>,
+    /// Whether a handle currently holds the receiver.
+    rx_busy: Mutex<bool>,
+    /// Signaled when a handle releases the receiver.
+    rx_released: Condvar,
     /// The original sender for the rendezvous process. Will be forked using [`Rendezvous::fork_guard`]
     /// or transiently forked from [`RendezvousGuard::fork`]. If all senders are dropped,
     /// [`Rendezvous::rendezvous`] can proceed.
-    tx: Option<mpsc::Sender<()>>,
+    tx: Mutex<Option<SignalSender>>,
+    /// Whether the channel was passed to [`Rendezvous::from_channel`], so that senders other
+    /// than guards may keep it open.
+    bridged: bool,
+    /// State shared between the [`Rendezvous`] and all of its guards.
+    shared: Arc<Shared>,
+    /// The number of [`Rendezvous`] handles sharing this channel; see [`Rendezvous::clone`].
+    handles: AtomicUsize,
+}
+
+impl Channel {
+    fn new(options: Options, round: u64, tx: SignalSender, rx: SignalReceiver) -> Self {
+        Self {
+            bridged: matches!(tx, SignalSender::Bridged(..)),
+            tx: Mutex::new(Some(tx)),
+            rx: Mutex::new(rx),
+            rx_busy: Mutex::new(false),
+            rx_released: Condvar::new(),
+            shared: Arc::new(Shared {
+                options,
+                round,
+                ..Shared::default()
+            }),
+            handles: AtomicUsize::new(1),
+        }
+    }
+
+    /// Blocks until all senders are dropped, discarding any signals received meanwhile.
+    fn wait(&self) {
+        // External senders of a bridged channel are only observed by the receiver.
+        if !self.bridged && self.shared.is_settled() {
+            return;
+        }
+        #[cfg(test)]
+        self.shared.blocking_waits.fetch_add(1, Ordering::Relaxed);
+        #[cfg(feature = "deadlock-detection")]
+        let _watchdog = self
+            .shared
+            .deadlock_threshold()
+            .map(|threshold| deadlock::Watchdog::spawn(&self.shared, threshold));
+        #[cfg(any(feature = "log", feature = "tracing"))]
+        if let Some(stall) = self.shared.options.stall_after {
+            return self.wait_watching(stall);
+        }
+        while self.recv().is_ok() {}
+    }
+
+    /// Blocks until all senders are dropped or the `deadline` passes, discarding any
+    /// signals received meanwhile.
+    fn wait_deadline(&self, deadline: Instant) -> Result<(), RendezvousTimeoutError> {
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            match self.recv_timeout(remaining) {
+                Ok(_) => continue,
+                Err(RecvTimeoutError::Timeout) => return Err(RendezvousTimeoutError::Timeout),
+                Err(RecvTimeoutError::Disconnected) => return Ok(()),
+            }
+        }
+    }
+
+    /// Blocks until `check` breaks or all senders are dropped, discarding any signals received
+    /// meanwhile.
+    ///
+    /// `check` is called up front, whenever a signal arrives, and otherwise after the duration
+    /// it continued with, which is usually [`POLL_INTERVAL`]. Returns the value `check` broke
+    /// with, or `None` once all senders are dropped.
+    fn poll_until<B>(&self, mut check: impl FnMut() -> ControlFlow<B, Duration>) -> Option<B> {
+        loop {
+            let wait = match check() {
+                ControlFlow::Break(value) => return Some(value),
+                ControlFlow::Continue(wait) => wait,
+            };
+            match self.recv_timeout(wait) {
+                Ok(_) | Err(RecvTimeoutError::Timeout) => continue,
+                Err(RecvTimeoutError::Disconnected) => return None,
+            }
+        }
+    }
+}
+
+/// State shared between a [`Rendezvous`] and its [`RendezvousGuard`] instances.
+#[derive(Default)]
+struct Shared {
+    /// The options the rendezvous was built with.
+    options: Options,
+    /// The round of the rendezvous this state belongs to.
+    round: u64,
+    /// The identifier assigned to the next forked guard.
+    next_id: AtomicU64,
+    /// The total number of guards forked.
+    forked: AtomicUsize,
+    /// The total number of guards dropped.
+    completed: AtomicUsize,
+    /// The total number of guards that finished dropping, including their sender.
+    settled: AtomicUsize,
+    /// The weights of outstanding guards forked using [`Rendezvous::fork_guard_weighted`].
+    weights: Mutex<HashMap<GuardId, u32>>,
+    /// Whether a weighted guard was ever forked.
+    weighted: AtomicBool,
+    /// The total weight of all dropped weighted guards, minus their number, i.e. the
+    /// difference between the completed weight and the number of dropped guards.
+    weight_offset: AtomicI64,
+    /// The number of waits that blocked on the channel.
+    #[cfg(test)]
+    blocking_waits: AtomicUsize,
+    /// The number of live guards and reservations counting against the guard limit.
+    admitted: AtomicUsize,
+    /// The number of threads waiting in [`Rendezvous::acquire_slot`].
+    slot_waiters: AtomicUsize,
+    /// Guards the slot pool condition of [`Rendezvous::acquire_slot`].
+    slot_lock: Mutex<()>,
+    /// Signaled when a guard is dropped while threads wait for a free slot.
+    slot_freed: Condvar,
+    /// Whether the original sender was released, i.e. no further guards can be forked
+    /// off the [`Rendezvous`] itself.
+    released: AtomicBool,
+    /// Whether the rendezvous was cancelled.
+    cancelled: AtomicBool,
+    /// The deadline by which guards must finish, if any.
+    deadline: Mutex<Option<Instant>>,
+    /// The guards that were dropped while their thread was panicking.
+    panicked: Mutex<Vec<GuardId>>,
+    /// The senders of guards armed for completion.
+    armed: Mutex<Vec<(GuardId, SignalSender)>>,
+    /// The guard standing in for all outstanding raw tokens; see [`Rendezvous::fork_raw`].
+    raw: raw::RawGuards,
+    /// Whether guard completions are iterated; see [`Rendezvous::completions`].
+    iterated: AtomicBool,
+    /// Rate-limits per-guard trace messages; created on first use if configured.
+    #[cfg(any(feature = "log", feature = "tracing"))]
+    throttle: Mutex<Option<throttle::TokenBucket>>,
+    /// Notifies asynchronous tasks waiting for cancellation.
+    #[cfg(feature = "tokio")]
+    cancel_notify: tokio::sync::Notify,
+    /// The user-owned ledger guard lifecycle events are appended to, if any.
+    ledger: Mutex<Option<Ledger>>,
+    /// Whether a ledger was ever set.
+    ledgered: AtomicBool,
+    /// The records of all outstanding guards.
+    registry: Mutex<HashMap<GuardId, GuardRecord>>,
+    /// The time span covered by all guard forks.
+    fork_span: Mutex<skew::Span>,
+    /// The time span covered by all guard completions.
+    completion_span: Mutex<skew::Span>,
+    /// The audited lifetimes of all completed guards.
+    audits: Mutex<Vec<audit::GuardAudit>>,
+    /// The tiers of prioritized guards.
+    tiers: Mutex<tiers::Tiers>,
+    /// Whether the guards were transferred to another rendezvous.
+    transferred: AtomicBool,
+    /// The state of the transfer to another rendezvous, if any.
+    transfer: Mutex<Option<transfer::Transfer>>,
+    /// The keys of all outstanding keyed guards.
+    keys: Mutex<keyed::KeyRegistry>,
+    /// Whether a keyed guard was ever forked.
+    keyed: AtomicBool,
+    /// The outstanding guards a receipt was issued for.
+    receipts: Mutex<HashSet<GuardId>>,
+    /// Whether a receipt was ever issued.
+    receipted: AtomicBool,
+    /// The subscribers of progress updates.
+    #[cfg(feature = "tokio")]
+    progress: Mutex<Vec<progress::Subscriber>>,
+    /// The subscribers of guard lifecycle events.
+    #[cfg(feature = "tokio")]
+    event_subscribers: Mutex<Vec<tokio::sync::mpsc::UnboundedSender<RendezvousEvent>>>,
+    /// Whether an event channel was ever requested.
+    #[cfg(feature = "tokio")]
+    has_event_subscribers: AtomicBool,
+    /// When the last sender was dropped; see [`Shared::stamp_disconnect`].
+    disconnected_at: Mutex<Option<Instant>>,
+    /// The wakers to wake once no guards are outstanding; see [`Rendezvous::poll_complete`].
+    wakers: Mutex<Vec<std::task::Waker>>,
+    /// Whether a waker was ever registered.
+    polled: AtomicBool,
+    /// The lifetimes of all completed guards; see [`Rendezvous::rendezvous_straggler`].
+    lifetimes: Mutex<Vec<Duration>>,
+    /// The recent completions; see [`Rendezvous::completion_rate`].
+    rate: Mutex<rate::RateWindow>,
+}
+
+impl Shared {
+    /// Allocates a new, unique guard identifier.
+    fn next_id(&self) -> GuardId {
+        GuardId(self.next_id.fetch_add(1, Ordering::Relaxed))
+    }
+
+    /// Returns the number of guards forked but not yet dropped.
+    fn live_guards(&self) -> usize {
+        if self.is_transferred() {
+            return 0;
+        }
+        let completed = self.completed.load(Ordering::Acquire);
+        let forked = self.forked.load(Ordering::Acquire);
+        forked.saturating_sub(completed)
+    }
+
+    /// Called when a guard is forked.
+    fn on_fork(&self, id: GuardId, parent: Option<GuardId>, label: Option<String>) {
+        let index = self.forked.fetch_add(1, Ordering::SeqCst);
+        self.forward_fork(index);
+        if !self.options.is_minimal() {
+            self.register(id, parent, label);
+            lock(&self.fork_span).record();
+        }
+        self.record(LedgerEvent::Forked, id);
+        #[cfg(feature = "tokio")]
+        {
+            self.notify_progress();
+            self.publish_event(RendezvousEvent::Forked(id));
+        }
+    }
+
+    /// Called when a guard is dropped, before its sender is released.
+    ///
+    /// Returns whether the guard was the last outstanding one.
+    ///
+    /// In minimal mode, this takes no locks and does not read the clock, unless a feature
+    /// that needs it, such as keyed or weighted guards, receipts or a ledger, was used on the
+    /// rendezvous, or the guard is dropped while its thread panics.
+    fn on_release(&self, id: GuardId) -> bool {
+        // Recorded before counting the completion, so the completed weight never transiently
+        // includes this guard at the default weight.
+        self.record_weight(id);
+        let index = self.completed.fetch_add(1, Ordering::SeqCst);
+        let last = index + 1 == self.forked.load(Ordering::SeqCst);
+        self.forward_release(index);
+        self.admitted.fetch_sub(1, Ordering::AcqRel);
+        self.notify_slots();
+        self.record_panic(id);
+        self.release_key(id);
+        self.release_receipt(id);
+        if !self.options.is_minimal() {
+            self.unregister(id);
+            lock(&self.completion_span).record();
+            self.record_completion_rate();
+            self.stamp_disconnect();
+        }
+        self.record(LedgerEvent::Completed, id);
+        #[cfg(feature = "tokio")]
+        {
+            self.notify_progress();
+            self.publish_event(RendezvousEvent::Completed(id));
+        }
+        self.wake_if_complete();
+        last
+    }
+
+    /// Called when the original sender is released.
+    fn on_rendezvous_released(&self) {
+        self.released.store(true, Ordering::Release);
+        #[cfg(feature = "tokio")]
+        self.notify_progress();
+        if !self.options.is_minimal() {
+            self.stamp_disconnect();
+        }
+    }
+}
+
+/// Locks a mutex, ignoring poisoning.
+///
+/// Bookkeeping must remain available while a guarded thread unwinds; the protected state is
+/// never left inconsistent by a panic.
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|poison| poison.into_inner())
+}
+
+/// A signal sent by a [`RendezvousGuard`] to its [`Rendezvous`] while the guard is alive.
+///
+/// The completion of the rendezvous itself is not a signal; it is indicated by all senders
+/// being dropped.
+enum Signal {
+    /// A progress notification; see [`RendezvousGuard::ping`].
+    Ping(GuardId),
+    /// A guard failed; see [`RendezvousGuard::fail`].
+    Failed(GuardFailure),
+    /// A guard completed with a value; see [`CollectingGuard::complete_with`].
+    Value(GuardId, Box<dyn std::any::Any + Send>),
+    /// A guard completed; see [`RendezvousBuilder::deterministic_order`].
+    Completed(GuardId),
 }
 
 /// A guard forked off a [`Rendezvous`] struct.
-pub struct RendezvousGuard(mpsc::Sender<()>);
+pub struct RendezvousGuard {
+    /// The sender keeping the rendezvous open while this guard is alive, or `None` if the guard
+    /// was armed using [`RendezvousGuard::arm_completion`].
+    tx: Mutex<Option<SignalSender>>,
+    /// State shared with the owning [`Rendezvous`].
+    shared: Arc<Shared>,
+    /// The identifier of this guard.
+    id: GuardId,
+    /// The priority tier of this guard, if any; see [`Rendezvous::fork_guard_prioritized`].
+    priority: Option<u8>,
+    /// Whether this guard is detached; see [`RendezvousGuard::is_detached`].
+    detached: bool,
+}
+
+/// Identifies a [`RendezvousGuard`] within its [`Rendezvous`].
+///
+/// Identifiers are assigned in fork order and are unique per rendezvous.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct GuardId(u64);
+
+impl GuardId {
+    /// Returns the numeric value of this identifier.
+    pub fn as_u64(&self) -> u64 {
+        self.0
+    }
+}
+
+impl Display for GuardId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{}", self.0)
+    }
+}
 
 impl Rendezvous {
     /// Create a new instance of a [`Rendezvous`] channel.
@@ -100,8 +655,105 @@ impl Rendezvous {
     /// let rendezvous = Rendezvous::new();
     /// ```
     pub fn new() -> Self {
-        let (tx, rx) = mpsc::channel();
-        Self { tx: Some(tx), rx }
+        Self::from_context(())
+    }
+}
+
+impl<C> Rendezvous<C> {
+    /// Create a new instance of a [`Rendezvous`] channel carrying a context value.
+    ///
+    /// The context is rendered into all log messages emitted by the rendezvous itself,
+    /// which makes it useful for correlation ids.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rendezvous::Rendezvous;
+    ///
+    /// let rendezvous = Rendezvous::with_context(String::from("request-42"));
+    /// assert_eq!(rendezvous.context(), "request-42");
+    /// ```
+    pub fn with_context(context: C) -> Self
+    where
+        C: Debug,
+    {
+        #[cfg_attr(not(any(feature = "log", feature = "tracing")), allow(unused_mut))]
+        let mut rendezvous = Self::from_context(context);
+        #[cfg(any(feature = "log", feature = "tracing"))]
+        {
+            rendezvous.describe_context = Some(<C as Debug>::fmt);
+        }
+        rendezvous
+    }
+
+    /// Creates a new instance carrying `context` without rendering it into log messages.
+    fn from_context(context: C) -> Self {
+        Self::from_parts(context, Options::default())
+    }
+
+    /// Creates a new instance from its context and options.
+    fn from_parts(context: C, options: Options) -> Self {
+        #[cfg(feature = "global-registry")]
+        let registration = global::Registration::register(options.name.clone());
+        Self {
+            channel: OnceCell::new(),
+            options,
+            round: 0,
+            released: false,
+            abandoned: false,
+            #[cfg(feature = "hist")]
+            wait_latencies: hist::LatencyHistogram::default(),
+            wakeup_latency: None,
+            spawned: RefCell::new(Vec::new()),
+            pool: OnceCell::new(),
+            context,
+            #[cfg(any(feature = "log", feature = "tracing"))]
+            describe_context: None,
+            #[cfg(feature = "global-registry")]
+            registration,
+        }
+    }
+
+    /// Returns the context value of this rendezvous.
+    pub fn context(&self) -> &C {
+        &self.context
+    }
+
+    /// Returns the number of guards that were forked but not yet dropped.
+    ///
+    /// This is a snapshot; guards may be forked or dropped concurrently.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use rendezvous::Rendezvous;
+    ///
+    /// let rendezvous = Rendezvous::new();
+    /// let guard = rendezvous.fork_guard();
+    /// assert_eq!(rendezvous.remaining_guards(), 1);
+    ///
+    /// drop(guard);
+    /// assert_eq!(rendezvous.remaining_guards(), 0);
+    /// # rendezvous.rendezvous();
+    /// ```
+    pub fn remaining_guards(&self) -> usize {
+        self.channel
+            .get()
+            .map_or(0, |channel| channel.shared.live_guards())
+    }
+
+    /// Returns the number of guards that were forked but not yet dropped.
+    ///
+    /// This is an alias of [`Rendezvous::remaining_guards`]. The sender held by the rendezvous
+    /// itself is never counted, and querying the count does not affect the rendezvous.
+    pub fn guard_count(&self) -> usize {
+        self.remaining_guards()
+    }
+
+    /// Returns a displayable suffix for log messages identifying this rendezvous.
+    #[cfg(any(feature = "log", feature = "tracing"))]
+    fn log_context(&self) -> LogContext<'_, C> {
+        LogContext(self)
     }
 
     /// Forks a guard off the [`Rendezvous`] channel.
@@ -126,14 +778,69 @@ impl Rendezvous {
     /// drop(guard);
     /// ```
     pub fn fork_guard(&self) -> RendezvousGuard {
-        if let Some(tx) = &self.tx {
-            #[cfg(feature = "log")]
-            {
-                trace!("Forking rendezvous guard");
-            }
-            RendezvousGuard(tx.clone())
+        self.fork_guard_with(None)
+    }
+
+    /// Forks a guard off the [`Rendezvous`] channel and tags it with a label for diagnostics.
+    ///
+    /// The label is reported in [`GuardSnapshot`]s of outstanding guards, e.g. when using
+    /// [`Rendezvous::rendezvous_timeout_snapshot`]. Otherwise, the guard behaves exactly like one
+    /// forked using [`Rendezvous::fork_guard`].
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use rendezvous::Rendezvous;
+    ///
+    /// let rendezvous = Rendezvous::new();
+    /// let guard = rendezvous.fork_guard_labeled("ingest");
+    /// drop(guard);
+    /// rendezvous.rendezvous();
+    /// ```
+    pub fn fork_guard_labeled(&self, label: impl Into<String>) -> RendezvousGuard {
+        self.fork_guard_with(Some(label.into()))
+    }
+
+    /// Forks a guard, optionally tagging it with a label.
+    fn fork_guard_with(&self, label: Option<String>) -> RendezvousGuard {
+        self.expect_forked(self.try_fork_guard_with(label))
+    }
+
+    /// Forks a guard, optionally tagging it with a label, or returns an error if the
+    /// rendezvous was already waited on.
+    fn try_fork_guard_with(&self, label: Option<String>) -> Result<RendezvousGuard, ForkError> {
+        if self.released || self.channel().is_released() {
+            return self.fork_after_wait();
+        }
+        let shared = &self.channel().shared;
+        if shared.is_transferred() {
+            panic!("Fork called after the guards were transferred")
+        }
+        shared.admit();
+        self.fork_admitted(label)
+    }
+
+    /// Forks a guard that was already admitted against the guard limit.
+    fn fork_admitted(&self, label: Option<String>) -> Result<RendezvousGuard, ForkError> {
+        // Waiting requires exclusive access, so neither forks nor reservations outlive it.
+        debug_assert!(!self.released, "Fork called after Rendezvous is dropped");
+        let channel = self.channel();
+        let tx = lock(&channel.tx).clone();
+        if let Some(tx) = tx {
+            #[cfg(any(feature = "log", feature = "tracing"))]
+            channel.shared.trace_event(
+                throttle::TraceEvent::Fork,
+                format_args!("Forking rendezvous guard{}", self.log_context()),
+            );
+            Ok(RendezvousGuard::new(
+                tx,
+                channel.shared.clone(),
+                None,
+                label,
+            ))
         } else {
-            unreachable!("Fork called after Rendezvous is dropped")
+            // Another clone started waiting concurrently.
+            self.fork_after_wait()
         }
     }
 
@@ -187,7 +894,71 @@ impl Rendezvous {
     /// drop(guard);
     /// ```
     pub fn rendezvous(mut self) {
+        let _span = self.wait_span("rendezvous");
+        self.rendezvous_summarized();
+    }
+
+    /// Executes the rendezvous process without consuming `self`.
+    ///
+    /// This blocks exactly like [`Rendezvous::rendezvous`], but allows waiting on a rendezvous
+    /// stored in a struct field without moving it out. Afterwards, the rendezvous is completed:
+    /// forking further guards panics, and dropping it no longer blocks.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::thread;
+    /// use rendezvous::Rendezvous;
+    ///
+    /// struct Coordinator {
+    ///     rendezvous: Rendezvous,
+    /// }
+    ///
+    /// let mut coordinator = Coordinator { rendezvous: Rendezvous::new() };
+    /// let guard = coordinator.rendezvous.fork_guard();
+    /// thread::spawn(move || drop(guard));
+    ///
+    /// coordinator.rendezvous.rendezvous_ref();
+    /// ```
+    pub fn rendezvous_ref(&mut self) {
+        let _span = self.wait_span("rendezvous_ref");
+        self.rendezvous_summarized();
+    }
+
+    /// Executes the rendezvous process, logging a summary once it completed.
+    fn rendezvous_summarized(&mut self) {
+        #[cfg(any(feature = "log", feature = "tracing"))]
+        let started = Instant::now();
+        self.rendezvous_internal();
+        #[cfg(any(feature = "log", feature = "tracing"))]
+        self.log_summary(started.elapsed());
+    }
+
+    /// Executes the rendezvous process, then returns the number of guards that completed.
+    ///
+    /// Every guard ever forked off this rendezvous in the current [round](Rendezvous::round)
+    /// is counted, including nested guards and guards dropped before this method is called.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::thread;
+    /// use rendezvous::Rendezvous;
+    ///
+    /// let rendezvous = Rendezvous::new();
+    /// drop(rendezvous.fork_guard());
+    /// let guard = rendezvous.fork_guard();
+    /// let nested = guard.fork();
+    /// thread::spawn(move || drop((guard, nested)));
+    ///
+    /// assert_eq!(rendezvous.rendezvous_counted(), 3);
+    /// ```
+    pub fn rendezvous_counted(mut self) -> usize {
+        let _span = self.wait_span("rendezvous_counted");
         self.rendezvous_internal();
+        self.channel
+            .get()
+            .map_or(0, |channel| channel.shared.completed.load(Ordering::SeqCst))
     }
 
     /// Asynchronously executes the rendezvous process.
@@ -235,9 +1006,13 @@ impl Rendezvous {
     /// ```
     #[cfg(feature = "tokio")]
     #[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
-    pub async fn rendezvous_async(self) -> Result<(), JoinError> {
+    pub async fn rendezvous_async(self) -> Result<(), JoinError>
+    where
+        C: Send + 'static,
+    {
+        let span = self.async_wait_span("rendezvous_async");
         let handle = task::spawn_blocking(|| self.rendezvous());
-        handle.await
+        span.instrument(handle).await
     }
 
     /// Executes the rendezvous process with a timeout.
@@ -296,39 +1071,186 @@ impl Rendezvous {
     /// drop(guard);
     /// ```
     pub fn rendezvous_timeout(&mut self, timeout: Duration) -> Result<(), RendezvousTimeoutError> {
-        if let Some(tx) = self.tx.take() {
-            drop(tx);
-        } else {
-            #[cfg(feature = "log")]
+        let _span = self.wait_span("rendezvous_timeout");
+        if self.released {
+            #[cfg(any(feature = "log", feature = "tracing"))]
             {
-                trace!("Rendezvous was called previously, attempting again");
+                trace!(
+                    "Rendezvous was called previously, attempting again{}",
+                    self.log_context()
+                );
             }
         }
-        match self.rx.recv_timeout(timeout) {
-            Ok(_) => Ok(()),
-            Err(err) => match err {
-                RecvTimeoutError::Timeout => {
-                    #[cfg(feature = "log")]
-                    {
-                        debug!("A timeout occurred during a rendezvous");
-                    }
-                    Err(RendezvousTimeoutError::Timeout)
-                }
-                RecvTimeoutError::Disconnected => Ok(()),
-            },
+        let started = Instant::now();
+        let result = match self.release() {
+            Some(channel) => channel.wait_deadline(started + timeout),
+            None => Ok(()),
+        };
+        self.record_wait(started);
+        #[cfg(any(feature = "log", feature = "tracing"))]
+        if result.is_err() {
+            debug!(
+                "A timeout occurred during a rendezvous{}",
+                self.log_context()
+            );
         }
+        result
+    }
+
+    /// Executes the rendezvous process with a timeout, returning the part of the timeout that
+    /// was left over once all guards were dropped.
+    ///
+    /// This allows chaining timed waits on a shared budget.
+    /// See [`Rendezvous::rendezvous_timeout`].
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use rendezvous::Rendezvous;
+    ///
+    /// let mut first = Rendezvous::new();
+    /// let mut second = Rendezvous::new();
+    /// let budget = Duration::from_secs(1);
+    ///
+    /// let budget = first.rendezvous_timeout_remaining(budget).unwrap();
+    /// let budget = second.rendezvous_timeout_remaining(budget).unwrap();
+    /// assert!(budget <= Duration::from_secs(1));
+    /// ```
+    pub fn rendezvous_timeout_remaining(
+        &mut self,
+        timeout: Duration,
+    ) -> Result<Duration, RendezvousTimeoutError> {
+        let _span = self.wait_span("rendezvous_timeout_remaining");
+        let started = Instant::now();
+        self.rendezvous_timeout(timeout)?;
+        Ok(timeout.saturating_sub(started.elapsed()))
+    }
+
+    /// Executes the rendezvous process with the timeout configured using
+    /// [`RendezvousBuilder::default_timeout`].
+    ///
+    /// Without a configured timeout, this blocks until all guards are dropped, like
+    /// [`Rendezvous::rendezvous_ref`].
+    pub fn rendezvous_or_default_timeout(&mut self) -> Result<(), RendezvousTimeoutError> {
+        let _span = self.wait_span("rendezvous_or_default_timeout");
+        match self.options.default_timeout {
+            Some(timeout) => self.rendezvous_timeout(timeout),
+            None => {
+                self.rendezvous_internal();
+                Ok(())
+            }
+        }
+    }
+
+    /// Asynchronously executes the rendezvous process with a timeout.
+    ///
+    /// Unlike [`Rendezvous::rendezvous_async`], this does not occupy a blocking thread: the
+    /// task is woken when the last guard is dropped, using [`Rendezvous::poll_complete`], so
+    /// the executor thread is free to run other tasks, including the ones holding the guards.
+    /// The timeout requires the Tokio runtime to have its time driver enabled.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use rendezvous::{Rendezvous, RendezvousTimeoutError};
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let mut rendezvous = Rendezvous::new();
+    /// let guard = rendezvous.fork_guard();
+    /// tokio::spawn(async move {
+    ///     tokio::time::sleep(Duration::from_millis(10)).await;
+    ///     drop(guard);
+    /// });
+    ///
+    /// let result = rendezvous.rendezvous_timeout_async(Duration::from_secs(5)).await;
+    /// assert_eq!(result, Ok(()));
+    /// # }
+    /// ```
+    #[cfg(feature = "tokio")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+    pub async fn rendezvous_timeout_async(
+        &mut self,
+        timeout: Duration,
+    ) -> Result<(), RendezvousTimeoutError> {
+        let span = self.async_wait_span("rendezvous_timeout_async");
+        let started = Instant::now();
+        let result = if self.release().is_some() {
+            let complete = std::future::poll_fn(|cx| self.poll_complete(cx));
+            span.instrument(tokio::time::timeout(timeout, complete))
+                .await
+                .map_err(|_| RendezvousTimeoutError::Timeout)
+        } else {
+            Ok(())
+        };
+        self.record_wait(started);
+        #[cfg(any(feature = "log", feature = "tracing"))]
+        if result.is_err() {
+            debug!(
+                "A timeout occurred during an asynchronous rendezvous{}",
+                self.log_context()
+            );
+        }
+        result
+    }
+
+    /// Records the duration of a wait that started at `started`.
+    #[cfg_attr(not(feature = "hist"), allow(unused_variables))]
+    fn record_wait(&mut self, started: Instant) {
+        self.record_wakeup(Instant::now());
+        #[cfg(feature = "hist")]
+        self.wait_latencies.record(started.elapsed());
+    }
+
+    /// Releases the original sender, marking the rendezvous as attempted.
+    ///
+    /// Returns the channel to wait on, or `None` if no guard was ever forked.
+    fn release(&mut self) -> Option<&Channel> {
+        self.released = true;
+        let channel = self.channel.get()?;
+        let tx = lock(&channel.tx).take();
+        if let Some(tx) = tx {
+            drop(tx);
+            channel.shared.on_rendezvous_released();
+        }
+        Some(channel)
+    }
+
+    /// Returns the channel, creating it if needed.
+    fn channel(&self) -> &Arc<Channel> {
+        self.channel.get_or_init(|| {
+            let (tx, rx) = bridge::channel();
+            self.new_channel(tx, rx)
+        })
+    }
+
+    /// Creates a channel for the current round from a channel pair.
+    fn new_channel(&self, tx: SignalSender, rx: SignalReceiver) -> Arc<Channel> {
+        let channel = Channel::new(self.options.clone(), self.round, tx, rx);
+        #[cfg(feature = "global-registry")]
+        self.registration.update(self.round, Some(&channel.shared));
+        Arc::new(channel)
+    }
+
+    /// Returns the state shared with the guards, creating the channel if needed.
+    fn shared(&self) -> &Arc<Shared> {
+        &self.channel().shared
     }
 
     /// Performs a rendezvous operation internally.
     ///
     /// This function borrows `self` and drops the `tx` channel if it exists.
     /// It then blocks on the `rx` channel, waiting for all [`RendezvousGuard`] instances to be
-    /// dropped, and discards any error that may occur.
+    /// dropped, and discards any error that may occur. If no guard was ever forked, no channel
+    /// exists and the function returns immediately.
     fn rendezvous_internal(&mut self) {
-        if let Some(tx) = self.tx.take() {
-            drop(tx);
+        let started = Instant::now();
+        if let Some(channel) = self.release() {
+            channel.wait();
         }
-        self.rx.recv().ok();
+        self.record_wait(started);
     }
 }
 
@@ -339,16 +1261,92 @@ impl Default for Rendezvous {
 }
 
 impl RendezvousGuard {
+    /// Creates a new guard, assigning it a fresh identifier. Nested guards pass the guard they
+    /// were forked off as `parent`.
+    fn new(
+        tx: SignalSender,
+        shared: Arc<Shared>,
+        parent: Option<GuardId>,
+        label: Option<String>,
+    ) -> Self {
+        let id = shared.next_id();
+        shared.on_fork(id, parent, label);
+        Self {
+            tx: Mutex::new(Some(tx)),
+            shared,
+            id,
+            priority: None,
+            detached: false,
+        }
+    }
+
+    /// Returns a sender into the rendezvous channel, or `None` if this guard was armed and
+    /// completed by [`Rendezvous::complete_armed`].
+    fn sender(&self) -> Option<SignalSender> {
+        match &*lock(&self.tx) {
+            Some(tx) => Some(tx.clone()),
+            None => self.shared.armed_sender(self.id),
+        }
+    }
+
     /// Forks a guard off the owning [`Rendezvous`] channel.
     ///
     /// When all guards are dropped, [`Rendezvous::rendezvous`] will proceed; until then, that
     /// call blocks.
     pub fn fork(&self) -> RendezvousGuard {
-        #[cfg(feature = "log")]
-        {
-            trace!("Forking nested rendezvous guard");
+        self.fork_with(None)
+    }
+
+    /// Forks a guard off the owning [`Rendezvous`] channel and tags it with a label for
+    /// diagnostics.
+    ///
+    /// This mirrors [`Rendezvous::fork_guard_labeled`]; otherwise, the guard behaves exactly
+    /// like one forked using [`RendezvousGuard::fork`]. Nested guards count towards
+    /// [`Rendezvous::guard_count`] like any other guard and keep the rendezvous open even if
+    /// the guard they were forked off is dropped first.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use rendezvous::Rendezvous;
+    ///
+    /// let rendezvous = Rendezvous::new();
+    /// let guard = rendezvous.fork_guard_labeled("ingest");
+    /// let nested = guard.fork_labeled("ingest/parse");
+    /// drop(guard);
+    /// assert_eq!(rendezvous.guard_count(), 1);
+    /// drop(nested);
+    /// rendezvous.rendezvous();
+    /// ```
+    pub fn fork_labeled(&self, label: impl Into<String>) -> RendezvousGuard {
+        self.fork_with(Some(label.into()))
+    }
+
+    /// Forks a nested guard, optionally tagging it with a label.
+    fn fork_with(&self, label: Option<String>) -> RendezvousGuard {
+        #[cfg(any(feature = "log", feature = "tracing"))]
+        self.shared.trace_event(
+            throttle::TraceEvent::Fork,
+            format_args!(
+                "Forking nested rendezvous guard [round {}]",
+                self.shared.round
+            ),
+        );
+        if self.detached {
+            return self.fork_detached();
         }
-        RendezvousGuard(self.0.clone())
+        let Some(tx) = self.sender() else {
+            unreachable!("Fork called after the guard was completed")
+        };
+        self.shared.admit();
+        let mut guard = RendezvousGuard::new(tx, self.shared.clone(), Some(self.id), label);
+        self.inherit_priority(&mut guard);
+        guard
+    }
+
+    /// Returns the identifier of this guard.
+    pub fn id(&self) -> GuardId {
+        self.id
     }
 
     /// A no-operation that consumes self, marking a rendezvous point.
@@ -364,6 +1362,68 @@ impl RendezvousGuard {
     /// rendezvous.rendezvous();
     /// ```
     pub fn completed(self) {}
+
+    /// Returns whether this is the only outstanding guard of its rendezvous.
+    ///
+    /// This is a best-effort snapshot: another guard may be forked off a guard or the
+    /// rendezvous right after checking. Use [`RendezvousGuard::complete_if_last`] to run
+    /// cleanup exactly once.
+    pub fn is_last(&self) -> bool {
+        self.shared.live_guards() == 1
+    }
+
+    /// Consumes the guard, returning whether it was the last outstanding guard of its
+    /// rendezvous when it was released.
+    ///
+    /// Unlike [`RendezvousGuard::is_last`], checking and releasing happen at once: of several
+    /// guards completed concurrently, at most one observes being the last, so the last worker
+    /// to finish can run extra cleanup exactly once. Guards forked afterwards start over.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::thread;
+    /// use rendezvous::Rendezvous;
+    ///
+    /// let rendezvous = Rendezvous::new();
+    /// let guards: Vec<_> = (0..4).map(|_| rendezvous.fork_guard()).collect();
+    /// let workers: Vec<_> = guards
+    ///     .into_iter()
+    ///     .map(|guard| thread::spawn(move || guard.complete_if_last()))
+    ///     .collect();
+    /// rendezvous.rendezvous();
+    ///
+    /// let completions: Vec<_> = workers.into_iter().map(|w| w.join().unwrap()).collect();
+    /// assert_eq!(completions.iter().filter(|&&last| last).count(), 1);
+    /// ```
+    pub fn complete_if_last(mut self) -> bool {
+        if let Some(priority) = self.priority.take() {
+            self.shared.on_tier_release(priority);
+        }
+        self.release_sender()
+    }
+
+    /// Releases the sender of this guard unless it was already released, returning whether
+    /// this was the last outstanding guard at that moment.
+    fn release_sender(&mut self) -> bool {
+        let tx = self
+            .tx
+            .get_mut()
+            .unwrap_or_else(|poison| poison.into_inner())
+            .take();
+        // An armed guard that was already completed must not be released twice.
+        let Some(tx) = tx.or_else(|| self.shared.take_armed(self.id)) else {
+            return false;
+        };
+        #[cfg(any(feature = "log", feature = "tracing"))]
+        self.shared.log_latency_bucket(self.id);
+        let last = self.shared.on_release(self.id);
+        // Signaled after counting the completion, so the receiver observes the count.
+        self.shared.signal_completion(self.id, &tx);
+        drop(tx);
+        self.shared.on_settled();
+        last
+    }
 }
 
 impl Clone for RendezvousGuard {
@@ -372,13 +1432,62 @@ impl Clone for RendezvousGuard {
     }
 }
 
-impl Drop for Rendezvous {
+impl Drop for RendezvousGuard {
     fn drop(&mut self) {
-        #[cfg(all(debug_assertions, feature = "log"))]
-        if self.tx.is_some() {
-            error!("Implementation error: Rendezvous method not invoked")
+        #[cfg(any(feature = "log", feature = "tracing"))]
+        self.shared.trace_event(
+            throttle::TraceEvent::Drop,
+            format_args!(
+                "Dropping rendezvous guard {} [round {}]",
+                self.id, self.shared.round
+            ),
+        );
+        if let Some(priority) = self.priority.take() {
+            self.shared.on_tier_release(priority);
         }
-        self.rendezvous_internal()
+        self.release_sender();
+    }
+}
+
+impl<C> Drop for Rendezvous<C> {
+    fn drop(&mut self) {
+        // Only the last of several clones waits for the guards.
+        if self
+            .channel
+            .get()
+            .is_some_and(|channel| !channel.release_handle())
+        {
+            return;
+        }
+        #[cfg(all(debug_assertions, any(feature = "log", feature = "tracing")))]
+        if !self.released
+            && !self.options.quiet_drop
+            && !self
+                .channel
+                .get()
+                .is_some_and(|channel| channel.is_released())
+        {
+            error!(
+                "Implementation error: Rendezvous method not invoked{}",
+                self.log_context()
+            )
+        }
+        if self.abandoned {
+            return;
+        }
+        #[cfg(feature = "fatal-leaks")]
+        if let Some(timeout) = self.options.drop_timeout {
+            self.drop_or_abort(timeout);
+            #[cfg(debug_assertions)]
+            self.check_balance();
+            return;
+        }
+        // Dropping a rendezvous that was not waited on is a wait of its own.
+        let _span = (!self.released).then(|| self.wait_span("drop"));
+        self.rendezvous_internal();
+        // In debug builds, a lost or duplicate completion signal is reported.
+        #[cfg(debug_assertions)]
+        self.check_balance();
     }
 }
 
@@ -402,11 +1511,146 @@ impl Display for RendezvousTimeoutError {
 
 impl Error for RendezvousTimeoutError {}
 
+/// Error that may occur when resetting a rendezvous using [`Rendezvous::reset`].
+#[derive(Debug, Eq, PartialEq)]
+pub enum ResetError {
+    /// Guards of the current round are still outstanding.
+    GuardsOutstanding,
+    /// Other clones of the rendezvous are still alive.
+    Cloned,
+}
+
+impl Display for ResetError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ResetError::GuardsOutstanding => write!(f, "Guards are still outstanding"),
+            ResetError::Cloned => write!(f, "Other clones of the rendezvous are still alive"),
+        }
+    }
+}
+
+impl Error for ResetError {}
+
+/// Error that may occur during a rendezvous process.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum RendezvousError {
+    /// A timeout occurred during a rendezvous process. The guards listed were still
+    /// outstanding at the moment of the timeout.
+    Timeout {
+        /// Snapshots of all guards outstanding at the moment of the timeout, ordered by id.
+        outstanding: Vec<GuardSnapshot>,
+    },
+    /// Guards were dropped while their thread was panicking; see
+    /// [`Rendezvous::rendezvous_checked`].
+    GuardPanicked {
+        /// The guards dropped while their thread was panicking, ordered by id.
+        panicked: Vec<GuardId>,
+    },
+    /// All guards were dropped before the requested number of them completed; see
+    /// [`Rendezvous::wait_for_n`].
+    TooFewGuards {
+        /// The number of guards requested to complete.
+        requested: usize,
+        /// The number of guards that completed.
+        completed: usize,
+    },
+    /// All guards were dropped before the requested weight completed; see
+    /// [`Rendezvous::rendezvous_weight_quorum`].
+    QuorumUnreachable {
+        /// The weight requested to complete.
+        requested: u64,
+        /// The weight of the guards that completed.
+        completed: u64,
+    },
+}
+
+impl RendezvousError {
+    /// Renders the outstanding guards as a tree following their fork hierarchy, or returns
+    /// `None` if no guards were outstanding.
+    ///
+    /// Guards forked off the [`Rendezvous`] itself, or off a guard that has completed since, are
+    /// rendered as roots; guards forked using [`RendezvousGuard::fork`] are nested below the
+    /// guard they were forked off. Each line shows the guard's identifier, label and age.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use rendezvous::RendezvousBuilder;
+    ///
+    /// let mut rendezvous = RendezvousBuilder::new().full_tracking().build();
+    /// let ingest = rendezvous.fork_guard_labeled("ingest");
+    /// let parse = ingest.fork();
+    ///
+    /// let error = rendezvous
+    ///     .rendezvous_timeout_snapshot(Duration::from_millis(10))
+    ///     .unwrap_err();
+    /// let tree = error.outstanding_tree().unwrap();
+    /// assert!(tree.starts_with("#0 'ingest'"));
+    /// assert!(tree.contains("└── #1"));
+    /// # drop((ingest, parse));
+    /// ```
+    pub fn outstanding_tree(&self) -> Option<String> {
+        match self {
+            RendezvousError::Timeout { outstanding } => registry::render_tree(outstanding),
+            RendezvousError::GuardPanicked { .. }
+            | RendezvousError::TooFewGuards { .. }
+            | RendezvousError::QuorumUnreachable { .. } => None,
+        }
+    }
+}
+
+impl Display for RendezvousError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RendezvousError::Timeout { outstanding } => {
+                write!(f, "Timeout with {} outstanding guards", outstanding.len())?;
+                for (index, guard) in outstanding.iter().enumerate() {
+                    let separator = if index == 0 { ": " } else { ", " };
+                    write!(f, "{separator}{guard}")?;
+                }
+                Ok(())
+            }
+            RendezvousError::GuardPanicked { panicked } => {
+                write!(f, "{} guards panicked", panicked.len())?;
+                for (index, guard) in panicked.iter().enumerate() {
+                    let separator = if index == 0 { ": " } else { ", " };
+                    write!(f, "{separator}{guard}")?;
+                }
+                Ok(())
+            }
+            RendezvousError::TooFewGuards {
+                requested,
+                completed,
+            } => write!(
+                f,
+                "Only {completed} of {requested} requested guards completed"
+            ),
+            RendezvousError::QuorumUnreachable {
+                requested,
+                completed,
+            } => write!(
+                f,
+                "Only a weight of {completed} of the requested {requested} completed"
+            ),
+        }
+    }
+}
+
+impl Error for RendezvousError {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::thread;
 
+    #[test]
+    fn types_are_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<Rendezvous>();
+        assert_send::<RendezvousGuard>();
+    }
+
     #[test]
     fn rendezvous_can_pass_away() {
         let rendezvous = Rendezvous::new();
@@ -429,6 +1673,224 @@ mod tests {
         drop(guard);
     }
 
+    #[cfg(feature = "tokio")]
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn async_timeout_yields_to_guard_tasks() {
+        let mut rendezvous = Rendezvous::new();
+        let guard = rendezvous.fork_guard();
+        let result = rendezvous
+            .rendezvous_timeout_async(Duration::from_millis(100))
+            .await;
+        assert_eq!(result, Err(RendezvousTimeoutError::Timeout));
+
+        // On a single-threaded runtime, the guard task only runs if the wait yields.
+        tokio::spawn(async move { drop(guard) });
+        let result = rendezvous
+            .rendezvous_timeout_async(Duration::from_secs(5))
+            .await;
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn rendezvous_without_guards_does_not_create_channel() {
+        let rendezvous = Rendezvous::new();
+        assert!(rendezvous.channel.get().is_none());
+        rendezvous.rendezvous();
+    }
+
+    #[test]
+    fn fork_guard_lazily_creates_channel() {
+        let mut rendezvous = Rendezvous::new();
+        assert!(rendezvous.channel.get().is_none());
+
+        let guard = rendezvous.fork_guard();
+        assert!(rendezvous.channel.get().is_some());
+
+        let result = rendezvous.rendezvous_timeout(Duration::from_millis(10));
+        assert_eq!(result, Err(RendezvousTimeoutError::Timeout));
+        drop(guard);
+        assert_eq!(
+            rendezvous.rendezvous_timeout(Duration::from_millis(10)),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn timeout_without_guards_succeeds_immediately() {
+        let mut rendezvous = Rendezvous::new();
+        assert_eq!(rendezvous.rendezvous_timeout(Duration::ZERO), Ok(()));
+    }
+
+    #[test]
+    fn guard_count_tracks_live_guards() {
+        let rendezvous = Rendezvous::new();
+        assert_eq!(rendezvous.guard_count(), 0);
+
+        let guard = rendezvous.fork_guard();
+        let nested = guard.fork();
+        assert_eq!(rendezvous.guard_count(), 2);
+
+        drop(guard);
+        assert_eq!(rendezvous.guard_count(), 1);
+        drop(nested);
+        assert_eq!(rendezvous.guard_count(), 0);
+        rendezvous.rendezvous();
+    }
+
+    #[test]
+    fn remaining_timeout_shrinks_by_the_time_waited() {
+        let mut rendezvous = Rendezvous::new();
+        let guard = rendezvous.fork_guard();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(50));
+            drop(guard);
+        });
+
+        let timeout = Duration::from_secs(5);
+        let remaining = rendezvous.rendezvous_timeout_remaining(timeout).unwrap();
+        assert!(remaining <= timeout - Duration::from_millis(50));
+        assert!(remaining > Duration::ZERO);
+
+        let mut stuck = Rendezvous::new();
+        let guard = stuck.fork_guard();
+        assert_eq!(
+            stuck.rendezvous_timeout_remaining(Duration::from_millis(10)),
+            Err(RendezvousTimeoutError::Timeout)
+        );
+        drop(guard);
+    }
+
+    #[test]
+    fn exactly_one_guard_completes_last() {
+        let rendezvous = Rendezvous::new();
+        let guard = rendezvous.fork_guard();
+        assert!(guard.is_last());
+        let other = guard.fork();
+        assert!(!guard.is_last());
+        assert!(!other.complete_if_last());
+        assert!(guard.is_last());
+        assert!(guard.complete_if_last());
+
+        let guards: Vec<_> = (0..8).map(|_| rendezvous.fork_guard()).collect();
+        let workers: Vec<_> = guards
+            .into_iter()
+            .map(|guard| thread::spawn(move || guard.complete_if_last()))
+            .collect();
+        rendezvous.rendezvous();
+        let lasts = workers
+            .into_iter()
+            .map(|worker| worker.join().unwrap())
+            .filter(|&last| last)
+            .count();
+        assert_eq!(lasts, 1);
+    }
+
+    #[test]
+    fn nested_fork_chains_keep_the_rendezvous_open() {
+        let mut rendezvous = RendezvousBuilder::new().full_tracking().build();
+        let root = rendezvous.fork_guard_labeled("root");
+        let child = root.fork_labeled("child");
+        let grandchild = child.fork_labeled("grandchild");
+        let sibling = child.fork();
+        assert_eq!(rendezvous.guard_count(), 4);
+
+        let labels: Vec<_> = rendezvous
+            .shared()
+            .snapshot()
+            .into_iter()
+            .map(|guard| (guard.parent, guard.label))
+            .collect();
+        assert_eq!(
+            labels,
+            vec![
+                (None, Some("root".to_owned())),
+                (Some(root.id()), Some("child".to_owned())),
+                (Some(child.id()), Some("grandchild".to_owned())),
+                (Some(child.id()), None),
+            ]
+        );
+
+        // Dropping the ancestors first leaves the rendezvous open for the descendants.
+        drop((root, child, sibling));
+        assert_eq!(rendezvous.guard_count(), 1);
+        assert_eq!(
+            rendezvous.rendezvous_timeout(Duration::from_millis(10)),
+            Err(RendezvousTimeoutError::Timeout)
+        );
+        thread::spawn(move || drop(grandchild));
+        assert_eq!(
+            rendezvous.rendezvous_timeout(Duration::from_secs(5)),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn counted_rendezvous_includes_early_drops() {
+        let rendezvous = Rendezvous::new();
+        assert_eq!(Rendezvous::new().rendezvous_counted(), 0);
+
+        let early = rendezvous.fork_guard();
+        early.fork().completed();
+        drop(early);
+        let late: Vec<_> = (0..3).map(|_| rendezvous.fork_guard()).collect();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(10));
+            drop(late);
+        });
+        assert_eq!(rendezvous.rendezvous_counted(), 5);
+    }
+
+    #[test]
+    fn context_is_accessible() {
+        let rendezvous = Rendezvous::with_context(String::from("request-1"));
+        assert_eq!(rendezvous.context(), "request-1");
+        rendezvous.rendezvous();
+    }
+
+    #[test]
+    #[cfg(feature = "log")]
+    fn context_appears_in_log_messages() {
+        crate::test_log::init();
+
+        let rendezvous = Rendezvous::with_context(String::from("correlation-7b1f"));
+        let guard = rendezvous.fork_guard();
+        drop(guard);
+        rendezvous.rendezvous();
+
+        let messages = crate::test_log::messages_containing("correlation-7b1f");
+        assert!(messages
+            .iter()
+            .any(|(_, message)| message
+                == "Forking rendezvous guard [\"correlation-7b1f\", round 0]"));
+    }
+
+    #[test]
+    fn rendezvous_ref_waits_in_place() {
+        struct Holder {
+            rendezvous: Rendezvous,
+        }
+
+        let mut holder = Holder {
+            rendezvous: Rendezvous::new(),
+        };
+        let guard = holder.rendezvous.fork_guard();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(50));
+            drop(guard);
+        });
+
+        holder.rendezvous.rendezvous_ref();
+        assert_eq!(holder.rendezvous.rendezvous_timeout(Duration::ZERO), Ok(()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn fork_after_rendezvous_ref_panics() {
+        let mut rendezvous = Rendezvous::new();
+        rendezvous.rendezvous_ref();
+        rendezvous.fork_guard();
+    }
+
     #[test]
     fn test_background_forks() {
         let rendezvous = Rendezvous::new();
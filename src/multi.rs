@@ -0,0 +1,88 @@
+//! Guards participating in several rendezvous at once.
+
+use crate::{Rendezvous, RendezvousGuard};
+
+/// A guard holding one [`RendezvousGuard`] of each of several rendezvous, releasing all of
+/// them together when dropped.
+///
+/// ## Example
+///
+/// ```
+/// use std::thread;
+/// use rendezvous::{MultiGuard, Rendezvous};
+///
+/// let ingest = Rendezvous::new();
+/// let metrics = Rendezvous::new();
+///
+/// let guard = MultiGuard::new(&[&ingest, &metrics]);
+/// thread::spawn(move || drop(guard));
+///
+/// ingest.rendezvous();
+/// metrics.rendezvous();
+/// ```
+pub struct MultiGuard {
+    /// The guards of all rendezvous, in the order they were provided.
+    guards: Vec<RendezvousGuard>,
+}
+
+impl MultiGuard {
+    /// Forks one guard off each rendezvous in `rendezvous`.
+    pub fn new<C>(rendezvous: &[&Rendezvous<C>]) -> Self {
+        Self {
+            guards: rendezvous.iter().map(|r| r.fork_guard()).collect(),
+        }
+    }
+
+    /// Returns the guards held, in the order the rendezvous were provided.
+    pub fn guards(&self) -> &[RendezvousGuard] {
+        &self.guards
+    }
+
+    /// Forks another multi-guard participating in the same rendezvous.
+    pub fn fork(&self) -> Self {
+        Self {
+            guards: self.guards.iter().map(RendezvousGuard::fork).collect(),
+        }
+    }
+}
+
+impl From<Vec<RendezvousGuard>> for MultiGuard {
+    /// Combines existing guards, e.g. of rendezvous with different context types.
+    fn from(guards: Vec<RendezvousGuard>) -> Self {
+        Self { guards }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RendezvousTimeoutError;
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn multi_guard_gates_all_rendezvous() {
+        let mut rendezvous = [Rendezvous::new(), Rendezvous::new(), Rendezvous::new()];
+        let guard = {
+            let [first, second, third] = &rendezvous;
+            MultiGuard::new(&[first, second, third])
+        };
+        assert_eq!(guard.guards().len(), 3);
+
+        for rendezvous in &mut rendezvous {
+            assert_eq!(rendezvous.remaining_guards(), 1);
+            assert_eq!(
+                rendezvous.rendezvous_timeout(Duration::from_millis(10)),
+                Err(RendezvousTimeoutError::Timeout)
+            );
+        }
+
+        thread::spawn(move || drop(guard));
+        for rendezvous in &mut rendezvous {
+            assert_eq!(
+                rendezvous.rendezvous_timeout(Duration::from_secs(5)),
+                Ok(())
+            );
+        }
+    }
+}
@@ -0,0 +1,113 @@
+//! A rendezvous that is consumed by its first wait.
+
+use crate::{Rendezvous, RendezvousGuard, RendezvousTimeoutError};
+use std::time::Duration;
+
+/// A [`Rendezvous`] whose wait methods all take `self`, so that it cannot be used after the
+/// first wait.
+///
+/// Waiting on a [`Rendezvous`] using e.g. [`Rendezvous::rendezvous_timeout`] borrows it
+/// mutably, so it can still be used afterwards, with forking panicking at runtime. With a
+/// `OneShotRendezvous`, any use after the first wait is a compile error instead:
+///
+/// ```compile_fail
+/// use std::time::Duration;
+/// use rendezvous::OneShotRendezvous;
+///
+/// let rendezvous = OneShotRendezvous::new();
+/// rendezvous.rendezvous_timeout(Duration::from_secs(1)).ok();
+/// rendezvous.rendezvous(); // does not compile
+/// ```
+///
+/// ## Example
+///
+/// ```
+/// use std::thread;
+/// use std::time::Duration;
+/// use rendezvous::OneShotRendezvous;
+///
+/// let rendezvous = OneShotRendezvous::new();
+/// let guard = rendezvous.fork_guard();
+/// thread::spawn(move || drop(guard));
+/// assert_eq!(rendezvous.rendezvous_timeout(Duration::from_secs(5)), Ok(()));
+/// ```
+pub struct OneShotRendezvous {
+    /// The underlying rendezvous.
+    inner: Rendezvous,
+}
+
+impl OneShotRendezvous {
+    /// Creates a new instance.
+    pub fn new() -> Self {
+        Self {
+            inner: Rendezvous::new(),
+        }
+    }
+
+    /// Forks a guard off the rendezvous. See [`Rendezvous::fork_guard`].
+    pub fn fork_guard(&self) -> RendezvousGuard {
+        self.inner.fork_guard()
+    }
+
+    /// Forks a labeled guard off the rendezvous. See [`Rendezvous::fork_guard_labeled`].
+    pub fn fork_guard_labeled(&self, label: impl Into<String>) -> RendezvousGuard {
+        self.inner.fork_guard_labeled(label)
+    }
+
+    /// Returns the number of guards not yet dropped. See [`Rendezvous::remaining_guards`].
+    pub fn remaining_guards(&self) -> usize {
+        self.inner.remaining_guards()
+    }
+
+    /// Blocks until all guards are dropped, consuming the rendezvous.
+    /// See [`Rendezvous::rendezvous`].
+    pub fn rendezvous(self) {
+        self.inner.rendezvous();
+    }
+
+    /// Blocks until all guards are dropped or the timeout occurs, consuming the rendezvous.
+    /// See [`Rendezvous::rendezvous_timeout`].
+    ///
+    /// On timeout, the rendezvous is dropped without waiting for the outstanding guards; they
+    /// remain valid, but nothing waits for them anymore.
+    pub fn rendezvous_timeout(mut self, timeout: Duration) -> Result<(), RendezvousTimeoutError> {
+        let result = self.inner.rendezvous_timeout(timeout);
+        self.inner.abandoned = true;
+        result
+    }
+}
+
+impl Default for OneShotRendezvous {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<Rendezvous> for OneShotRendezvous {
+    /// Wraps a rendezvous, e.g. one configured using a
+    /// [`RendezvousBuilder`](crate::RendezvousBuilder).
+    fn from(inner: Rendezvous) -> Self {
+        Self { inner }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    #[test]
+    fn timeout_consumes_without_blocking() {
+        let rendezvous = OneShotRendezvous::new();
+        let guard = rendezvous.fork_guard_labeled("stuck");
+        assert_eq!(rendezvous.remaining_guards(), 1);
+
+        let started = Instant::now();
+        assert_eq!(
+            rendezvous.rendezvous_timeout(Duration::from_millis(10)),
+            Err(RendezvousTimeoutError::Timeout)
+        );
+        assert!(started.elapsed() < Duration::from_secs(1));
+        drop(guard);
+    }
+}
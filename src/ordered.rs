@@ -0,0 +1,133 @@
+//! Releasing the values delivered by guards in guard id order.
+
+use crate::bridge::SignalSender;
+use crate::{GuardId, Shared, Signal};
+use std::collections::BTreeMap;
+use std::sync::atomic::Ordering;
+
+/// A guard that delivered a value or completed, but whose predecessors did not all complete.
+struct Pending<T> {
+    /// The value delivered using
+    /// [`CollectingGuard::complete_with`](crate::CollectingGuard::complete_with), if any.
+    value: Option<T>,
+    /// Whether the guard completed.
+    completed: bool,
+}
+
+/// Buffers the values delivered by guards until all guards with lower ids completed.
+pub(crate) struct Reorder<T> {
+    /// The id of the next guard to release.
+    next: u64,
+    /// The guards not yet released, by id.
+    pending: BTreeMap<GuardId, Pending<T>>,
+}
+
+impl<T> Default for Reorder<T> {
+    fn default() -> Self {
+        Self {
+            next: 0,
+            pending: BTreeMap::new(),
+        }
+    }
+}
+
+impl<T> Reorder<T> {
+    /// Buffers the value delivered by guard `id`.
+    pub(crate) fn value(&mut self, id: GuardId, value: T) {
+        self.entry(id).value = Some(value);
+    }
+
+    /// Marks guard `id` as completed.
+    pub(crate) fn completed(&mut self, id: GuardId) {
+        self.entry(id).completed = true;
+    }
+
+    /// Passes the values of all guards completed in contiguous id order to `f`.
+    pub(crate) fn release(&mut self, mut f: impl FnMut(GuardId, T)) {
+        while let Some(entry) = self.pending.first_entry() {
+            if entry.key().0 != self.next || !entry.get().completed {
+                return;
+            }
+            let (id, pending) = entry.remove_entry();
+            self.next += 1;
+            if let Some(value) = pending.value {
+                f(id, value);
+            }
+        }
+    }
+
+    /// Passes all remaining values to `f` in id order, regardless of gaps.
+    pub(crate) fn flush(self, mut f: impl FnMut(GuardId, T)) {
+        for (id, pending) in self.pending {
+            if let Some(value) = pending.value {
+                f(id, value);
+            }
+        }
+    }
+
+    /// Returns the pending entry of guard `id`, creating it if needed.
+    fn entry(&mut self, id: GuardId) -> &mut Pending<T> {
+        self.pending.entry(id).or_insert(Pending {
+            value: None,
+            completed: false,
+        })
+    }
+}
+
+impl Shared {
+    /// Reports the completion of guard `id` through `tx`, if completions are ordered or
+    /// iterated.
+    pub(crate) fn signal_completion(&self, id: GuardId, tx: &SignalSender) {
+        if self.options.deterministic_order || self.iterated.load(Ordering::Acquire) {
+            tx.send(Signal::Completed(id));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::RendezvousBuilder;
+    use std::thread;
+
+    #[test]
+    fn values_are_released_in_id_order() {
+        let rendezvous = RendezvousBuilder::new()
+            .deterministic_order(true)
+            .build_collecting();
+        let guards: Vec<_> = (0..4).map(|_| rendezvous.fork_guard()).collect();
+        let ids: Vec<_> = guards.iter().map(|guard| guard.id()).collect();
+        // Completed in reverse id order.
+        thread::spawn(move || {
+            for (index, guard) in guards.into_iter().enumerate().rev() {
+                if index == 2 {
+                    drop(guard);
+                } else {
+                    guard.complete_with(index);
+                }
+            }
+        });
+
+        let mut received = Vec::new();
+        rendezvous.rendezvous_each(|id, index| received.push((id, index)));
+        assert_eq!(received, vec![(ids[0], 0), (ids[1], 1), (ids[3], 3)]);
+    }
+
+    #[test]
+    fn gaps_are_flushed_once_all_guards_dropped() {
+        let mut rendezvous = RendezvousBuilder::new().deterministic_order(true).build();
+        let armed = rendezvous.fork_guard();
+        armed.arm_completion();
+        let late = rendezvous.fork_guard();
+        let early = rendezvous.fork_guard();
+        early.deliver("early");
+        rendezvous.complete_armed();
+        thread::spawn(move || late.deliver("late"));
+
+        // The armed guard never signals its completion, so the values are flushed in id order
+        // once all guards dropped.
+        let mut values = Vec::new();
+        rendezvous.for_each_value(|_id, value: &str| values.push(value));
+        assert_eq!(values, vec!["late", "early"]);
+        drop(armed);
+    }
+}
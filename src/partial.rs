@@ -0,0 +1,125 @@
+//! Waiting until a number of guards completed, rather than all of them.
+
+use crate::{Rendezvous, RendezvousError, POLL_INTERVAL};
+use std::ops::ControlFlow;
+use std::sync::atomic::Ordering;
+use std::time::Instant;
+
+impl<C> Rendezvous<C> {
+    /// Blocks until at least `n` guards completed, then returns without waiting for the others.
+    ///
+    /// Guards dropped before this call count towards `n`. Like
+    /// [`Rendezvous::rendezvous_timeout`], this releases the sender held by the rendezvous, so
+    /// no further guards can be forked off it; the remaining guards still keep the rendezvous
+    /// open, and a later wait or dropping the rendezvous waits for them. The completion count is
+    /// polled every few milliseconds, so this returns slightly after the `n`-th guard completed.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`RendezvousError::TooFewGuards`] if all guards were dropped before `n` of them
+    /// completed, i.e. if fewer than `n` guards were forked in total.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::thread;
+    /// use std::time::Duration;
+    /// use rendezvous::Rendezvous;
+    ///
+    /// let mut rendezvous = Rendezvous::new();
+    /// for delay in [10, 20, 200] {
+    ///     let guard = rendezvous.fork_guard();
+    ///     thread::spawn(move || {
+    ///         thread::sleep(Duration::from_millis(delay));
+    ///         drop(guard);
+    ///     });
+    /// }
+    ///
+    /// rendezvous.wait_for_n(2).unwrap();
+    /// assert!(rendezvous.remaining_guards() <= 1);
+    /// ```
+    pub fn wait_for_n(&mut self, n: usize) -> Result<(), RendezvousError> {
+        let _span = self.wait_span("wait_for_n");
+        let started = Instant::now();
+        let mut completed = 0;
+        if let Some(channel) = self.release() {
+            let shared = &channel.shared;
+            channel.poll_until(|| {
+                if shared.completed.load(Ordering::SeqCst) >= n {
+                    ControlFlow::Break(())
+                } else {
+                    ControlFlow::Continue(POLL_INTERVAL)
+                }
+            });
+            completed = shared.completed.load(Ordering::SeqCst);
+        }
+        self.record_wait(started);
+        if completed >= n {
+            return Ok(());
+        }
+        #[cfg(any(feature = "log", feature = "tracing"))]
+        {
+            debug!(
+                "Only {completed} of {n} requested guards completed{}",
+                self.log_context()
+            );
+        }
+        Err(RendezvousError::TooFewGuards {
+            requested: n,
+            completed,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RendezvousTimeoutError;
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn returns_once_n_guards_completed() {
+        let mut rendezvous = Rendezvous::new();
+        drop(rendezvous.fork_guard());
+        let slow = rendezvous.fork_guard();
+        let fast = rendezvous.fork_guard();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(10));
+            drop(fast);
+        });
+
+        assert_eq!(rendezvous.wait_for_n(2), Ok(()));
+        assert_eq!(rendezvous.remaining_guards(), 1);
+        assert_eq!(
+            rendezvous.rendezvous_timeout(Duration::from_millis(10)),
+            Err(RendezvousTimeoutError::Timeout)
+        );
+        drop(slow);
+        rendezvous.rendezvous();
+    }
+
+    #[test]
+    fn fails_if_too_few_guards_were_forked() {
+        let mut rendezvous = Rendezvous::new();
+        let guard = rendezvous.fork_guard();
+        thread::spawn(move || drop(guard));
+
+        let error = rendezvous.wait_for_n(2).unwrap_err();
+        assert_eq!(
+            error,
+            RendezvousError::TooFewGuards {
+                requested: 2,
+                completed: 1
+            }
+        );
+        assert_eq!(error.to_string(), "Only 1 of 2 requested guards completed");
+        assert_eq!(
+            Rendezvous::new().wait_for_n(1),
+            Err(RendezvousError::TooFewGuards {
+                requested: 1,
+                completed: 0
+            })
+        );
+    }
+}
@@ -0,0 +1,172 @@
+//! A rendezvous whose original sender was released.
+
+use crate::{Completions, Rendezvous, RendezvousTimeoutError};
+use std::time::Duration;
+
+/// A [`Rendezvous`] whose original sender was released, obtained using
+/// [`Rendezvous::into_pending`].
+///
+/// Waiting on a [`Rendezvous`] releases its original sender, but the rendezvous keeps offering
+/// its forking methods, which then panic at runtime. A `RendezvousPending` makes the released
+/// state explicit: it only offers waiting, so forking off it is a compile error:
+///
+/// ```compile_fail
+/// use rendezvous::Rendezvous;
+///
+/// let pending = Rendezvous::new().into_pending();
+/// let guard = pending.fork_guard(); // does not compile
+/// ```
+///
+/// Guards forked before can still fork further guards off themselves.
+///
+/// ## Example
+///
+/// ```
+/// use std::thread;
+/// use std::time::Duration;
+/// use rendezvous::{Rendezvous, RendezvousTimeoutError};
+///
+/// let rendezvous = Rendezvous::new();
+/// let guard = rendezvous.fork_guard();
+///
+/// let mut pending = rendezvous.into_pending();
+/// assert_eq!(pending.try_rendezvous(), Err(RendezvousTimeoutError::Timeout));
+///
+/// thread::spawn(move || drop(guard));
+/// assert_eq!(pending.rendezvous_timeout(Duration::from_secs(5)), Ok(()));
+/// ```
+pub struct RendezvousPending<C = ()> {
+    /// The underlying rendezvous, with its original sender released.
+    inner: Rendezvous<C>,
+}
+
+impl<C> Rendezvous<C> {
+    /// Releases the original sender, turning the rendezvous into a [`RendezvousPending`] that
+    /// can only be waited on.
+    pub fn into_pending(mut self) -> RendezvousPending<C> {
+        self.release();
+        RendezvousPending { inner: self }
+    }
+}
+
+impl<C> RendezvousPending<C> {
+    /// Returns the number of guards not yet dropped. See [`Rendezvous::remaining_guards`].
+    pub fn remaining_guards(&self) -> usize {
+        self.inner.remaining_guards()
+    }
+
+    /// Returns the context attached to the rendezvous. See [`Rendezvous::context`].
+    pub fn context(&self) -> &C {
+        self.inner.context()
+    }
+
+    /// Returns immediately, succeeding if all guards were dropped.
+    ///
+    /// Returns [`RendezvousTimeoutError::Timeout`] if any guard is still outstanding.
+    pub fn try_rendezvous(&mut self) -> Result<(), RendezvousTimeoutError> {
+        self.rendezvous_timeout(Duration::ZERO)
+    }
+
+    /// Blocks until all guards are dropped or the timeout occurs.
+    /// See [`Rendezvous::rendezvous_timeout`].
+    pub fn rendezvous_timeout(&mut self, timeout: Duration) -> Result<(), RendezvousTimeoutError> {
+        self.inner.rendezvous_timeout(timeout)
+    }
+
+    /// Blocks until all guards are dropped, consuming the rendezvous.
+    /// See [`Rendezvous::rendezvous`].
+    pub fn rendezvous(self) {
+        self.inner.rendezvous();
+    }
+
+    /// Returns an iterator yielding once for every guard that completes.
+    /// See [`Rendezvous::completions`].
+    pub fn completions(&mut self) -> Completions<'_, C> {
+        self.inner.completions()
+    }
+}
+
+/// Iterates over the completions of the guards; see [`RendezvousPending::completions`].
+///
+/// ## Example
+///
+/// ```
+/// use std::thread;
+/// use rendezvous::Rendezvous;
+///
+/// let rendezvous = Rendezvous::new();
+/// for _ in 0..3 {
+///     let guard = rendezvous.fork_guard();
+///     thread::spawn(move || drop(guard));
+/// }
+///
+/// let mut pending = rendezvous.into_pending();
+/// let mut finished = 0;
+/// for () in &mut pending {
+///     finished += 1;
+/// }
+/// assert_eq!(finished, 3);
+/// ```
+impl<'a, C> IntoIterator for &'a mut RendezvousPending<C> {
+    type Item = ();
+    type IntoIter = Completions<'a, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.completions()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn pending_rendezvous_waits_for_guards() {
+        let rendezvous = Rendezvous::new();
+        let guard = rendezvous.fork_guard();
+        let nested = guard.fork();
+
+        let mut pending = rendezvous.into_pending();
+        assert_eq!(pending.remaining_guards(), 2);
+        assert_eq!(
+            pending.try_rendezvous(),
+            Err(RendezvousTimeoutError::Timeout)
+        );
+        assert_eq!(
+            pending.rendezvous_timeout(Duration::from_millis(10)),
+            Err(RendezvousTimeoutError::Timeout)
+        );
+
+        drop(guard);
+        thread::spawn(move || drop(nested));
+        pending.rendezvous();
+    }
+
+    #[test]
+    fn pending_rendezvous_iterates_over_completions() {
+        let rendezvous = Rendezvous::new();
+        let guards: Vec<_> = (0..3).map(|_| rendezvous.fork_guard()).collect();
+        let mut pending = rendezvous.into_pending();
+        thread::spawn(move || {
+            for guard in guards {
+                thread::sleep(Duration::from_millis(5));
+                drop(guard);
+            }
+        });
+
+        let mut completed = 0;
+        for () in &mut pending {
+            completed += 1;
+        }
+        assert_eq!(completed, 3);
+        assert_eq!(pending.remaining_guards(), 0);
+        assert_eq!(pending.try_rendezvous(), Ok(()));
+    }
+
+    #[test]
+    fn pending_rendezvous_without_guards_is_complete() {
+        let mut pending = Rendezvous::new().into_pending();
+        assert_eq!(pending.try_rendezvous(), Ok(()));
+    }
+}
@@ -0,0 +1,83 @@
+//! Tracking the phase each outstanding guard is in.
+
+use crate::{lock, Rendezvous, RendezvousGuard};
+use std::collections::HashMap;
+
+impl RendezvousGuard {
+    /// Reports that the holder of this guard entered the phase `name`, replacing the previous
+    /// phase.
+    ///
+    /// Phases give a live view of multi-stage work; see [`Rendezvous::phase_histogram`].
+    /// Phases are discarded in [minimal](crate::RendezvousBuilder::minimal) mode.
+    pub fn enter_phase(&self, name: &str) {
+        if let Some(record) = lock(&self.shared.registry).get_mut(&self.id) {
+            record.phase = Some(name.to_owned());
+        }
+    }
+}
+
+impl<C> Rendezvous<C> {
+    /// Counts the outstanding guards per phase entered using [`RendezvousGuard::enter_phase`].
+    ///
+    /// Guards that did not enter any phase yet are not counted.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use rendezvous::RendezvousBuilder;
+    ///
+    /// let rendezvous = RendezvousBuilder::new().full_tracking().build();
+    /// let first = rendezvous.fork_guard();
+    /// let second = rendezvous.fork_guard();
+    /// first.enter_phase("parse");
+    /// second.enter_phase("parse");
+    /// second.enter_phase("write");
+    ///
+    /// let histogram = rendezvous.phase_histogram();
+    /// assert_eq!(histogram["parse"], 1);
+    /// assert_eq!(histogram["write"], 1);
+    /// # drop((first, second));
+    /// ```
+    pub fn phase_histogram(&self) -> HashMap<String, usize> {
+        let mut histogram = HashMap::new();
+        let Some(channel) = self.channel.get() else {
+            return histogram;
+        };
+        for record in lock(&channel.shared.registry).values() {
+            if let Some(phase) = &record.phase {
+                *histogram.entry(phase.clone()).or_insert(0) += 1;
+            }
+        }
+        histogram
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RendezvousBuilder;
+
+    #[test]
+    fn histogram_counts_live_guards_per_phase() {
+        let rendezvous = RendezvousBuilder::new().full_tracking().build();
+        let parsing: Vec<_> = (0..3).map(|_| rendezvous.fork_guard()).collect();
+        let writing: Vec<_> = (0..2).map(|_| rendezvous.fork_guard()).collect();
+        let idle = rendezvous.fork_guard();
+        parsing.iter().for_each(|guard| guard.enter_phase("parse"));
+        writing.iter().for_each(|guard| guard.enter_phase("parse"));
+        writing.iter().for_each(|guard| guard.enter_phase("write"));
+
+        let histogram = rendezvous.phase_histogram();
+        assert_eq!(histogram.len(), 2);
+        assert_eq!(histogram["parse"], 3);
+        assert_eq!(histogram["write"], 2);
+
+        drop(parsing);
+        assert_eq!(
+            rendezvous.phase_histogram(),
+            HashMap::from([("write".to_owned(), 2)])
+        );
+        drop((writing, idle));
+        assert!(rendezvous.phase_histogram().is_empty());
+    }
+}
@@ -0,0 +1,102 @@
+//! Progress pings sent by guards while they are still alive.
+
+use crate::{GuardId, Rendezvous, RendezvousGuard, Signal};
+use std::time::Instant;
+
+impl RendezvousGuard {
+    /// Sends a progress ping to the owning [`Rendezvous`].
+    ///
+    /// A ping does not complete the guard; it can be observed by a waiter using
+    /// [`Rendezvous::rendezvous_with_pings`]. Pings sent while nobody observes them
+    /// are discarded by the regular rendezvous methods.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::thread;
+    /// use rendezvous::Rendezvous;
+    ///
+    /// let mut rendezvous = Rendezvous::new();
+    /// let guard = rendezvous.fork_guard();
+    /// thread::spawn(move || {
+    ///     for _ in 0..3 {
+    ///         guard.ping();
+    ///     }
+    /// });
+    ///
+    /// let mut pings = 0;
+    /// rendezvous.rendezvous_with_pings(|_id| pings += 1);
+    /// assert_eq!(pings, 3);
+    /// ```
+    pub fn ping(&self) {
+        #[cfg(any(feature = "log", feature = "tracing"))]
+        {
+            trace!(
+                "Rendezvous guard {} pinged [round {}]",
+                self.id,
+                self.shared.round
+            );
+        }
+        if let Some(tx) = self.sender() {
+            tx.send(Signal::Ping(self.id));
+        }
+    }
+}
+
+impl<C> Rendezvous<C> {
+    /// Executes the rendezvous process, invoking `on_ping` for every progress ping
+    /// sent by a guard using [`RendezvousGuard::ping`] while waiting.
+    ///
+    /// Blocks until all guards are dropped, just like [`Rendezvous::rendezvous`]. The
+    /// callback is invoked on the waiting thread with the identifier of the pinging guard.
+    pub fn rendezvous_with_pings<F>(&mut self, mut on_ping: F)
+    where
+        F: FnMut(GuardId),
+    {
+        let _span = self.wait_span("rendezvous_with_pings");
+        let started = Instant::now();
+        if let Some(channel) = self.release() {
+            while let Ok(signal) = channel.recv() {
+                match signal {
+                    Signal::Ping(id) => on_ping(id),
+                    Signal::Failed(_) | Signal::Value(..) | Signal::Completed(_) => {}
+                }
+            }
+        }
+        self.record_wait(started);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn pings_are_observed_before_completion() {
+        let mut rendezvous = Rendezvous::new();
+        let guard = rendezvous.fork_guard();
+        let guard_id = guard.id();
+
+        thread::spawn(move || {
+            for _ in 0..3 {
+                guard.ping();
+                thread::sleep(Duration::from_millis(10));
+            }
+        });
+
+        let mut pings = Vec::new();
+        rendezvous.rendezvous_with_pings(|id| pings.push(id));
+        assert_eq!(pings, vec![guard_id; 3]);
+    }
+
+    #[test]
+    fn pings_are_ignored_by_plain_rendezvous() {
+        let rendezvous = Rendezvous::new();
+        let guard = rendezvous.fork_guard();
+        guard.ping();
+        drop(guard);
+        rendezvous.rendezvous();
+    }
+}
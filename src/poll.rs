@@ -0,0 +1,159 @@
+//! Polling for completion, as a building block for custom futures.
+
+use crate::{lock, Rendezvous, Shared};
+use std::sync::atomic::{fence, Ordering};
+use std::task::{Context, Poll, Waker};
+
+impl Shared {
+    /// Wakes all registered wakers if no guards are outstanding.
+    ///
+    /// This runs synchronously in the drop path of the last guard. The wakers are taken out
+    /// before waking them, so a woken task polling again does not contend for the lock.
+    pub(crate) fn wake_if_complete(&self) {
+        if !self.polled.load(Ordering::SeqCst) {
+            return;
+        }
+        let wakers = {
+            let mut wakers = lock(&self.wakers);
+            if wakers.is_empty() || self.live_guards() != 0 {
+                return;
+            }
+            std::mem::take(&mut *wakers)
+        };
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+}
+
+impl<C> Rendezvous<C> {
+    /// Polls whether all guards forked so far were dropped.
+    ///
+    /// Returns [`Poll::Ready`] if no guard is outstanding. Otherwise, the waker of `cx` is
+    /// registered to be woken when the last outstanding guard is dropped, and
+    /// [`Poll::Pending`] is returned. This is the primitive for implementing a [`Future`]
+    /// manually, which allows integrating the rendezvous with any executor without depending on
+    /// a specific runtime.
+    ///
+    /// Polling does not start the rendezvous: guards can still be forked afterwards, after
+    /// which the rendezvous is pending again.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::future::poll_fn;
+    /// use std::thread;
+    /// use rendezvous::Rendezvous;
+    ///
+    /// # tokio_test::block_on(async {
+    /// let rendezvous = Rendezvous::new();
+    /// let guard = rendezvous.fork_guard();
+    /// thread::spawn(move || drop(guard));
+    ///
+    /// poll_fn(|cx| rendezvous.poll_complete(cx)).await;
+    /// assert_eq!(rendezvous.remaining_guards(), 0);
+    /// # });
+    /// ```
+    pub fn poll_complete(&self, cx: &mut Context<'_>) -> Poll<()> {
+        let Some(channel) = self.channel.get() else {
+            return Poll::Ready(());
+        };
+        let shared = &channel.shared;
+        // The waker is registered before checking, under the lock taken by the last guard
+        // to wake it, so that a concurrent drop cannot be missed.
+        let mut wakers = lock(&shared.wakers);
+        // Pairs with the completion count and flag check of the dropping guard: either the
+        // guard observes the flag and takes the lock, or this observes its completion.
+        shared.polled.store(true, Ordering::SeqCst);
+        fence(Ordering::SeqCst);
+        if shared.live_guards() == 0 {
+            return Poll::Ready(());
+        }
+        register(&mut wakers, cx.waker());
+        Poll::Pending
+    }
+}
+
+/// Adds `waker` to `wakers`, unless an equivalent waker is registered already.
+fn register(wakers: &mut Vec<Waker>, waker: &Waker) {
+    if !wakers.iter().any(|registered| registered.will_wake(waker)) {
+        wakers.push(waker.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::task::{RawWaker, RawWakerVTable, Wake};
+
+    /// Returns a waker that does nothing when woken.
+    fn noop_waker() -> Waker {
+        const VTABLE: RawWakerVTable = RawWakerVTable::new(|_| RAW, |_| {}, |_| {}, |_| {});
+        const RAW: RawWaker = RawWaker::new(std::ptr::null(), &VTABLE);
+        // SAFETY: The vtable functions ignore the data pointer and do nothing.
+        unsafe { Waker::from_raw(RAW) }
+    }
+
+    #[derive(Default)]
+    struct CountingWaker(AtomicUsize);
+
+    impl Wake for CountingWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn poll_complete_is_pending_until_guard_drops() {
+        let rendezvous = Rendezvous::new();
+        let guard = rendezvous.fork_guard();
+
+        let noop_waker = noop_waker();
+        let mut noop = Context::from_waker(&noop_waker);
+        assert_eq!(rendezvous.poll_complete(&mut noop), Poll::Pending);
+
+        let counter = Arc::new(CountingWaker::default());
+        let waker = Waker::from(counter.clone());
+        let mut cx = Context::from_waker(&waker);
+        assert_eq!(rendezvous.poll_complete(&mut cx), Poll::Pending);
+        assert_eq!(rendezvous.poll_complete(&mut cx), Poll::Pending);
+
+        drop(guard);
+        assert_eq!(counter.0.load(Ordering::SeqCst), 1);
+        assert_eq!(rendezvous.poll_complete(&mut noop), Poll::Ready(()));
+        rendezvous.rendezvous();
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn waiting_task_is_woken_promptly() {
+        use std::time::{Duration, Instant};
+
+        let mut rendezvous = Rendezvous::new();
+        let guard = rendezvous.fork_guard();
+        let dropped = tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            drop(guard);
+            Instant::now()
+        });
+
+        let result = rendezvous
+            .rendezvous_timeout_async(Duration::from_secs(5))
+            .await;
+        let resolved = Instant::now();
+        assert_eq!(result, Ok(()));
+        let dropped = dropped.await.unwrap();
+        // Far below the timeout, so the task was woken rather than timing out.
+        assert!(resolved.saturating_duration_since(dropped) < Duration::from_secs(2));
+    }
+
+    #[test]
+    fn poll_complete_without_guards_is_ready() {
+        let rendezvous = Rendezvous::new();
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+        assert_eq!(rendezvous.poll_complete(&mut cx), Poll::Ready(()));
+    }
+}
@@ -0,0 +1,143 @@
+//! A fixed pool of worker threads running guarded tasks.
+
+use crate::{lock, Rendezvous, RendezvousGuard};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+
+/// A task submitted to a [`ThreadPool`].
+type Job = Box<dyn FnOnce() + Send>;
+
+/// A fixed number of worker threads taking jobs from a shared queue.
+///
+/// Dropping the pool closes the queue; the workers finish the jobs already queued and then
+/// exit. They are not joined.
+pub(crate) struct ThreadPool {
+    /// The sending side of the job queue.
+    jobs: mpsc::Sender<Job>,
+}
+
+impl ThreadPool {
+    /// Spawns `size` worker threads.
+    fn new(size: usize) -> Self {
+        let (jobs, queue) = mpsc::channel::<Job>();
+        let queue = Arc::new(Mutex::new(queue));
+        for index in 0..size {
+            let queue = queue.clone();
+            thread::Builder::new()
+                .name(format!("rendezvous-pool-{index}"))
+                .spawn(move || loop {
+                    let job = lock(&queue).recv();
+                    match job {
+                        // A panicking job must not take the worker down with it; the panic
+                        // is reported by the job itself, if needed.
+                        Ok(job) => drop(panic::catch_unwind(AssertUnwindSafe(job))),
+                        Err(_) => break,
+                    }
+                })
+                .expect("failed to spawn a rendezvous pool thread");
+        }
+        Self { jobs }
+    }
+
+    /// Queues `job` to run on one of the workers.
+    pub(crate) fn execute<F>(&self, job: F)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        self.jobs
+            .send(Box::new(job))
+            .expect("the pool workers outlive the pool");
+    }
+}
+
+impl<C> Rendezvous<C> {
+    /// Returns the thread pool, spawning its workers on first use, or `None` if the rendezvous
+    /// was not built with one.
+    pub(crate) fn pool(&self) -> Option<&ThreadPool> {
+        let size = self.options.thread_pool?;
+        Some(self.pool.get_or_init(|| ThreadPool::new(size)))
+    }
+
+    /// Runs `f` with a guard forked off this rendezvous, on the thread pool if the rendezvous
+    /// was built with [`RendezvousBuilder::thread_pool`](crate::RendezvousBuilder::thread_pool),
+    /// or on a freshly spawned thread otherwise.
+    ///
+    /// Unlike [`Rendezvous::spawn_guarded`], no [`JoinHandle`](thread::JoinHandle) is returned,
+    /// which allows reusing pooled threads. Use [`Rendezvous::spawn_guarded_collecting`] to
+    /// collect results.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::sync::Arc;
+    /// use rendezvous::RendezvousBuilder;
+    ///
+    /// let rendezvous = RendezvousBuilder::new().thread_pool(4).build();
+    /// let processed = Arc::new(AtomicUsize::new(0));
+    /// for _ in 0..100 {
+    ///     let processed = processed.clone();
+    ///     rendezvous.submit_guarded(move |_guard| {
+    ///         processed.fetch_add(1, Ordering::Relaxed);
+    ///     });
+    /// }
+    ///
+    /// rendezvous.rendezvous();
+    /// assert_eq!(processed.load(Ordering::Relaxed), 100);
+    /// ```
+    pub fn submit_guarded<F>(&self, f: F)
+    where
+        F: FnOnce(RendezvousGuard) + Send + 'static,
+    {
+        let guard = self.fork_guard();
+        match self.pool() {
+            Some(pool) => pool.execute(move || f(guard)),
+            None => drop(thread::spawn(move || f(guard))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RendezvousBuilder;
+    use std::collections::HashSet;
+    use std::time::Duration;
+
+    #[test]
+    fn pool_runs_more_tasks_than_threads() {
+        let rendezvous = RendezvousBuilder::new().thread_pool(2).build();
+        let threads = Arc::new(Mutex::new(Vec::new()));
+        for _ in 0..10 {
+            let threads = threads.clone();
+            rendezvous.submit_guarded(move |_guard| {
+                thread::sleep(Duration::from_millis(5));
+                lock(&threads).push(thread::current().id());
+            });
+        }
+
+        rendezvous.rendezvous();
+        let threads = lock(&threads);
+        assert_eq!(threads.len(), 10);
+        assert!(threads.iter().collect::<HashSet<_>>().len() <= 2);
+    }
+
+    #[test]
+    fn pool_collects_results_and_panics() {
+        let rendezvous = RendezvousBuilder::new().thread_pool(1).build();
+        for i in 0..3 {
+            rendezvous.spawn_guarded_collecting(move |_guard| {
+                if i == 1 {
+                    panic!("worker failed");
+                }
+                i
+            });
+        }
+
+        let results = rendezvous.rendezvous_join::<i32>();
+        assert!(matches!(results[0], Ok(0)));
+        assert!(results[1].is_err());
+        assert!(matches!(results[2], Ok(2)));
+    }
+}
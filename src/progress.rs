@@ -0,0 +1,154 @@
+//! A stream of completion fractions for driving asynchronous progress reporting.
+
+use crate::{lock, Rendezvous, Shared};
+use futures_core::Stream;
+use std::pin::Pin;
+use std::sync::atomic::Ordering;
+use std::task::{Context, Poll};
+use tokio::sync::mpsc;
+
+/// A subscriber of progress updates.
+pub(crate) struct Subscriber {
+    /// The channel progress updates are sent to.
+    tx: mpsc::UnboundedSender<f32>,
+    /// The fraction most recently sent to the subscriber.
+    last: Option<f32>,
+}
+
+/// A stream of completion fractions, created using [`Rendezvous::progress_stream`].
+///
+/// Yields the fraction of completed guards every time it changes and ends after yielding
+/// `1.0` once all guards were dropped and the rendezvous was started.
+pub struct ProgressStream {
+    /// The channel progress updates are received from.
+    rx: mpsc::UnboundedReceiver<f32>,
+}
+
+impl Stream for ProgressStream {
+    type Item = f32;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rx.poll_recv(cx)
+    }
+}
+
+impl<C> Rendezvous<C> {
+    /// Returns a [`Stream`] of the fraction of completed guards.
+    ///
+    /// The fraction is the number of dropped guards divided by the number of forked guards.
+    /// A new value is yielded each time the fraction changes, starting with the current value
+    /// if guards were forked already. Since further guards can be forked while the stream is
+    /// consumed, the fraction may dip. The stream ends after yielding `1.0` once all guards were
+    /// dropped and the rendezvous was started, e.g. using [`Rendezvous::rendezvous`].
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::future::poll_fn;
+    /// use std::pin::Pin;
+    /// use futures_core::Stream;
+    /// use rendezvous::Rendezvous;
+    ///
+    /// # tokio_test::block_on(async {
+    /// let rendezvous = Rendezvous::new();
+    /// let mut progress = rendezvous.progress_stream();
+    ///
+    /// let guards = [rendezvous.fork_guard(), rendezvous.fork_guard()];
+    /// tokio::task::spawn_blocking(move || rendezvous.rendezvous());
+    /// drop(guards);
+    ///
+    /// let mut last = 0.0;
+    /// while let Some(fraction) = poll_fn(|cx| Pin::new(&mut progress).poll_next(cx)).await {
+    ///     last = fraction;
+    /// }
+    /// assert_eq!(last, 1.0);
+    /// # });
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+    pub fn progress_stream(&self) -> ProgressStream {
+        let (tx, rx) = mpsc::unbounded_channel();
+        if self.released && self.channel.get().is_none() {
+            // Nothing was ever forked and nothing can be forked anymore.
+            tx.send(1.0).ok();
+            return ProgressStream { rx };
+        }
+        let shared = self.shared();
+        lock(&shared.progress).push(Subscriber { tx, last: None });
+        shared.notify_progress();
+        ProgressStream { rx }
+    }
+}
+
+impl Shared {
+    /// Sends the current completion fraction to all subscribers whose last value differs.
+    ///
+    /// Once all guards are dropped and the rendezvous was started, the subscribers are
+    /// removed, ending their streams.
+    pub(crate) fn notify_progress(&self) {
+        let mut subscribers = lock(&self.progress);
+        if subscribers.is_empty() {
+            return;
+        }
+        let forked = self.forked.load(Ordering::Acquire);
+        let completed = self.completed.load(Ordering::Acquire);
+        let done = self.released.load(Ordering::Acquire) && completed >= forked;
+        if forked == 0 && !done {
+            return;
+        }
+        let fraction = if forked == 0 {
+            1.0
+        } else {
+            completed as f32 / forked as f32
+        };
+        subscribers.retain_mut(|subscriber| {
+            if subscriber.last == Some(fraction) {
+                return true;
+            }
+            subscriber.last = Some(fraction);
+            subscriber.tx.send(fraction).is_ok()
+        });
+        if done {
+            subscribers.clear();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::future::poll_fn;
+
+    async fn next(stream: &mut ProgressStream) -> Option<f32> {
+        poll_fn(|cx| Pin::new(&mut *stream).poll_next(cx)).await
+    }
+
+    #[tokio::test]
+    async fn stream_yields_increasing_fractions() {
+        let rendezvous = Rendezvous::new();
+        let mut guards: Vec<_> = (0..3).map(|_| rendezvous.fork_guard()).collect();
+        let mut progress = rendezvous.progress_stream();
+
+        let waiter = tokio::task::spawn_blocking(move || rendezvous.rendezvous());
+
+        assert_eq!(next(&mut progress).await, Some(0.0));
+        drop(guards.pop());
+        assert_eq!(next(&mut progress).await, Some(1.0 / 3.0));
+        drop(guards.pop());
+        assert_eq!(next(&mut progress).await, Some(2.0 / 3.0));
+        drop(guards.pop());
+        assert_eq!(next(&mut progress).await, Some(1.0));
+        assert_eq!(next(&mut progress).await, None);
+
+        waiter.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn stream_of_completed_rendezvous_ends() {
+        let mut rendezvous = Rendezvous::new();
+        rendezvous.rendezvous_ref();
+
+        let mut progress = rendezvous.progress_stream();
+        assert_eq!(next(&mut progress).await, Some(1.0));
+        assert_eq!(next(&mut progress).await, None);
+    }
+}
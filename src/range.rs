@@ -0,0 +1,73 @@
+//! Forking guards for range-partitioned work.
+
+use crate::{Rendezvous, RendezvousGuard};
+
+impl<C> Rendezvous<C> {
+    /// Forks `count` guards, each paired with its partition index in `0..count`.
+    ///
+    /// This suits data-parallel work split into `count` partitions: each partition is processed
+    /// with its own guard, and the index identifies the slice of the work range it covers.
+    /// For a `count` of zero, no guards are forked and the returned list is empty.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::thread;
+    /// use rendezvous::Rendezvous;
+    ///
+    /// let data: Vec<u32> = (0..1000).collect();
+    /// let chunk = (data.len() + 3) / 4;
+    ///
+    /// let rendezvous = Rendezvous::new();
+    /// for (index, guard) in rendezvous.fork_range_guards(4) {
+    ///     let slice = data[index * chunk..((index + 1) * chunk).min(data.len())].to_vec();
+    ///     thread::spawn(move || {
+    ///         let _sum: u32 = slice.iter().sum();
+    ///         drop(guard);
+    ///     });
+    /// }
+    /// rendezvous.rendezvous();
+    /// ```
+    pub fn fork_range_guards(&self, count: usize) -> Vec<(usize, RendezvousGuard)> {
+        (0..count).map(|index| (index, self.fork_guard())).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RendezvousTimeoutError;
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn range_guards_are_indexed_and_gate_the_rendezvous() {
+        let mut rendezvous = Rendezvous::new();
+        let guards = rendezvous.fork_range_guards(4);
+        let indices: Vec<_> = guards.iter().map(|(index, _)| *index).collect();
+        assert_eq!(indices, vec![0, 1, 2, 3]);
+        assert_eq!(rendezvous.remaining_guards(), 4);
+
+        let mut guards = guards.into_iter();
+        let (_, last) = guards.next_back().unwrap();
+        drop(guards);
+        assert_eq!(
+            rendezvous.rendezvous_timeout(Duration::from_millis(10)),
+            Err(RendezvousTimeoutError::Timeout)
+        );
+
+        thread::spawn(move || drop(last));
+        assert_eq!(
+            rendezvous.rendezvous_timeout(Duration::from_secs(5)),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn zero_range_guards_complete_immediately() {
+        let rendezvous = Rendezvous::new();
+        assert!(rendezvous.fork_range_guards(0).is_empty());
+        assert_eq!(rendezvous.remaining_guards(), 0);
+        rendezvous.rendezvous();
+    }
+}
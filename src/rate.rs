@@ -0,0 +1,181 @@
+//! Monitoring the rate of guard completions and detecting stalls.
+
+#[cfg(any(feature = "log", feature = "tracing"))]
+use crate::Channel;
+use crate::{lock, Rendezvous, Shared};
+#[cfg(any(feature = "log", feature = "tracing"))]
+use std::sync::mpsc::RecvTimeoutError;
+use std::time::{Duration, Instant};
+
+/// The number of buckets of the sliding window.
+const BUCKETS: usize = 10;
+
+/// The time span covered by a single bucket.
+const BUCKET_WIDTH: Duration = Duration::from_millis(100);
+
+/// Counts completions over a sliding window of one second, using fixed-size buckets so that
+/// the memory footprint does not grow with the completion rate.
+#[derive(Debug, Default)]
+pub(crate) struct RateWindow {
+    /// The reference point of the bucket epochs; set by the first completion.
+    origin: Option<Instant>,
+    /// The epoch and completion count of each bucket.
+    buckets: [(u64, u32); BUCKETS],
+    /// The point in time of the most recent completion.
+    last: Option<Instant>,
+}
+
+impl RateWindow {
+    /// Returns the epoch of the bucket containing `now`.
+    fn epoch(origin: Instant, now: Instant) -> u64 {
+        (now.saturating_duration_since(origin).as_nanos() / BUCKET_WIDTH.as_nanos()) as u64
+    }
+
+    /// Records a completion.
+    fn record(&mut self) {
+        let now = Instant::now();
+        let origin = *self.origin.get_or_insert(now);
+        let epoch = Self::epoch(origin, now);
+        let bucket = &mut self.buckets[epoch as usize % BUCKETS];
+        if bucket.0 != epoch {
+            *bucket = (epoch, 0);
+        }
+        bucket.1 += 1;
+        self.last = Some(now);
+    }
+
+    /// Returns the completions per second over the window ending now.
+    fn rate(&self) -> f32 {
+        let Some(origin) = self.origin else {
+            return 0.0;
+        };
+        let current = Self::epoch(origin, Instant::now());
+        let completions: u32 = self
+            .buckets
+            .iter()
+            .filter(|(epoch, _)| current - epoch < BUCKETS as u64)
+            .map(|(_, count)| count)
+            .sum();
+        completions as f32 / (BUCKET_WIDTH * BUCKETS as u32).as_secs_f32()
+    }
+}
+
+impl Shared {
+    /// Records a completion in the sliding window.
+    pub(crate) fn record_completion_rate(&self) {
+        lock(&self.rate).record();
+    }
+
+    /// Returns the point in time of the most recent completion, if any.
+    #[cfg(any(feature = "log", feature = "tracing"))]
+    pub(crate) fn last_completion(&self) -> Option<Instant> {
+        lock(&self.rate).last
+    }
+}
+
+#[cfg(any(feature = "log", feature = "tracing"))]
+impl Channel {
+    /// Blocks until all senders are dropped, logging a warning whenever no guard completed
+    /// within `stall` while guards remain.
+    pub(crate) fn wait_watching(&self, stall: Duration) {
+        let started = Instant::now();
+        let mut warned_at = None;
+        loop {
+            match self.recv_timeout(stall) {
+                Ok(_) => continue,
+                Err(RecvTimeoutError::Disconnected) => return,
+                Err(RecvTimeoutError::Timeout) => {}
+            }
+            let progress = self
+                .shared
+                .last_completion()
+                .map_or(started, |last| last.max(started));
+            if progress.elapsed() < stall || warned_at == Some(progress) {
+                continue;
+            }
+            warned_at = Some(progress);
+            warn!(
+                "Rendezvous stalled: no guard completed within {stall:?}, {} guards remaining [round {}]",
+                self.shared.live_guards(),
+                self.shared.round
+            );
+        }
+    }
+}
+
+impl<C> Rendezvous<C> {
+    /// Returns the number of guards completed per second over a sliding window of the last
+    /// second.
+    ///
+    /// A positive rate indicates steady progress; a rate of zero while guards remain indicates
+    /// a stall. The window consists of ten buckets of 100 ms each, so the rate is updated in
+    /// steps. A [minimal](crate::RendezvousBuilder::minimal) rendezvous does not track
+    /// completions and always reports `0.0`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use rendezvous::RendezvousBuilder;
+    ///
+    /// let rendezvous = RendezvousBuilder::new().full_tracking().build();
+    /// for _ in 0..5 {
+    ///     rendezvous.fork_guard().completed();
+    /// }
+    /// assert_eq!(rendezvous.completion_rate(), 5.0);
+    /// rendezvous.rendezvous();
+    /// ```
+    pub fn completion_rate(&self) -> f32 {
+        self.channel
+            .get()
+            .map_or(0.0, |channel| lock(&channel.shared.rate).rate())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RendezvousBuilder;
+    use std::thread;
+
+    #[test]
+    fn steady_completions_have_a_positive_rate() {
+        let rendezvous = RendezvousBuilder::new().full_tracking().build();
+        assert_eq!(rendezvous.completion_rate(), 0.0);
+        for _ in 0..10 {
+            rendezvous.fork_guard().completed();
+            thread::sleep(Duration::from_millis(20));
+        }
+        let rate = rendezvous.completion_rate();
+        assert!(rate > 0.0 && rate <= 10.0, "{rate}");
+        rendezvous.rendezvous();
+    }
+
+    #[test]
+    fn old_completions_leave_the_window() {
+        let mut window = RateWindow::default();
+        window.record();
+        window.origin = window.origin.map(|origin| origin - Duration::from_secs(2));
+        assert_eq!(window.rate(), 0.0);
+    }
+
+    #[test]
+    #[cfg(feature = "log")]
+    fn stall_warning_fires() {
+        crate::test_log::init();
+
+        let rendezvous = crate::RendezvousBuilder::new()
+            .stall_after(Duration::from_millis(20))
+            .build();
+        rendezvous.fork_guard().completed();
+        let stuck = rendezvous.fork_guard_labeled("stuck");
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(150));
+            drop(stuck);
+        });
+        rendezvous.rendezvous();
+
+        let messages = crate::test_log::messages_containing("within 20ms, 1 guards remaining");
+        assert!(!messages.is_empty());
+        assert!(messages.iter().all(|(level, _)| *level == log::Level::Warn));
+    }
+}
@@ -0,0 +1,300 @@
+//! Completing guards through plain tokens instead of owned guards.
+
+use crate::{Rendezvous, RendezvousGuard, Shared};
+use std::fmt::{Debug, Formatter};
+use std::marker::PhantomData;
+use std::ptr;
+use std::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// Set in [`RawGuards::state`] while the guard standing in for the raw tokens is swapped.
+const BUSY: usize = 1;
+
+/// The increment of [`RawGuards::state`] for one raw token.
+const TOKEN: usize = 2;
+
+/// The guard standing in for all outstanding raw tokens of a rendezvous.
+///
+/// Forking and completing a token only updates an atomic counter, unless it is the first or
+/// last outstanding token, which swaps the guard while [`BUSY`] is set. Meanwhile, other
+/// tokens wait for the swap to finish.
+#[derive(Default)]
+pub(crate) struct RawGuards {
+    /// The number of outstanding raw tokens times [`TOKEN`], possibly with [`BUSY`] set.
+    state: AtomicUsize,
+    /// The guard keeping the rendezvous open while any raw token is outstanding, or null.
+    guard: AtomicPtr<RendezvousGuard>,
+}
+
+impl RawGuards {
+    /// Counts a forked token, calling `fork` to create the guard if it is the only one.
+    ///
+    /// If `fork` panics, no token is counted.
+    fn fork(&self, fork: impl FnOnce() -> RendezvousGuard) {
+        let mut state = self.state.load(Ordering::Acquire);
+        loop {
+            if state & BUSY != 0 {
+                std::hint::spin_loop();
+                state = self.state.load(Ordering::Acquire);
+                continue;
+            }
+            let next = if state == 0 {
+                TOKEN | BUSY
+            } else {
+                state + TOKEN
+            };
+            match self
+                .state
+                .compare_exchange_weak(state, next, Ordering::AcqRel, Ordering::Acquire)
+            {
+                Ok(_) => break,
+                Err(actual) => state = actual,
+            }
+        }
+        if state != 0 {
+            return;
+        }
+
+        /// Withdraws the first token again if forking its guard panics.
+        struct Withdraw<'a>(&'a AtomicUsize);
+
+        impl Drop for Withdraw<'_> {
+            fn drop(&mut self) {
+                self.0.store(0, Ordering::Release);
+            }
+        }
+
+        let withdraw = Withdraw(&self.state);
+        let guard = Box::into_raw(Box::new(fork()));
+        std::mem::forget(withdraw);
+        self.guard.store(guard, Ordering::Release);
+        self.state.fetch_and(!BUSY, Ordering::Release);
+    }
+
+    /// Counts a completed token, dropping the guard if it was the last one.
+    ///
+    /// Panics if no token is outstanding.
+    fn complete(&self) {
+        let mut state = self.state.load(Ordering::Acquire);
+        loop {
+            assert!(state >= TOKEN, "completed more raw tokens than were forked");
+            if state & BUSY != 0 {
+                std::hint::spin_loop();
+                state = self.state.load(Ordering::Acquire);
+                continue;
+            }
+            let next = if state == TOKEN { BUSY } else { state - TOKEN };
+            match self
+                .state
+                .compare_exchange_weak(state, next, Ordering::AcqRel, Ordering::Acquire)
+            {
+                Ok(_) => break,
+                Err(actual) => state = actual,
+            }
+        }
+        if state != TOKEN {
+            return;
+        }
+        let guard = self.guard.swap(ptr::null_mut(), Ordering::AcqRel);
+        self.state.fetch_and(!BUSY, Ordering::Release);
+        // Dropped last, since dropping the guard may free the state this belongs to.
+        // SAFETY: The guard was leaked by `fork`, and only the last token takes it.
+        drop(unsafe { Box::from_raw(guard) });
+    }
+}
+
+impl Drop for RawGuards {
+    fn drop(&mut self) {
+        let guard = *self.guard.get_mut();
+        if !guard.is_null() {
+            // SAFETY: The guard was leaked by `fork` and not taken by any token.
+            drop(unsafe { Box::from_raw(guard) });
+        }
+    }
+}
+
+/// A token standing in for a guard, obtained using [`Rendezvous::fork_raw`].
+///
+/// The token is [`Copy`] and has no [`Drop`] implementation: it does not complete on its
+/// own, but must be passed to [`Rendezvous::complete_raw`] exactly once. Its type parameter
+/// is the context type of the rendezvous it was forked off.
+pub struct RawGuardToken<C = ()> {
+    /// The state of the rendezvous the token was forked off, kept alive by the guard standing
+    /// in for all outstanding tokens.
+    shared: *const Shared,
+    /// The context type of the rendezvous the token was forked off.
+    context: PhantomData<fn() -> C>,
+}
+
+impl<C> Clone for RawGuardToken<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C> Copy for RawGuardToken<C> {}
+
+impl<C> Debug for RawGuardToken<C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RawGuardToken")
+            .field("shared", &self.shared)
+            .finish()
+    }
+}
+
+// SAFETY: The token only points at `Shared`, which is `Send` and `Sync`.
+unsafe impl<C> Send for RawGuardToken<C> {}
+
+// SAFETY: See above; the token itself is immutable.
+unsafe impl<C> Sync for RawGuardToken<C> {}
+
+impl<C> Rendezvous<C> {
+    /// Forks a raw token off the rendezvous, to be completed using
+    /// [`Rendezvous::complete_raw`].
+    ///
+    /// Raw tokens bypass the ownership-based guard machinery for hot loops interfacing with
+    /// unsafe code: while any raw token is outstanding, a single guard keeps the rendezvous
+    /// open, so forking and completing a token only updates an atomic counter, except for the
+    /// first and last outstanding token. The tokens are not tracked individually: together,
+    /// they count as one guard towards [`Rendezvous::remaining_guards`], and they carry no id
+    /// or label.
+    ///
+    /// Forking a token is safe, since a token that is never completed only keeps the
+    /// rendezvous open, like a leaked guard.
+    ///
+    /// ## Panics
+    ///
+    /// Forking the first outstanding token after the rendezvous was waited on behaves like
+    /// [`Rendezvous::fork_guard`]; see [`RendezvousBuilder::on_fork_after_wait`].
+    ///
+    /// [`RendezvousBuilder::on_fork_after_wait`]: crate::RendezvousBuilder::on_fork_after_wait
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use rendezvous::Rendezvous;
+    ///
+    /// let rendezvous = Rendezvous::new();
+    /// let tokens: Vec<_> = (0..100).map(|_| rendezvous.fork_raw()).collect();
+    /// for token in tokens {
+    ///     // SAFETY: Every token is completed exactly once, before the rendezvous.
+    ///     unsafe { Rendezvous::complete_raw(token) };
+    /// }
+    /// rendezvous.rendezvous();
+    /// ```
+    pub fn fork_raw(&self) -> RawGuardToken<C> {
+        let shared = self.shared();
+        shared.raw.fork(|| self.fork_guard());
+        RawGuardToken {
+            shared: Arc::as_ptr(shared),
+            context: PhantomData,
+        }
+    }
+
+    /// Completes a raw token forked using [`Rendezvous::fork_raw`].
+    ///
+    /// ## Safety
+    ///
+    /// The token must be outstanding: forked using [`Rendezvous::fork_raw`] and not completed
+    /// before. Since tokens are [`Copy`], this is not enforced by the compiler. Completing a
+    /// token that is not outstanding, e.g. a second time or after the rendezvous completed, is
+    /// undefined behavior: it may let the rendezvous proceed while other tokens are still
+    /// outstanding, or access the state of the rendezvous after it was freed.
+    ///
+    /// A token that is never completed is safe, but keeps the rendezvous open forever.
+    pub unsafe fn complete_raw(token: RawGuardToken<C>) {
+        // SAFETY: The caller guarantees that the token is outstanding, so the guard standing
+        // in for it holds a reference to the shared state.
+        let shared = unsafe { &*token.shared };
+        shared.raw.complete();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RendezvousTimeoutError;
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn balanced_raw_tokens_complete_the_rendezvous() {
+        let mut rendezvous = Rendezvous::new();
+        let guard = rendezvous.fork_guard();
+        let tokens: Vec<_> = (0..4).map(|_| rendezvous.fork_raw()).collect();
+        assert_eq!(rendezvous.remaining_guards(), 2);
+
+        drop(guard);
+        let (first, rest) = tokens.split_first().unwrap();
+        // SAFETY: Each token is completed exactly once, before the rendezvous completes.
+        unsafe { Rendezvous::complete_raw(*first) };
+        assert_eq!(
+            rendezvous.rendezvous_timeout(Duration::from_millis(10)),
+            Err(RendezvousTimeoutError::Timeout)
+        );
+
+        let rest = rest.to_vec();
+        thread::spawn(move || {
+            for token in rest {
+                // SAFETY: See above.
+                unsafe { Rendezvous::complete_raw(token) };
+            }
+        });
+        assert_eq!(
+            rendezvous.rendezvous_timeout(Duration::from_secs(5)),
+            Ok(())
+        );
+        assert_eq!(rendezvous.remaining_guards(), 0);
+    }
+
+    #[test]
+    fn raw_tokens_can_be_forked_again_after_all_completed() {
+        let rendezvous = Rendezvous::new();
+        for _ in 0..3 {
+            let token = rendezvous.fork_raw();
+            assert_eq!(rendezvous.remaining_guards(), 1);
+            // SAFETY: The token is completed exactly once, before the rendezvous.
+            unsafe { Rendezvous::complete_raw(token) };
+            assert_eq!(rendezvous.remaining_guards(), 0);
+        }
+        rendezvous.rendezvous();
+    }
+
+    #[test]
+    fn tokens_completed_concurrently_with_forks_keep_the_rendezvous_open() {
+        use std::sync::mpsc;
+
+        let rendezvous = Rendezvous::new();
+        let (senders, workers): (Vec<_>, Vec<_>) = (0..4)
+            .map(|_| {
+                let (tx, rx) = mpsc::channel::<RawGuardToken>();
+                let worker = thread::spawn(move || {
+                    for token in rx {
+                        // SAFETY: Every token is sent to a single worker, which completes it
+                        // once, before the rendezvous.
+                        unsafe { Rendezvous::complete_raw(token) };
+                    }
+                });
+                (tx, worker)
+            })
+            .unzip();
+        for tx in senders.iter().cycle().take(10_000) {
+            tx.send(rendezvous.fork_raw()).unwrap();
+        }
+        drop(senders);
+        for worker in workers {
+            worker.join().unwrap();
+        }
+        assert_eq!(rendezvous.remaining_guards(), 0);
+        rendezvous.rendezvous();
+    }
+
+    #[test]
+    fn raw_tokens_carry_the_context_type() {
+        let rendezvous = Rendezvous::with_context("request");
+        let token: RawGuardToken<&str> = rendezvous.fork_raw();
+        // SAFETY: The token is completed exactly once, before the rendezvous.
+        unsafe { Rendezvous::complete_raw(token) };
+        rendezvous.rendezvous();
+    }
+}
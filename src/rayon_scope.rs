@@ -0,0 +1,124 @@
+//! Gating tasks running on the `rayon` thread pool.
+
+use crate::{Rendezvous, RendezvousGuard};
+
+impl<C> Rendezvous<C> {
+    /// Forks a guard and runs `f` with it on the global `rayon` thread pool.
+    ///
+    /// The task runs detached, like one passed to [`rayon::spawn`]; the rendezvous waits for
+    /// it as long as `f` holds on to the guard.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::sync::Arc;
+    /// use rendezvous::Rendezvous;
+    ///
+    /// let rendezvous = Rendezvous::new();
+    /// let counter = Arc::new(AtomicUsize::new(0));
+    /// for _ in 0..4 {
+    ///     let counter = counter.clone();
+    ///     rendezvous.spawn_rayon(move |_guard| {
+    ///         counter.fetch_add(1, Ordering::SeqCst);
+    ///     });
+    /// }
+    ///
+    /// rendezvous.rendezvous();
+    /// assert_eq!(counter.load(Ordering::SeqCst), 4);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    pub fn spawn_rayon<F>(&self, f: F)
+    where
+        F: FnOnce(RendezvousGuard) + Send + 'static,
+    {
+        let guard = self.fork_guard();
+        rayon::spawn(move || f(guard));
+    }
+
+    /// Creates a `rayon` scope and passes it to `f` along with this rendezvous, so that guards
+    /// can be forked for the scoped tasks.
+    ///
+    /// The scope is created using [`rayon::in_place_scope`], so `f` runs on the current
+    /// thread and the rendezvous does not need to be shared with the thread pool. Like any
+    /// `rayon` scope, this returns once all tasks spawned into the scope completed; guards
+    /// moved into those tasks are dropped by then unless the tasks hand them off elsewhere.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::sync::Mutex;
+    /// use rendezvous::Rendezvous;
+    ///
+    /// let rendezvous = Rendezvous::new();
+    /// let results = Mutex::new(Vec::new());
+    /// rendezvous.install_rayon_scope(|scope, rendezvous| {
+    ///     for i in 0..3 {
+    ///         let guard = rendezvous.fork_guard();
+    ///         let results = &results;
+    ///         scope.spawn(move |_| {
+    ///             results.lock().unwrap().push(i);
+    ///             drop(guard);
+    ///         });
+    ///     }
+    /// });
+    ///
+    /// rendezvous.rendezvous();
+    /// assert_eq!(results.into_inner().unwrap().len(), 3);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    pub fn install_rayon_scope<'scope, R, F>(&self, f: F) -> R
+    where
+        F: FnOnce(&rayon::Scope<'scope>, &Self) -> R,
+    {
+        rayon::in_place_scope(|scope| f(scope, self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn rendezvous_waits_for_rayon_tasks() {
+        let rendezvous = Rendezvous::new();
+        let finished = Arc::new(AtomicUsize::new(0));
+        for delay in [30, 10, 20] {
+            let finished = finished.clone();
+            rendezvous.spawn_rayon(move |guard| {
+                thread::sleep(Duration::from_millis(delay));
+                finished.fetch_add(1, Ordering::SeqCst);
+                drop(guard);
+            });
+        }
+
+        rendezvous.rendezvous();
+        assert_eq!(finished.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn scoped_tasks_can_hand_off_guards() {
+        let mut rendezvous = Rendezvous::new();
+        let (tx, rx) = std::sync::mpsc::channel();
+        rendezvous.install_rayon_scope(|scope, rendezvous| {
+            for _ in 0..2 {
+                let guard = rendezvous.fork_guard();
+                let tx = tx.clone();
+                scope.spawn(move |_| tx.send(guard).unwrap());
+            }
+        });
+
+        // The scope completed, but the handed-off guards still keep the rendezvous open.
+        let guards: Vec<_> = rx.try_iter().collect();
+        assert_eq!(rendezvous.remaining_guards(), 2);
+        thread::spawn(move || drop(guards));
+        assert_eq!(
+            rendezvous.rendezvous_timeout(Duration::from_secs(5)),
+            Ok(())
+        );
+    }
+}
@@ -0,0 +1,113 @@
+//! Receipts confirming that a handed-off guard was eventually released.
+
+use crate::{lock, GuardId, RendezvousGuard, Shared};
+use std::sync::atomic::Ordering;
+use std::sync::{Arc, Weak};
+
+/// A non-owning receipt for a [`RendezvousGuard`], created using
+/// [`RendezvousGuard::into_receipt`].
+///
+/// The receipt does not keep the rendezvous open; it only observes whether the guard
+/// it was issued for has been released.
+#[derive(Debug, Clone)]
+pub struct GuardReceipt {
+    /// The guard the receipt was issued for.
+    id: GuardId,
+    /// The state shared with the guard's rendezvous.
+    shared: Weak<Shared>,
+}
+
+impl RendezvousGuard {
+    /// Issues a receipt for this guard, e.g. before handing the guard to another subsystem.
+    ///
+    /// The [`GuardReceipt`] allows the original owner to verify that the transferred guard was
+    /// eventually released rather than leaked by the receiver.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use rendezvous::Rendezvous;
+    ///
+    /// let rendezvous = Rendezvous::new();
+    /// let (guard, receipt) = rendezvous.fork_guard().into_receipt();
+    /// assert!(!receipt.is_completed());
+    ///
+    /// drop(guard);
+    /// assert!(receipt.is_completed());
+    /// rendezvous.rendezvous();
+    /// ```
+    pub fn into_receipt(self) -> (RendezvousGuard, GuardReceipt) {
+        let mut receipts = lock(&self.shared.receipts);
+        self.shared.receipted.store(true, Ordering::Release);
+        receipts.insert(self.id);
+        drop(receipts);
+        let receipt = GuardReceipt {
+            id: self.id,
+            shared: Arc::downgrade(&self.shared),
+        };
+        (self, receipt)
+    }
+}
+
+impl GuardReceipt {
+    /// Returns the identifier of the guard this receipt was issued for.
+    pub fn guard_id(&self) -> GuardId {
+        self.id
+    }
+
+    /// Returns whether the guard this receipt was issued for was released.
+    pub fn is_completed(&self) -> bool {
+        self.shared
+            .upgrade()
+            .map_or(true, |shared| !lock(&shared.receipts).contains(&self.id))
+    }
+}
+
+impl Shared {
+    /// Marks the receipt issued for the guard `id`, if any, as completed.
+    ///
+    /// This takes no lock unless a receipt was ever issued.
+    pub(crate) fn release_receipt(&self, id: GuardId) {
+        if self.receipted.load(Ordering::Acquire) {
+            lock(&self.receipts).remove(&id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Rendezvous, RendezvousBuilder};
+    use std::thread;
+
+    #[test]
+    fn receipt_reports_completion_of_transferred_guard() {
+        let rendezvous = Rendezvous::new();
+        let (guard, receipt) = rendezvous.fork_guard().into_receipt();
+        let other = rendezvous.fork_guard();
+
+        let worker = thread::spawn(move || guard);
+        let guard = worker.join().unwrap();
+        assert!(!receipt.clone().is_completed());
+
+        thread::spawn(move || drop(guard)).join().unwrap();
+        assert!(receipt.is_completed());
+
+        drop(other);
+        rendezvous.rendezvous();
+        assert!(receipt.is_completed());
+    }
+
+    #[test]
+    fn receipt_reports_completion_in_minimal_mode() {
+        let rendezvous = RendezvousBuilder::new().minimal().build();
+        let (guard, receipt) = rendezvous.fork_guard().into_receipt();
+        let other = rendezvous.fork_guard();
+        assert!(!receipt.is_completed());
+
+        drop(guard);
+        assert!(receipt.is_completed());
+
+        drop(other);
+        rendezvous.rendezvous();
+    }
+}
@@ -0,0 +1,468 @@
+//! Bookkeeping of outstanding guards for diagnostics.
+
+#[cfg(feature = "deadlock-detection")]
+use crate::deadlock::ForkSite;
+use crate::{audit, lock, GuardId, Rendezvous, RendezvousError, RendezvousGuard, Shared};
+#[cfg(feature = "deadlock-detection")]
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+#[cfg(feature = "deadlock-detection")]
+use std::sync::Arc;
+use std::sync::TryLockError;
+use std::time::{Duration, Instant};
+
+/// The record of an outstanding guard.
+pub(crate) struct GuardRecord {
+    /// The guard this guard was forked off, if any.
+    parent: Option<GuardId>,
+    /// The label the guard was forked with, if any.
+    label: Option<String>,
+    /// The attributes set using [`RendezvousGuard::set_attr`], in the order they were first set.
+    attributes: Vec<(String, String)>,
+    /// The point in time the guard was forked at.
+    forked_at: Instant,
+    /// The name of the thread the guard was forked on, if auditing is enabled.
+    thread: Option<String>,
+    /// Where the guard was forked, if deadlock detection is enabled.
+    #[cfg(feature = "deadlock-detection")]
+    fork_site: Option<Arc<ForkSite>>,
+    /// The phase entered using [`RendezvousGuard::enter_phase`], if any.
+    pub(crate) phase: Option<String>,
+}
+
+/// A snapshot of an outstanding guard.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct GuardSnapshot {
+    /// The identifier of the guard.
+    pub id: GuardId,
+    /// The guard this guard was forked off using [`RendezvousGuard::fork`], or `None` if it
+    /// was forked off the [`Rendezvous`] itself.
+    ///
+    /// [`RendezvousGuard::fork`]: crate::RendezvousGuard::fork
+    pub parent: Option<GuardId>,
+    /// The label the guard was forked with, if any.
+    pub label: Option<String>,
+    /// The attributes set using [`RendezvousGuard::set_attr`], in the order they were first
+    /// set.
+    ///
+    /// [`RendezvousGuard::set_attr`]: crate::RendezvousGuard::set_attr
+    pub attributes: Vec<(String, String)>,
+    /// The time elapsed since the guard was forked.
+    pub age: Duration,
+}
+
+impl GuardSnapshot {
+    /// Returns the value of the attribute `key`, if set.
+    pub fn attr(&self, key: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+impl Display for GuardSnapshot {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.id)?;
+        if let Some(label) = &self.label {
+            write!(f, " '{label}'")?;
+        }
+        if !self.attributes.is_empty() {
+            write!(f, " [")?;
+            for (index, (key, value)) in self.attributes.iter().enumerate() {
+                if index > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{key}={value}")?;
+            }
+            write!(f, "]")?;
+        }
+        write!(f, " ({:?} old)", self.age)
+    }
+}
+
+impl Shared {
+    /// Registers a newly forked guard.
+    pub(crate) fn register(&self, id: GuardId, parent: Option<GuardId>, label: Option<String>) {
+        let record = GuardRecord {
+            parent,
+            label,
+            attributes: Vec::new(),
+            forked_at: Instant::now(),
+            thread: self.is_audited().then(audit::current_thread_name),
+            #[cfg(feature = "deadlock-detection")]
+            fork_site: self
+                .detects_deadlocks()
+                .then(|| Arc::new(ForkSite::capture())),
+            phase: None,
+        };
+        lock(&self.registry).insert(id, record);
+    }
+
+    /// Returns the point in time the outstanding guard `id` was forked at.
+    #[cfg(any(feature = "log", feature = "tracing"))]
+    pub(crate) fn forked_at(&self, id: GuardId) -> Option<Instant> {
+        lock(&self.registry).get(&id).map(|record| record.forked_at)
+    }
+
+    /// Removes a released guard.
+    pub(crate) fn unregister(&self, id: GuardId) {
+        let record = lock(&self.registry).remove(&id);
+        if let Some(record) = record {
+            self.record_lifetime(record.forked_at);
+            self.record_audit(id, record.label, record.thread, record.forked_at);
+        }
+    }
+
+    /// Takes snapshots of all outstanding guards, ordered by id.
+    pub(crate) fn snapshot(&self) -> Vec<GuardSnapshot> {
+        let now = Instant::now();
+        let mut snapshots: Vec<_> = lock(&self.registry)
+            .iter()
+            .map(|(id, record)| GuardSnapshot {
+                id: *id,
+                parent: record.parent,
+                label: record.label.clone(),
+                attributes: record.attributes.clone(),
+                age: now.saturating_duration_since(record.forked_at),
+            })
+            .collect();
+        snapshots.sort_by_key(|snapshot| snapshot.id);
+        snapshots
+    }
+
+    /// Returns where the outstanding guards were forked, for guards whose fork site was
+    /// recorded.
+    #[cfg(feature = "deadlock-detection")]
+    pub(crate) fn fork_sites(&self) -> HashMap<GuardId, Arc<ForkSite>> {
+        lock(&self.registry)
+            .iter()
+            .filter_map(|(id, record)| Some((*id, Arc::clone(record.fork_site.as_ref()?))))
+            .collect()
+    }
+
+    /// Returns the label of the outstanding guard `id`, or `None` if it has none or the
+    /// registry is currently locked.
+    pub(crate) fn try_label(&self, id: GuardId) -> Option<String> {
+        let registry = match self.registry.try_lock() {
+            Ok(registry) => registry,
+            Err(TryLockError::Poisoned(poison)) => poison.into_inner(),
+            Err(TryLockError::WouldBlock) => return None,
+        };
+        registry.get(&id).and_then(|record| record.label.clone())
+    }
+
+    /// Returns the age of the oldest outstanding guard, if any.
+    pub(crate) fn oldest_age(&self) -> Option<Duration> {
+        let now = Instant::now();
+        lock(&self.registry)
+            .values()
+            .map(|record| now.saturating_duration_since(record.forked_at))
+            .max()
+    }
+}
+
+impl RendezvousGuard {
+    /// Sets the attribute `key` of this guard to `value`, replacing any previous value.
+    ///
+    /// Attributes are reported in [`GuardSnapshot`]s of outstanding guards and in the
+    /// diagnostics rendered from them, which allows filtering and grouping outstanding guards
+    /// by application-meaningful dimensions, such as a shard or region. Attributes are
+    /// discarded in [minimal](crate::RendezvousBuilder::minimal) mode.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use rendezvous::{RendezvousBuilder, RendezvousError};
+    ///
+    /// let mut rendezvous = RendezvousBuilder::new().full_tracking().build();
+    /// let mut guard = rendezvous.fork_guard();
+    /// guard.set_attr("region", "eu");
+    ///
+    /// let Err(RendezvousError::Timeout { outstanding }) =
+    ///     rendezvous.rendezvous_timeout_snapshot(Duration::from_millis(10))
+    /// else {
+    ///     panic!("expected a timeout");
+    /// };
+    /// assert_eq!(outstanding[0].attr("region"), Some("eu"));
+    /// drop(guard);
+    /// ```
+    pub fn set_attr(&mut self, key: &str, value: impl Into<String>) {
+        let mut registry = lock(&self.shared.registry);
+        let Some(record) = registry.get_mut(&self.id) else {
+            return;
+        };
+        let value = value.into();
+        match record.attributes.iter_mut().find(|(k, _)| k == key) {
+            Some((_, existing)) => *existing = value,
+            None => record.attributes.push((key.to_owned(), value)),
+        }
+    }
+}
+
+/// Renders `outstanding` as a tree following the fork hierarchy, or returns `None` if empty.
+pub(crate) fn render_tree(outstanding: &[GuardSnapshot]) -> Option<String> {
+    if outstanding.is_empty() {
+        return None;
+    }
+    let is_outstanding = |id: GuardId| outstanding.iter().any(|guard| guard.id == id);
+    let mut tree = String::new();
+    let roots = outstanding
+        .iter()
+        .filter(|guard| !guard.parent.is_some_and(is_outstanding));
+    for root in roots {
+        render_subtree(outstanding, root, "", "", &mut tree);
+    }
+    Some(tree)
+}
+
+/// Renders `guard` and its outstanding descendants into `tree`.
+fn render_subtree(
+    outstanding: &[GuardSnapshot],
+    guard: &GuardSnapshot,
+    prefix: &str,
+    child_prefix: &str,
+    tree: &mut String,
+) {
+    tree.push_str(&format!("{prefix}{guard}\n"));
+    let children: Vec<_> = outstanding
+        .iter()
+        .filter(|child| child.parent == Some(guard.id))
+        .collect();
+    for (index, child) in children.iter().enumerate() {
+        let (branch, indent) = if index + 1 == children.len() {
+            ("└── ", "    ")
+        } else {
+            ("├── ", "│   ")
+        };
+        render_subtree(
+            outstanding,
+            child,
+            &format!("{child_prefix}{branch}"),
+            &format!("{child_prefix}{indent}"),
+            tree,
+        );
+    }
+}
+
+impl<C> Rendezvous<C> {
+    /// Asserts that all guards are already completed, without blocking.
+    ///
+    /// If guards are still alive, this panics with a message listing them, including their
+    /// labels and ages. The check is only performed with debug assertions enabled, and compiles
+    /// to nothing otherwise, similar to [`debug_assert!`].
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use rendezvous::Rendezvous;
+    ///
+    /// let rendezvous = Rendezvous::new();
+    /// rendezvous.fork_guard().completed();
+    /// rendezvous.assert_complete();
+    /// rendezvous.rendezvous();
+    /// ```
+    #[track_caller]
+    pub fn assert_complete(&self) {
+        #[cfg(debug_assertions)]
+        {
+            let remaining = self.remaining_guards();
+            if remaining == 0 {
+                return;
+            }
+            let outstanding = self
+                .channel
+                .get()
+                .map(|channel| channel.shared.snapshot())
+                .unwrap_or_default();
+            let mut message = format!("Rendezvous has {remaining} outstanding guards");
+            for (index, guard) in outstanding.iter().enumerate() {
+                let separator = if index == 0 { ": " } else { ", " };
+                message.push_str(&format!("{separator}{guard}"));
+            }
+            panic!("{message}");
+        }
+    }
+
+    /// Executes the rendezvous process with a timeout, capturing snapshots of all outstanding
+    /// guards if the timeout occurs.
+    ///
+    /// This behaves like [`Rendezvous::rendezvous_timeout`], but the returned error carries
+    /// everything needed to diagnose the timeout. The snapshot is taken at the moment of the
+    /// timeout, so it cannot race with guards completing afterwards. For a
+    /// [minimal](crate::RendezvousBuilder::minimal) rendezvous, no guards are tracked and the
+    /// list of outstanding guards is always empty.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use rendezvous::{RendezvousBuilder, RendezvousError};
+    ///
+    /// let mut rendezvous = RendezvousBuilder::new().full_tracking().build();
+    /// let guard = rendezvous.fork_guard_labeled("ingest");
+    ///
+    /// let result = rendezvous.rendezvous_timeout_snapshot(Duration::from_millis(10));
+    /// let Err(RendezvousError::Timeout { outstanding }) = result else {
+    ///     panic!("expected a timeout");
+    /// };
+    /// assert_eq!(outstanding[0].label.as_deref(), Some("ingest"));
+    /// drop(guard);
+    /// ```
+    pub fn rendezvous_timeout_snapshot(
+        &mut self,
+        timeout: Duration,
+    ) -> Result<(), RendezvousError> {
+        let _span = self.wait_span("rendezvous_timeout_snapshot");
+        self.rendezvous_timeout(timeout).map_err(|_| {
+            let outstanding = self
+                .channel
+                .get()
+                .map(|channel| channel.shared.snapshot())
+                .unwrap_or_default();
+            RendezvousError::Timeout { outstanding }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RendezvousBuilder;
+
+    #[test]
+    fn timeout_error_lists_outstanding_guards() {
+        let mut rendezvous = RendezvousBuilder::new().full_tracking().build();
+        let first = rendezvous.fork_guard_labeled("first");
+        let second = rendezvous.fork_guard_labeled("second");
+        let completed = rendezvous.fork_guard_labeled("completed");
+        drop(completed);
+
+        let error = rendezvous
+            .rendezvous_timeout_snapshot(Duration::from_millis(10))
+            .unwrap_err();
+        let RendezvousError::Timeout { outstanding } = &error else {
+            panic!("expected a timeout");
+        };
+        let labels: Vec<_> = outstanding.iter().map(|s| s.label.as_deref()).collect();
+        assert_eq!(labels, vec![Some("first"), Some("second")]);
+        assert_eq!(outstanding[0].id, first.id());
+        assert!(outstanding[0].age >= Duration::from_millis(10));
+        assert!(error.to_string().contains("'second'"));
+
+        drop(first);
+        drop(second);
+        assert_eq!(
+            rendezvous.rendezvous_timeout_snapshot(Duration::from_secs(1)),
+            Ok(())
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn assert_complete_lists_outstanding_guards() {
+        use std::panic::{self, AssertUnwindSafe};
+
+        let rendezvous = Rendezvous::new();
+        let guard = rendezvous.fork_guard_labeled("straggler");
+        let panic =
+            panic::catch_unwind(AssertUnwindSafe(|| rendezvous.assert_complete())).unwrap_err();
+        let message = panic.downcast_ref::<String>().unwrap();
+        assert!(message.starts_with("Rendezvous has 1 outstanding guards: #0 'straggler' ("));
+
+        drop(guard);
+        rendezvous.assert_complete();
+        rendezvous.rendezvous();
+    }
+
+    #[test]
+    fn timeout_error_renders_guard_tree() {
+        let mut rendezvous = RendezvousBuilder::new().full_tracking().build();
+        let ingest = rendezvous.fork_guard_labeled("ingest");
+        let parse = ingest.fork();
+        let validate = parse.fork();
+        let index = ingest.fork();
+        let other = rendezvous.fork_guard_labeled("other");
+
+        let error = rendezvous
+            .rendezvous_timeout_snapshot(Duration::from_millis(10))
+            .unwrap_err();
+        let tree = error.outstanding_tree().unwrap();
+        let lines: Vec<_> = tree.lines().collect();
+        assert_eq!(lines.len(), 5);
+        assert!(lines[0].starts_with("#0 'ingest' ("));
+        assert!(lines[1].starts_with("├── #1 ("));
+        assert!(lines[2].starts_with("│   └── #2 ("));
+        assert!(lines[3].starts_with("└── #3 ("));
+        assert!(lines[4].starts_with("#4 'other' ("));
+        assert!(lines.iter().all(|line| line.ends_with(" old)")));
+
+        drop((ingest, parse, validate, index, other));
+        assert_eq!(
+            rendezvous.rendezvous_timeout_snapshot(Duration::from_secs(1)),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn attributes_appear_in_snapshots() {
+        let mut rendezvous = RendezvousBuilder::new().full_tracking().build();
+        let mut guard = rendezvous.fork_guard_labeled("worker");
+        guard.set_attr("shard", "3");
+        guard.set_attr("region", "us");
+        guard.set_attr("region", "eu");
+
+        let error = rendezvous
+            .rendezvous_timeout_snapshot(Duration::from_millis(10))
+            .unwrap_err();
+        let RendezvousError::Timeout { outstanding } = &error else {
+            panic!("expected a timeout");
+        };
+        assert_eq!(
+            outstanding[0].attributes,
+            vec![
+                ("shard".to_owned(), "3".to_owned()),
+                ("region".to_owned(), "eu".to_owned())
+            ]
+        );
+        assert_eq!(outstanding[0].attr("shard"), Some("3"));
+        assert_eq!(outstanding[0].attr("missing"), None);
+        assert!(error
+            .to_string()
+            .contains("#0 'worker' [shard=3, region=eu] ("));
+        drop(guard);
+    }
+
+    #[test]
+    fn unlabeled_guards_are_listed_alongside_labeled_ones() {
+        let mut rendezvous = RendezvousBuilder::new().full_tracking().build();
+        let labeled = rendezvous.fork_guard_labeled("db");
+        let unlabeled = rendezvous.fork_guard();
+
+        let error = rendezvous
+            .rendezvous_timeout_snapshot(Duration::from_millis(10))
+            .unwrap_err();
+        let RendezvousError::Timeout { outstanding } = &error else {
+            panic!("expected a timeout");
+        };
+        let labels: Vec<_> = outstanding.iter().map(|s| s.label.as_deref()).collect();
+        assert_eq!(labels, vec![Some("db"), None]);
+        assert_eq!(outstanding[1].id, unlabeled.id());
+        drop((labeled, unlabeled));
+    }
+
+    #[test]
+    fn orphaned_guards_are_rendered_as_roots() {
+        let mut rendezvous = RendezvousBuilder::new().full_tracking().build();
+        let parent = rendezvous.fork_guard();
+        let child = parent.fork();
+        drop(parent);
+
+        let error = rendezvous
+            .rendezvous_timeout_snapshot(Duration::from_millis(10))
+            .unwrap_err();
+        assert!(error.outstanding_tree().unwrap().starts_with("#1 ("));
+        drop(child);
+    }
+}
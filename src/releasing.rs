@@ -0,0 +1,91 @@
+//! Releasing resources right before blocking on a rendezvous.
+
+use crate::Rendezvous;
+use std::sync::MutexGuard;
+use std::time::Instant;
+
+impl<C> Rendezvous<C> {
+    /// Executes the rendezvous process, running `release` immediately before blocking.
+    ///
+    /// A common deadlock is a thread holding a resource, such as a [`MutexGuard`], while
+    /// waiting for the rendezvous, while the workers need that same resource to finish and drop
+    /// their guards. Releasing the resource in `release` ensures it is no longer held while
+    /// waiting. Re-acquiring it afterwards is the caller's responsibility.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::sync::{Arc, Mutex};
+    /// use std::thread;
+    /// use rendezvous::Rendezvous;
+    ///
+    /// let connection = Arc::new(Mutex::new(Vec::new()));
+    /// let rendezvous = Rendezvous::new();
+    ///
+    /// let mut held = connection.lock().unwrap();
+    /// held.push("coordinator");
+    ///
+    /// thread::spawn({
+    ///     let guard = rendezvous.fork_guard();
+    ///     let connection = connection.clone();
+    ///     move || {
+    ///         connection.lock().unwrap().push("worker");
+    ///         drop(guard);
+    ///     }
+    /// });
+    ///
+    /// rendezvous.rendezvous_after(|| drop(held));
+    /// assert_eq!(*connection.lock().unwrap(), ["coordinator", "worker"]);
+    /// ```
+    pub fn rendezvous_after<F>(mut self, release: F)
+    where
+        F: FnOnce(),
+    {
+        let _span = self.wait_span("rendezvous_after");
+        let started = Instant::now();
+        let channel = self.release();
+        release();
+        if let Some(channel) = channel {
+            channel.wait();
+        }
+        self.record_wait(started);
+    }
+
+    /// Executes the rendezvous process, unlocking `lock` immediately before blocking.
+    ///
+    /// This is a shorthand for [`rendezvous_after`](Self::rendezvous_after) dropping the
+    /// [`MutexGuard`], so that workers waiting for the same mutex can finish while the calling
+    /// thread waits for them.
+    pub fn rendezvous_releasing<T>(self, lock: MutexGuard<'_, T>) {
+        self.rendezvous_after(|| drop(lock));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn lock_is_released_before_waiting() {
+        let counter = Arc::new(Mutex::new(0));
+        let rendezvous = Rendezvous::new();
+
+        let held = counter.lock().unwrap();
+        for _ in 0..4 {
+            let guard = rendezvous.fork_guard();
+            let counter = counter.clone();
+            thread::spawn(move || {
+                *counter.lock().unwrap() += 1;
+                drop(guard);
+            });
+        }
+
+        // Give the workers time to block on the mutex.
+        thread::sleep(Duration::from_millis(20));
+        rendezvous.rendezvous_releasing(held);
+        assert_eq!(*counter.lock().unwrap(), 4);
+    }
+}
@@ -0,0 +1,143 @@
+//! Reserving capacity for guards to be forked later.
+
+use crate::{Rendezvous, RendezvousGuard, Shared};
+use std::sync::atomic::Ordering;
+
+impl Shared {
+    /// Counts a guard or reservation against the guard limit, returning `false` if the limit
+    /// is reached.
+    fn try_admit(&self) -> bool {
+        let max_guards = self.options.max_guards;
+        self.admitted
+            .fetch_update(
+                Ordering::AcqRel,
+                Ordering::Acquire,
+                |admitted| match max_guards {
+                    Some(max_guards) if admitted >= max_guards => None,
+                    _ => Some(admitted + 1),
+                },
+            )
+            .is_ok()
+    }
+
+    /// Counts a guard against the guard limit, panicking if the limit is reached.
+    pub(crate) fn admit(&self) {
+        if !self.try_admit() {
+            panic!(
+                "Guard limit of {} exceeded",
+                self.options.max_guards.unwrap_or_default()
+            )
+        }
+    }
+}
+
+/// The reserved right to fork a guard off a [`Rendezvous`] later.
+///
+/// A reservation counts against the [guard limit](crate::RendezvousBuilder::max_guards), but
+/// does not keep the rendezvous open. Turn it into a guard using [`Reservation::realize`], or
+/// drop it to release the capacity again.
+#[must_use = "dropping a reservation releases it"]
+pub struct Reservation<'a, C = ()> {
+    /// The rendezvous the reservation was made on.
+    rendezvous: &'a Rendezvous<C>,
+    /// Whether the reservation was realized into a guard.
+    realized: bool,
+}
+
+impl<C> Reservation<'_, C> {
+    /// Converts the reservation into a guard, which keeps the rendezvous open until dropped.
+    ///
+    /// The guard takes over the capacity held by the reservation, so this never exceeds the
+    /// guard limit.
+    pub fn realize(mut self) -> RendezvousGuard {
+        self.realized = true;
+        self.rendezvous
+            .expect_forked(self.rendezvous.fork_admitted(None))
+    }
+}
+
+impl<C> Drop for Reservation<'_, C> {
+    fn drop(&mut self) {
+        if !self.realized {
+            self.rendezvous
+                .shared()
+                .admitted
+                .fetch_sub(1, Ordering::AcqRel);
+        }
+    }
+}
+
+impl<C> Rendezvous<C> {
+    /// Reserves the right to fork a guard later, or returns `None` if the
+    /// [guard limit](crate::RendezvousBuilder::max_guards) is reached.
+    ///
+    /// The reservation counts against the limit, but not against completion: the rendezvous
+    /// does not wait for unrealized reservations. This decouples admitting work from starting
+    /// it. Without a guard limit, reserving always succeeds.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use rendezvous::RendezvousBuilder;
+    ///
+    /// let rendezvous = RendezvousBuilder::new().max_guards(2).build();
+    /// let admitted = rendezvous.reserve().expect("capacity is available");
+    /// let guard = admitted.realize();
+    /// drop(guard);
+    /// rendezvous.rendezvous();
+    /// ```
+    pub fn reserve(&self) -> Option<Reservation<'_, C>> {
+        if self.released {
+            return None;
+        }
+        if !self.channel().shared.try_admit() {
+            return None;
+        }
+        Some(Reservation {
+            rendezvous: self,
+            realized: false,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::RendezvousBuilder;
+    use std::panic::{self, AssertUnwindSafe};
+
+    #[test]
+    fn reservations_respect_the_guard_limit() {
+        let rendezvous = RendezvousBuilder::new().max_guards(3).build();
+
+        let first = rendezvous.reserve().unwrap();
+        let second = rendezvous.reserve().unwrap();
+        let third = rendezvous.reserve().unwrap();
+        assert!(rendezvous.reserve().is_none());
+
+        // Reservations do not count against completion.
+        assert_eq!(rendezvous.remaining_guards(), 0);
+
+        let guard = first.realize();
+        assert_eq!(rendezvous.remaining_guards(), 1);
+        assert!(rendezvous.reserve().is_none());
+
+        drop(second);
+        let fourth = rendezvous.reserve().unwrap();
+        assert!(rendezvous.reserve().is_none());
+
+        drop(guard);
+        assert!(rendezvous.reserve().is_some());
+        drop((third, fourth));
+        rendezvous.rendezvous();
+    }
+
+    #[test]
+    fn forking_beyond_the_limit_panics() {
+        let rendezvous = RendezvousBuilder::new().max_guards(1).build();
+        let guard = rendezvous.fork_guard();
+        let result = panic::catch_unwind(AssertUnwindSafe(|| guard.fork()));
+        assert!(result.is_err());
+        drop(guard);
+        rendezvous.rendezvous();
+    }
+}
@@ -0,0 +1,231 @@
+//! Reusing a rendezvous across rounds.
+
+use crate::{bridge, lock, Channel, Rendezvous, RendezvousGuard, ResetError, Shared};
+use std::sync::atomic::AtomicBool;
+use std::sync::{Arc, Mutex};
+
+impl Channel {
+    /// Reconnects the channel for `round` using a new channel pair, carrying over the ledger
+    /// and reusing the allocations of the shared state if nothing else refers to it anymore.
+    fn recycle(&mut self, round: u64) {
+        let (tx, rx) = bridge::channel();
+        *self.rx.get_mut().unwrap_or_else(|p| p.into_inner()) = rx;
+        *self.tx.get_mut().unwrap_or_else(|p| p.into_inner()) = Some(tx);
+        self.bridged = false;
+        if let Some(state) = Arc::get_mut(&mut self.shared) {
+            state.recycle(round);
+            return;
+        }
+        let ledger = lock(&self.shared.ledger).take();
+        self.shared = Arc::new(Shared {
+            options: self.shared.options.clone(),
+            round,
+            ledgered: AtomicBool::new(ledger.is_some()),
+            ledger: Mutex::new(ledger),
+            ..Shared::default()
+        });
+    }
+}
+
+impl Shared {
+    /// Resets this state for `round` in place, keeping the ledger and the capacity of the
+    /// per-guard bookkeeping.
+    fn recycle(&mut self, round: u64) {
+        fn cleared<T: Default>(mutex: &mut Mutex<T>, clear: fn(&mut T)) -> Mutex<T> {
+            let mut value = std::mem::take(mutex.get_mut().unwrap_or_else(|p| p.into_inner()));
+            clear(&mut value);
+            Mutex::new(value)
+        }
+
+        let ledger = std::mem::take(&mut self.ledger);
+        let ledgered = std::mem::take(&mut self.ledgered);
+        let registry = cleared(&mut self.registry, |registry| registry.clear());
+        let panicked = cleared(&mut self.panicked, Vec::clear);
+        let armed = cleared(&mut self.armed, Vec::clear);
+        let tiers = cleared(&mut self.tiers, |tiers| tiers.clear());
+        let lifetimes = cleared(&mut self.lifetimes, Vec::clear);
+        *self = Shared {
+            options: std::mem::take(&mut self.options),
+            round,
+            ledger,
+            ledgered,
+            registry,
+            panicked,
+            armed,
+            tiers,
+            lifetimes,
+            ..Shared::default()
+        };
+    }
+}
+
+impl<C> Rendezvous<C> {
+    /// Returns the current round of this rendezvous.
+    ///
+    /// The round starts at `0` and is incremented by every [`reset`](Self::reset). It is
+    /// included in log messages and [ledger entries](crate::LedgerEntry), and guards report the
+    /// round they were forked in via [`RendezvousGuard::round`].
+    pub fn round(&self) -> u64 {
+        self.round
+    }
+
+    /// Prepares the rendezvous for another round after a completed rendezvous.
+    ///
+    /// The internal channel is recreated, so that guards can be forked and waited for again,
+    /// and the [round](Self::round) is incremented. A [ledger](Self::with_ledger) set on the
+    /// rendezvous is carried over into the next round.
+    ///
+    /// To avoid allocation churn in pipelines with many rounds, the bookkeeping of the previous
+    /// round is cleared and reused, unless a [receipt](crate::GuardReceipt) still refers to it.
+    /// The channel itself cannot be reconnected once all its senders are dropped, so a new one
+    /// is created for every round.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`ResetError::GuardsOutstanding`] if guards of the current round are still alive.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use rendezvous::Rendezvous;
+    ///
+    /// let mut rendezvous = Rendezvous::new();
+    /// for round in 0..3 {
+    ///     let guard = rendezvous.fork_guard();
+    ///     assert_eq!(guard.round(), round);
+    ///     drop(guard);
+    ///     rendezvous.rendezvous_ref();
+    ///     rendezvous.reset().unwrap();
+    /// }
+    /// # rendezvous.rendezvous();
+    /// ```
+    pub fn reset(&mut self) -> Result<(), ResetError> {
+        if self.remaining_guards() > 0 {
+            return Err(ResetError::GuardsOutstanding);
+        }
+        if let Some(channel) = self.channel.get_mut() {
+            if Arc::get_mut(channel).is_none() {
+                return Err(ResetError::Cloned);
+            }
+        }
+        self.released = false;
+        self.abandoned = false;
+        self.round += 1;
+        // The registration refers to the shared state weakly, which would prevent recycling it.
+        #[cfg(feature = "global-registry")]
+        self.registration.update(self.round, None);
+        if let Some(channel) = self.channel.get_mut().and_then(Arc::get_mut) {
+            channel.recycle(self.round);
+        }
+        #[cfg(feature = "global-registry")]
+        self.registration.update(
+            self.round,
+            self.channel.get().map(|channel| &channel.shared),
+        );
+        #[cfg(any(feature = "log", feature = "tracing"))]
+        {
+            trace!("Rendezvous was reset{}", self.log_context());
+        }
+        Ok(())
+    }
+}
+
+impl RendezvousGuard {
+    /// Returns the [round](Rendezvous::round) of the rendezvous this guard was forked in.
+    pub fn round(&self) -> u64 {
+        self.shared.round
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LedgerEvent, RendezvousBuilder};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    #[test]
+    fn guards_report_their_round() {
+        let ledger = Arc::new(Mutex::new(Vec::new()));
+        let mut rendezvous = Rendezvous::new();
+        rendezvous.with_ledger(ledger.clone());
+        assert_eq!(rendezvous.round(), 0);
+
+        let first = rendezvous.fork_guard();
+        let nested = first.fork();
+        assert_eq!((first.round(), nested.round()), (0, 0));
+        thread::spawn(move || drop((first, nested)));
+        rendezvous.rendezvous_ref();
+
+        rendezvous.reset().unwrap();
+        assert_eq!(rendezvous.round(), 1);
+        let second = rendezvous.fork_guard();
+        assert_eq!(second.round(), 1);
+        thread::spawn(move || drop(second));
+        rendezvous.rendezvous();
+
+        let rounds: Vec<_> = lock(&ledger)
+            .iter()
+            .filter(|entry| entry.event == LedgerEvent::Forked)
+            .map(|entry| entry.round)
+            .collect();
+        assert_eq!(rounds, vec![0, 0, 1]);
+    }
+
+    #[test]
+    fn reset_clears_and_reuses_bookkeeping() {
+        let mut rendezvous = RendezvousBuilder::new().full_tracking().build();
+        let guards: Vec<_> = (0..64)
+            .map(|_| rendezvous.fork_guard_labeled("first"))
+            .collect();
+        let shared = Arc::as_ptr(rendezvous.shared());
+        drop(guards);
+        rendezvous.rendezvous_ref();
+        rendezvous.reset().unwrap();
+
+        assert_eq!(Arc::as_ptr(rendezvous.shared()), shared);
+        assert!(lock(&rendezvous.shared().registry).capacity() >= 64);
+        assert_eq!(rendezvous.remaining_guards(), 0);
+        assert_eq!(rendezvous.ramp_window(), None);
+
+        let guard = rendezvous.fork_guard_labeled("second");
+        assert_eq!(guard.id().as_u64(), 0);
+        let snapshot = rendezvous.shared().snapshot();
+        assert_eq!(snapshot.len(), 1);
+        assert_eq!(snapshot[0].label.as_deref(), Some("second"));
+        drop(guard);
+        rendezvous.rendezvous();
+    }
+
+    #[test]
+    fn reset_supports_round_based_barrier() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let mut rendezvous = Rendezvous::new();
+        for round in 0..5 {
+            for _ in 0..3 {
+                let guard = rendezvous.fork_guard();
+                let counter = counter.clone();
+                thread::spawn(move || {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    drop(guard);
+                });
+            }
+            rendezvous.rendezvous_ref();
+            assert_eq!(counter.load(Ordering::SeqCst), 3 * (round + 1));
+            rendezvous.reset().unwrap();
+        }
+        rendezvous.rendezvous();
+    }
+
+    #[test]
+    fn reset_fails_with_outstanding_guards() {
+        let mut rendezvous = Rendezvous::new();
+        let guard = rendezvous.fork_guard();
+        assert_eq!(rendezvous.reset(), Err(ResetError::GuardsOutstanding));
+        assert_eq!(rendezvous.round(), 0);
+        drop(guard);
+        assert_eq!(rendezvous.reset(), Ok(()));
+        rendezvous.rendezvous();
+    }
+}
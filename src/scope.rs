@@ -0,0 +1,124 @@
+//! Scoped rendezvous mirroring [`std::thread::scope`].
+
+use crate::{Rendezvous, RendezvousGuard};
+use std::panic::{self, AssertUnwindSafe};
+use std::thread::{self, Scope, ScopedJoinHandle};
+
+/// A rendezvous scope created using [`Rendezvous::scope`].
+///
+/// Threads spawned using [`ScopedRendezvous::spawn`] hold a guard for as long as they run,
+/// and may borrow from outside the scope like threads spawned using [`Scope::spawn`].
+pub struct ScopedRendezvous<'scope, 'env: 'scope> {
+    /// The rendezvous waited on when the scope ends.
+    rendezvous: Rendezvous,
+    /// The thread scope guarded threads are spawned in.
+    scope: &'scope Scope<'scope, 'env>,
+}
+
+impl<'scope> ScopedRendezvous<'scope, '_> {
+    /// Spawns a scoped thread holding a guard of the rendezvous until `f` returns.
+    pub fn spawn<F, T>(&self, f: F) -> ScopedJoinHandle<'scope, T>
+    where
+        F: FnOnce() -> T + Send + 'scope,
+        T: Send + 'scope,
+    {
+        let guard = self.rendezvous.fork_guard();
+        self.scope.spawn(move || {
+            let _guard = guard;
+            f()
+        })
+    }
+
+    /// Forks a guard off the rendezvous, e.g. to hand it to work not spawned using
+    /// [`ScopedRendezvous::spawn`]. The scope does not end before the guard is dropped.
+    pub fn fork_guard(&self) -> RendezvousGuard {
+        self.rendezvous.fork_guard()
+    }
+
+    /// Returns the number of guards not yet dropped. See [`Rendezvous::remaining_guards`].
+    pub fn remaining_guards(&self) -> usize {
+        self.rendezvous.remaining_guards()
+    }
+}
+
+impl Rendezvous {
+    /// Runs `f` with a new rendezvous scope, then waits for all guards forked in it.
+    ///
+    /// Like [`thread::scope`], this guarantees that all guarded work completed before it
+    /// returns: the rendezvous is waited on exactly once when `f` returns, and all threads
+    /// spawned in the scope are joined. If `f` panics, the rendezvous is still waited on
+    /// before the panic resumes unwinding.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use rendezvous::Rendezvous;
+    ///
+    /// let processed = AtomicUsize::new(0);
+    /// Rendezvous::scope(|scope| {
+    ///     for _ in 0..4 {
+    ///         scope.spawn(|| processed.fetch_add(1, Ordering::SeqCst));
+    ///     }
+    /// });
+    /// assert_eq!(processed.load(Ordering::SeqCst), 4);
+    /// ```
+    pub fn scope<'env, F, R>(f: F) -> R
+    where
+        F: for<'scope> FnOnce(&ScopedRendezvous<'scope, 'env>) -> R,
+    {
+        thread::scope(|scope| {
+            let scoped = ScopedRendezvous {
+                rendezvous: Rendezvous::new(),
+                scope,
+            };
+            let result = panic::catch_unwind(AssertUnwindSafe(|| f(&scoped)));
+            scoped.rendezvous.rendezvous();
+            result.unwrap_or_else(|payload| panic::resume_unwind(payload))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[test]
+    fn scope_waits_for_handed_off_guards() {
+        let (tx, rx) = std::sync::mpsc::channel();
+        let value = Rendezvous::scope(|scope| {
+            let guard = scope.fork_guard();
+            std::thread::spawn(move || {
+                std::thread::sleep(Duration::from_millis(20));
+                tx.send(()).unwrap();
+                drop(guard);
+            });
+            assert_eq!(scope.remaining_guards(), 1);
+            42
+        });
+        assert_eq!(value, 42);
+        assert_eq!(rx.try_recv(), Ok(()));
+    }
+
+    #[test]
+    fn scope_waits_before_resuming_a_panic() {
+        let finished = Arc::new(AtomicUsize::new(0));
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            Rendezvous::scope(|scope| {
+                let guard = scope.fork_guard();
+                let finished = finished.clone();
+                std::thread::spawn(move || {
+                    std::thread::sleep(Duration::from_millis(20));
+                    finished.fetch_add(1, Ordering::SeqCst);
+                    drop(guard);
+                });
+                panic!("coordinator failed");
+            })
+        }));
+        assert!(result.is_err());
+        assert_eq!(finished.load(Ordering::SeqCst), 1);
+    }
+}
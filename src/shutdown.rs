@@ -0,0 +1,161 @@
+//! A graceful shutdown sequence combining a bounded wait, cancellation and panic detection.
+
+use crate::{lock, GuardId, Rendezvous, Shared};
+use std::collections::HashSet;
+use std::sync::atomic::Ordering;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// The outcome of [`Rendezvous::shutdown`], classifying every guard ever forked.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct ShutdownReport {
+    /// Guards that were dropped regularly within the grace period, ordered by id.
+    pub completed: Vec<GuardId>,
+    /// Guards that were still outstanding when the grace period elapsed and were
+    /// cancelled, ordered by id.
+    pub cancelled: Vec<GuardId>,
+    /// Guards that were dropped while their thread was panicking, ordered by id.
+    pub panicked: Vec<GuardId>,
+}
+
+impl ShutdownReport {
+    /// Returns whether all guards completed regularly within the grace period.
+    pub fn is_clean(&self) -> bool {
+        self.cancelled.is_empty() && self.panicked.is_empty()
+    }
+}
+
+impl Shared {
+    /// Records that a guard was dropped, noting whether its thread was panicking.
+    pub(crate) fn record_panic(&self, id: GuardId) {
+        if thread::panicking() {
+            lock(&self.panicked).push(id);
+        }
+    }
+}
+
+impl<C> Rendezvous<C> {
+    /// Gracefully shuts down the rendezvous, returning a report instead of blocking.
+    ///
+    /// The sequence is:
+    ///
+    /// 1. The original sender is released and all guards are waited for, up to `grace`.
+    /// 2. If guards are still outstanding when `grace` elapses, they are recorded as
+    ///    cancelled and the rendezvous is [cancelled](Rendezvous::cancel), signaling their
+    ///    holders to bail out.
+    /// 3. Every guard ever forked is classified as completed, cancelled, or panicked (i.e.
+    ///    dropped while its thread was unwinding).
+    /// 4. The rendezvous is dropped without waiting for the cancelled guards.
+    ///
+    /// Guards are classified as of the moment the grace period ended; cancelled guards that are
+    /// dropped afterwards are still reported as cancelled. For a
+    /// [minimal](crate::RendezvousBuilder::minimal) rendezvous, outstanding guards are not
+    /// tracked, so only completed and panicked guards are reported.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use rendezvous::RendezvousBuilder;
+    ///
+    /// let rendezvous = RendezvousBuilder::new().full_tracking().build();
+    /// let done = rendezvous.fork_guard();
+    /// let stuck = rendezvous.fork_guard();
+    /// let (done_id, stuck_id) = (done.id(), stuck.id());
+    /// drop(done);
+    ///
+    /// let report = rendezvous.shutdown(Duration::from_millis(10));
+    /// assert_eq!(report.completed, vec![done_id]);
+    /// assert_eq!(report.cancelled, vec![stuck_id]);
+    /// assert!(stuck.is_cancelled());
+    /// ```
+    pub fn shutdown(mut self, grace: Duration) -> ShutdownReport {
+        let _span = self.wait_span("shutdown");
+        let started = Instant::now();
+        let Some(channel) = self.release() else {
+            return ShutdownReport::default();
+        };
+        let timed_out = channel.wait_deadline(started + grace).is_err();
+        let shared = channel.shared.clone();
+        self.record_wait(started);
+
+        let mut report = ShutdownReport::default();
+        if timed_out {
+            report.cancelled = shared
+                .snapshot()
+                .into_iter()
+                .map(|guard| guard.id)
+                .collect();
+            shared.cancel();
+            self.abandoned = true;
+            #[cfg(any(feature = "log", feature = "tracing"))]
+            {
+                debug!(
+                    "Rendezvous shutdown cancelled {} outstanding guards{}",
+                    report.cancelled.len(),
+                    self.log_context()
+                );
+            }
+        }
+
+        report.panicked = lock(&shared.panicked).clone();
+        report.panicked.sort();
+
+        let cancelled: HashSet<_> = report.cancelled.iter().collect();
+        let panicked: HashSet<_> = report.panicked.iter().collect();
+        let forked = shared.next_id.load(Ordering::Acquire);
+        report.completed = (0..forked)
+            .map(GuardId)
+            .filter(|id| !cancelled.contains(id) && !panicked.contains(id))
+            .collect();
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RendezvousBuilder;
+
+    #[test]
+    fn report_classifies_guards() {
+        let rendezvous = RendezvousBuilder::new().full_tracking().build();
+
+        let completing = rendezvous.fork_guard();
+        let stuck = rendezvous.fork_guard();
+        let panicking = rendezvous.fork_guard();
+        let ids = (completing.id(), stuck.id(), panicking.id());
+
+        thread::spawn(move || drop(completing)).join().unwrap();
+        thread::spawn(move || {
+            let _guard = panicking;
+            panic!("worker failed");
+        })
+        .join()
+        .unwrap_err();
+        let stuck_worker = thread::spawn(move || {
+            while !stuck.is_cancelled() {
+                thread::sleep(Duration::from_millis(5));
+            }
+        });
+
+        let report = rendezvous.shutdown(Duration::from_millis(100));
+
+        assert_eq!(report.completed, vec![ids.0]);
+        assert_eq!(report.cancelled, vec![ids.1]);
+        assert_eq!(report.panicked, vec![ids.2]);
+        assert!(!report.is_clean());
+        stuck_worker.join().unwrap();
+    }
+
+    #[test]
+    fn clean_shutdown() {
+        let rendezvous = Rendezvous::new();
+        let guard = rendezvous.fork_guard();
+        thread::spawn(move || drop(guard));
+
+        let report = rendezvous.shutdown(Duration::from_secs(5));
+        assert!(report.is_clean());
+        assert_eq!(report.completed.len(), 1);
+    }
+}
@@ -0,0 +1,239 @@
+//! Waiting for a rendezvous until interrupted by `SIGINT`.
+
+use crate::{lock, Rendezvous};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::RecvTimeoutError;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// The number of `SIGINT`s received while the handler was installed.
+static INTERRUPTS: AtomicUsize = AtomicUsize::new(0);
+
+/// The state of the `SIGINT` handler, shared by all interruptible waits.
+static HANDLER: Mutex<Handler> = Mutex::new(Handler {
+    waiters: 0,
+    previous: None,
+});
+
+/// The outcome of [`Rendezvous::rendezvous_interruptible_signal`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum RendezvousOutcome {
+    /// All guards were dropped.
+    Completed,
+    /// A `SIGINT` was received before all guards were dropped.
+    Interrupted,
+}
+
+/// The installation of the `SIGINT` handler.
+struct Handler {
+    /// The number of waits observing `SIGINT`.
+    waiters: usize,
+    /// The disposition replaced by the handler, restored once no wait observes `SIGINT`, or
+    /// `None` if the handler could not be installed.
+    previous: Option<libc::sigaction>,
+}
+
+/// The `SIGINT` handler. Only increments an atomic, which is async-signal-safe.
+extern "C" fn on_sigint(_signal: libc::c_int) {
+    INTERRUPTS.fetch_add(1, Ordering::SeqCst);
+}
+
+/// Installs the `SIGINT` handler, returning the disposition it replaced.
+fn install_handler() -> Option<libc::sigaction> {
+    // SAFETY: Both actions are fully initialized before use, and the handler only performs
+    // an async-signal-safe atomic increment.
+    unsafe {
+        let mut action: libc::sigaction = std::mem::zeroed();
+        action.sa_sigaction = on_sigint as extern "C" fn(libc::c_int) as libc::sighandler_t;
+        action.sa_flags = libc::SA_RESTART;
+        libc::sigemptyset(&mut action.sa_mask);
+        let mut previous: libc::sigaction = std::mem::zeroed();
+        if libc::sigaction(libc::SIGINT, &action, &mut previous) != 0 {
+            #[cfg(any(feature = "log", feature = "tracing"))]
+            {
+                warn!(
+                    "Failed to install the SIGINT handler: {}",
+                    std::io::Error::last_os_error()
+                );
+            }
+            return None;
+        }
+        Some(previous)
+    }
+}
+
+/// Restores the `SIGINT` disposition replaced by [`install_handler`].
+fn restore_handler(previous: &libc::sigaction) {
+    // SAFETY: `previous` was filled in by `sigaction` when installing the handler.
+    if unsafe { libc::sigaction(libc::SIGINT, previous, std::ptr::null_mut()) } != 0 {
+        #[cfg(any(feature = "log", feature = "tracing"))]
+        {
+            warn!(
+                "Failed to restore the SIGINT disposition: {}",
+                std::io::Error::last_os_error()
+            );
+        }
+    }
+}
+
+/// Observes `SIGINT` while alive.
+///
+/// The first observer installs the handler, and the last one restores the previous
+/// disposition when dropped.
+struct Interrupts {
+    /// The value of [`INTERRUPTS`] when the observation started.
+    observed: usize,
+}
+
+impl Interrupts {
+    fn observe() -> Self {
+        // Read before installing the handler, so a signal received right after installing it
+        // is not missed.
+        let observed = INTERRUPTS.load(Ordering::SeqCst);
+        let mut handler = lock(&HANDLER);
+        if handler.waiters == 0 {
+            handler.previous = install_handler();
+        }
+        handler.waiters += 1;
+        Self { observed }
+    }
+
+    /// Returns whether a `SIGINT` was received since the observation started.
+    fn received(&self) -> bool {
+        INTERRUPTS.load(Ordering::SeqCst) != self.observed
+    }
+}
+
+impl Drop for Interrupts {
+    fn drop(&mut self) {
+        let mut handler = lock(&HANDLER);
+        handler.waiters -= 1;
+        if handler.waiters == 0 {
+            if let Some(previous) = handler.previous.take() {
+                restore_handler(&previous);
+            }
+        }
+    }
+}
+
+impl<C> Rendezvous<C> {
+    /// Executes the rendezvous process until all guards are dropped, or until the process
+    /// receives a `SIGINT`, e.g. because Ctrl-C was pressed.
+    ///
+    /// For the duration of the wait, a process-wide `SIGINT` handler replaces the previous
+    /// disposition of the signal, e.g. the default of terminating the process. The previous
+    /// disposition is restored once no interruptible wait is in progress anymore, so a signal
+    /// received outside of a wait is handled as before. The handler only increments an atomic
+    /// counter, which is async-signal-safe, and interrupts all waits in progress. The wait is a
+    /// polling loop that checks the counter every `poll` interval, so an interrupt is observed
+    /// at most one interval late.
+    ///
+    /// <div class="warning">
+    /// After an interrupt, the remaining guards keep running but are no longer waited on: the
+    /// rendezvous is abandoned, and dropping it does not block. Another component changing the
+    /// `SIGINT` disposition during the wait prevents interrupts from being observed, and its
+    /// change is undone when the wait ends.
+    /// </div>
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use rendezvous::{Rendezvous, RendezvousOutcome};
+    ///
+    /// let mut rendezvous = Rendezvous::new();
+    /// let guard = rendezvous.fork_guard();
+    /// // ... hand the guard to a long-running worker ...
+    /// # std::mem::forget(guard);
+    ///
+    /// match rendezvous.rendezvous_interruptible_signal(Duration::from_millis(50)) {
+    ///     RendezvousOutcome::Completed => println!("done"),
+    ///     RendezvousOutcome::Interrupted => println!("interrupted, shutting down"),
+    /// }
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "signal", unix))))]
+    pub fn rendezvous_interruptible_signal(&mut self, poll: Duration) -> RendezvousOutcome {
+        let _span = self.wait_span("rendezvous_interruptible_signal");
+        let interrupts = Interrupts::observe();
+        let started = Instant::now();
+        let mut outcome = RendezvousOutcome::Completed;
+        if let Some(channel) = self.release() {
+            loop {
+                if interrupts.received() {
+                    outcome = RendezvousOutcome::Interrupted;
+                    break;
+                }
+                match channel.recv_timeout(poll) {
+                    Ok(_) | Err(RecvTimeoutError::Timeout) => continue,
+                    Err(RecvTimeoutError::Disconnected) => break,
+                }
+            }
+        }
+        drop(interrupts);
+        self.record_wait(started);
+        if outcome == RendezvousOutcome::Interrupted {
+            #[cfg(any(feature = "log", feature = "tracing"))]
+            {
+                debug!(
+                    "A rendezvous was interrupted by SIGINT{}",
+                    self.log_context()
+                );
+            }
+            self.abandoned = true;
+        }
+        outcome
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    /// Returns the current `SIGINT` handler.
+    fn current_handler() -> libc::sighandler_t {
+        // SAFETY: Querying the disposition only writes to `current`.
+        unsafe {
+            let mut current: libc::sigaction = std::mem::zeroed();
+            assert_eq!(
+                libc::sigaction(libc::SIGINT, std::ptr::null(), &mut current),
+                0
+            );
+            current.sa_sigaction
+        }
+    }
+
+    #[test]
+    fn sigint_interrupts_the_wait_and_restores_the_disposition() {
+        let previous = current_handler();
+
+        let mut rendezvous = Rendezvous::new();
+        let guard = rendezvous.fork_guard();
+        let interrupter = thread::spawn(move || {
+            // Raising the signal before the handler is installed would terminate the process.
+            // Function addresses are not guaranteed to be unique, so this waits for any change
+            // rather than comparing against `on_sigint`.
+            while current_handler() == previous {
+                thread::yield_now();
+            }
+            // SAFETY: The handler installed by the wait only increments an atomic.
+            assert_eq!(unsafe { libc::raise(libc::SIGINT) }, 0);
+        });
+
+        let outcome = rendezvous.rendezvous_interruptible_signal(Duration::from_millis(10));
+        assert_eq!(outcome, RendezvousOutcome::Interrupted);
+        interrupter.join().unwrap();
+        assert_eq!(current_handler(), previous);
+
+        // The abandoned rendezvous does not wait for the guard when dropped.
+        drop(rendezvous);
+        drop(guard);
+
+        // The interrupt does not carry over into the next wait.
+        let mut rendezvous = Rendezvous::new();
+        rendezvous.fork_guard().completed();
+        let outcome = rendezvous.rendezvous_interruptible_signal(Duration::from_millis(10));
+        assert_eq!(outcome, RendezvousOutcome::Completed);
+        assert_eq!(current_handler(), previous);
+    }
+}
@@ -0,0 +1,91 @@
+//! Measuring the skew between guard forks and between guard completions.
+
+use crate::{lock, Rendezvous};
+use std::time::{Duration, Instant};
+
+/// The time span covered by a series of events.
+#[derive(Debug, Default, Copy, Clone)]
+pub(crate) struct Span {
+    /// The point in time of the first and of the latest event.
+    bounds: Option<(Instant, Instant)>,
+}
+
+impl Span {
+    /// Records an event occurring now.
+    pub(crate) fn record(&mut self) {
+        let now = Instant::now();
+        self.bounds = match self.bounds {
+            Some((first, _)) => Some((first, now)),
+            None => Some((now, now)),
+        };
+    }
+
+    /// Returns the time between the first and the latest event, if any.
+    fn window(&self) -> Option<Duration> {
+        self.bounds.map(|(first, last)| last - first)
+    }
+}
+
+impl<C> Rendezvous<C> {
+    /// Returns the time between the first and the latest guard fork (the "ramp-up" window),
+    /// or `None` if no guard was forked yet.
+    ///
+    /// A short window indicates that work started in a tight burst. Always returns `None` for
+    /// a [minimal](crate::RendezvousBuilder::minimal) rendezvous.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use rendezvous::RendezvousBuilder;
+    ///
+    /// let rendezvous = RendezvousBuilder::new().full_tracking().build();
+    /// assert_eq!(rendezvous.ramp_window(), None);
+    ///
+    /// let guard = rendezvous.fork_guard();
+    /// assert!(rendezvous.ramp_window().is_some());
+    /// # drop(guard);
+    /// ```
+    pub fn ramp_window(&self) -> Option<Duration> {
+        let channel = self.channel.get()?;
+        lock(&channel.shared.fork_span).window()
+    }
+
+    /// Returns the time between the first and the latest guard completion, or `None` if no
+    /// guard completed yet.
+    ///
+    /// A short window indicates that work finished in a tight burst. Always returns `None` for
+    /// a [minimal](crate::RendezvousBuilder::minimal) rendezvous.
+    pub fn completion_window(&self) -> Option<Duration> {
+        let channel = self.channel.get()?;
+        lock(&channel.shared.completion_span).window()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RendezvousBuilder;
+    use std::thread;
+
+    #[test]
+    fn ramp_window_spans_forks() {
+        let rendezvous = RendezvousBuilder::new().full_tracking().build();
+        let first = rendezvous.fork_guard();
+        assert_eq!(rendezvous.ramp_window(), Some(Duration::ZERO));
+
+        thread::sleep(Duration::from_millis(200));
+        let second = rendezvous.fork_guard();
+
+        let window = rendezvous.ramp_window().unwrap();
+        assert!(window >= Duration::from_millis(200), "{window:?}");
+
+        assert_eq!(rendezvous.completion_window(), None);
+        drop(first);
+        thread::sleep(Duration::from_millis(50));
+        drop(second);
+
+        let window = rendezvous.completion_window().unwrap();
+        assert!(window >= Duration::from_millis(50), "{window:?}");
+        rendezvous.rendezvous();
+    }
+}
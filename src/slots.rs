@@ -0,0 +1,93 @@
+//! Limiting concurrency using a pool of guard slots.
+
+use crate::{lock, Rendezvous, RendezvousGuard, Shared};
+use std::sync::atomic::Ordering;
+
+impl Shared {
+    /// Wakes up threads waiting for a free slot, if any.
+    pub(crate) fn notify_slots(&self) {
+        if self.slot_waiters.load(Ordering::Acquire) > 0 {
+            let _lock = lock(&self.slot_lock);
+            self.slot_freed.notify_all();
+        }
+    }
+}
+
+impl<C> Rendezvous<C> {
+    /// Blocks until fewer than `max` guards are alive, then forks a guard.
+    ///
+    /// This uses the guards as a pool of slots, limiting the number of concurrently running
+    /// workers while still gating a final rendezvous on all of them.
+    ///
+    /// <div class="warning">
+    /// This blocks until another thread drops a guard. Calling it while the calling thread
+    /// itself holds the guards that need to be dropped is a deadlock.
+    /// </div>
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `max` is zero, since no slot could ever become available.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::thread;
+    /// use rendezvous::Rendezvous;
+    ///
+    /// let rendezvous = Rendezvous::new();
+    /// for _ in 0..8 {
+    ///     // At most two workers run at any time.
+    ///     let guard = rendezvous.acquire_slot(2);
+    ///     thread::spawn(move || drop(guard));
+    /// }
+    /// rendezvous.rendezvous();
+    /// ```
+    pub fn acquire_slot(&self, max: usize) -> RendezvousGuard {
+        assert!(max > 0, "A slot pool requires at least one slot");
+        let shared = self.shared();
+        let mut slot_lock = lock(&shared.slot_lock);
+        shared.slot_waiters.fetch_add(1, Ordering::AcqRel);
+        while shared.live_guards() >= max {
+            slot_lock = shared
+                .slot_freed
+                .wait(slot_lock)
+                .unwrap_or_else(|poison| poison.into_inner());
+        }
+        shared.slot_waiters.fetch_sub(1, Ordering::AcqRel);
+        drop(slot_lock);
+        self.fork_guard()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicBool;
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn acquire_blocks_until_a_slot_is_freed() {
+        let rendezvous = Rendezvous::new();
+        let first = rendezvous.acquire_slot(2);
+        let second = rendezvous.acquire_slot(2);
+
+        let released = Arc::new(AtomicBool::new(false));
+        thread::spawn({
+            let released = released.clone();
+            move || {
+                thread::sleep(Duration::from_millis(50));
+                released.store(true, Ordering::Release);
+                drop(first);
+            }
+        });
+
+        let third = rendezvous.acquire_slot(2);
+        assert!(released.load(Ordering::Acquire));
+        assert_eq!(rendezvous.remaining_guards(), 2);
+
+        drop((second, third));
+        rendezvous.rendezvous();
+    }
+}
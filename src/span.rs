@@ -0,0 +1,183 @@
+//! Spans around waits, emitted with the `tracing` feature.
+
+use crate::Rendezvous;
+#[cfg(feature = "tokio")]
+use std::future::Future;
+#[cfg(feature = "tracing")]
+use std::time::Instant;
+
+/// A span entered for the duration of a blocking wait, recording the wait duration as its
+/// `elapsed` field when dropped. Without the `tracing` feature, this does nothing.
+pub(crate) struct WaitSpan {
+    #[cfg(feature = "tracing")]
+    span: tracing::span::EnteredSpan,
+    #[cfg(feature = "tracing")]
+    started: Instant,
+}
+
+#[cfg(feature = "tracing")]
+impl Drop for WaitSpan {
+    fn drop(&mut self) {
+        self.span
+            .record("elapsed", tracing::field::debug(self.started.elapsed()));
+    }
+}
+
+/// The span of an asynchronous wait, entered only while the wait is polled; see
+/// [`WaitSpan`].
+#[cfg(feature = "tokio")]
+pub(crate) struct AsyncWaitSpan {
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
+}
+
+#[cfg(feature = "tokio")]
+impl AsyncWaitSpan {
+    /// Awaits `future` within the span, then records the wait duration.
+    pub(crate) async fn instrument<F: Future>(self, future: F) -> F::Output {
+        #[cfg(feature = "tracing")]
+        {
+            let started = Instant::now();
+            let output = tracing::Instrument::instrument(future, self.span.clone()).await;
+            self.span
+                .record("elapsed", tracing::field::debug(started.elapsed()));
+            output
+        }
+        #[cfg(not(feature = "tracing"))]
+        future.await
+    }
+}
+
+impl<C> Rendezvous<C> {
+    /// Enters the span of the blocking wait `wait`, named after the method waiting.
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+    pub(crate) fn wait_span(&self, wait: &'static str) -> WaitSpan {
+        WaitSpan {
+            #[cfg(feature = "tracing")]
+            span: self.span(wait).entered(),
+            #[cfg(feature = "tracing")]
+            started: Instant::now(),
+        }
+    }
+
+    /// Creates the span of the asynchronous wait `wait`; see [`Rendezvous::wait_span`].
+    #[cfg(feature = "tokio")]
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+    pub(crate) fn async_wait_span(&self, wait: &'static str) -> AsyncWaitSpan {
+        AsyncWaitSpan {
+            #[cfg(feature = "tracing")]
+            span: self.span(wait),
+        }
+    }
+
+    /// Creates a `rendezvous` span with the waiting method, the round and the number of
+    /// outstanding guards when the wait started.
+    #[cfg(feature = "tracing")]
+    fn span(&self, wait: &'static str) -> tracing::Span {
+        tracing::info_span!(
+            "rendezvous",
+            wait,
+            round = self.round,
+            guards = self.remaining_guards(),
+            elapsed = tracing::field::Empty,
+        )
+    }
+}
+
+#[cfg(all(test, feature = "tracing"))]
+mod tests {
+    use crate::{Rendezvous, RendezvousTimeoutError};
+    use std::fmt::Debug;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    /// The fields of a span or an event, in the order they were recorded.
+    #[derive(Default)]
+    struct Fields(Vec<(&'static str, String)>);
+
+    impl Fields {
+        fn get(&self, name: &str) -> Option<&str> {
+            self.0
+                .iter()
+                .find(|(field, _)| *field == name)
+                .map(|(_, value)| value.as_str())
+        }
+    }
+
+    impl Visit for Fields {
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            self.0.push((field.name(), format!("{value:?}")));
+        }
+    }
+
+    /// Records the fields of all spans and events.
+    #[derive(Default)]
+    struct Recorder {
+        spans: Arc<Mutex<Vec<Fields>>>,
+        events: Arc<Mutex<Vec<Fields>>>,
+    }
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            let mut fields = Fields::default();
+            span.record(&mut fields);
+            let mut spans = self.spans.lock().unwrap();
+            spans.push(fields);
+            Id::from_u64(spans.len() as u64)
+        }
+
+        fn record(&self, span: &Id, values: &Record<'_>) {
+            let index = span.into_u64() as usize - 1;
+            values.record(&mut self.spans.lock().unwrap()[index]);
+        }
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            let mut fields = Fields::default();
+            event.record(&mut fields);
+            self.events.lock().unwrap().push(fields);
+        }
+
+        fn enter(&self, _: &Id) {}
+
+        fn exit(&self, _: &Id) {}
+    }
+
+    #[test]
+    fn waits_are_wrapped_in_spans_recording_the_duration() {
+        let recorder = Recorder::default();
+        let spans = recorder.spans.clone();
+        let events = recorder.events.clone();
+
+        tracing::subscriber::with_default(recorder, || {
+            let mut rendezvous = Rendezvous::new();
+            let guard = rendezvous.fork_guard();
+            assert_eq!(
+                rendezvous.rendezvous_timeout(Duration::from_millis(10)),
+                Err(RendezvousTimeoutError::Timeout)
+            );
+            drop(guard);
+            rendezvous.rendezvous();
+        });
+
+        let spans = spans.lock().unwrap();
+        let waits: Vec<_> = spans.iter().filter_map(|span| span.get("wait")).collect();
+        assert_eq!(waits, ["\"rendezvous_timeout\"", "\"rendezvous\""]);
+        assert_eq!(spans[0].get("guards"), Some("1"));
+        assert_eq!(spans[0].get("round"), Some("0"));
+        assert!(spans.iter().all(|span| span.get("elapsed").is_some()));
+
+        let events = events.lock().unwrap();
+        assert!(events.iter().any(|event| event
+            .get("message")
+            .is_some_and(|message| message.starts_with("A timeout occurred during a rendezvous"))));
+    }
+}
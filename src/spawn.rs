@@ -0,0 +1,153 @@
+//! Spawning guarded threads.
+
+use crate::{Rendezvous, RendezvousGuard};
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc;
+use std::thread::{self, JoinHandle};
+
+/// A type-erased join of a thread spawned by [`Rendezvous::spawn_guarded_collecting`].
+pub(crate) type Joiner = Box<dyn FnOnce() -> thread::Result<Box<dyn Any + Send>> + Send>;
+
+impl<C> Rendezvous<C> {
+    /// Spawns a thread running `f` with a guard forked off this rendezvous.
+    ///
+    /// The guard is passed to `f`; unless moved elsewhere, it is dropped when `f` returns.
+    /// A dedicated thread is spawned even if the rendezvous was built with a
+    /// [thread pool](crate::RendezvousBuilder::thread_pool), since its [`JoinHandle`] is
+    /// returned; use [`Rendezvous::submit_guarded`] to run `f` on the pool.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use rendezvous::Rendezvous;
+    ///
+    /// let rendezvous = Rendezvous::new();
+    /// let handle = rendezvous.spawn_guarded(|_guard| 42);
+    /// rendezvous.rendezvous();
+    /// assert_eq!(handle.join().unwrap(), 42);
+    /// ```
+    pub fn spawn_guarded<F, T>(&self, f: F) -> JoinHandle<T>
+    where
+        F: FnOnce(RendezvousGuard) -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        let guard = self.fork_guard();
+        thread::spawn(move || f(guard))
+    }
+
+    /// Spawns a thread running `f` with a guard forked off this rendezvous, recording its
+    /// [`JoinHandle`] to collect the result using [`Rendezvous::rendezvous_join`].
+    ///
+    /// All threads spawned using this method on the same rendezvous must return the same type.
+    /// If the rendezvous was built with a [thread pool](crate::RendezvousBuilder::thread_pool),
+    /// `f` runs on the pool instead of a dedicated thread.
+    pub fn spawn_guarded_collecting<F, T>(&self, f: F)
+    where
+        F: FnOnce(RendezvousGuard) -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        if let Some(pool) = self.pool() {
+            let guard = self.fork_guard();
+            let (tx, rx) = mpsc::sync_channel(1);
+            pool.execute(move || {
+                tx.send(panic::catch_unwind(AssertUnwindSafe(|| f(guard))))
+                    .ok();
+            });
+            self.spawned.borrow_mut().push(Box::new(move || {
+                rx.recv()
+                    .expect("pooled tasks run to completion")
+                    .map(|value| Box::new(value) as Box<dyn Any + Send>)
+            }));
+            return;
+        }
+        let handle = self.spawn_guarded(f);
+        self.spawned.borrow_mut().push(Box::new(move || {
+            handle
+                .join()
+                .map(|value| Box::new(value) as Box<dyn Any + Send>)
+        }));
+    }
+
+    /// Executes the rendezvous process, then joins all threads spawned using
+    /// [`Rendezvous::spawn_guarded_collecting`] and returns their results in spawn order.
+    ///
+    /// A thread that panicked yields the `Err` of its [`JoinHandle::join`].
+    ///
+    /// ## Panics
+    ///
+    /// Panics if a thread spawned using [`Rendezvous::spawn_guarded_collecting`] returned a type
+    /// other than `T`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use rendezvous::Rendezvous;
+    ///
+    /// let rendezvous = Rendezvous::new();
+    /// for i in 0..3 {
+    ///     rendezvous.spawn_guarded_collecting(move |_guard| i * 2);
+    /// }
+    ///
+    /// let results: Vec<_> = rendezvous
+    ///     .rendezvous_join::<i32>()
+    ///     .into_iter()
+    ///     .map(Result::unwrap)
+    ///     .collect();
+    /// assert_eq!(results, vec![0, 2, 4]);
+    /// ```
+    pub fn rendezvous_join<T>(mut self) -> Vec<thread::Result<T>>
+    where
+        T: Send + 'static,
+    {
+        let _span = self.wait_span("rendezvous_join");
+        self.rendezvous_internal();
+        self.spawned
+            .take()
+            .into_iter()
+            .map(|join| {
+                join().map(|value| {
+                    *value
+                        .downcast::<T>()
+                        .expect("all collected threads return the requested type")
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn rendezvous_join_collects_results() {
+        let rendezvous = Rendezvous::new();
+        for i in 0..3u64 {
+            rendezvous.spawn_guarded_collecting(move |_guard| {
+                thread::sleep(Duration::from_millis(30 * (3 - i)));
+                i + 1
+            });
+        }
+
+        let results: Vec<_> = rendezvous
+            .rendezvous_join::<u64>()
+            .into_iter()
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(results, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn rendezvous_join_reports_panics() {
+        let rendezvous = Rendezvous::new();
+        rendezvous.spawn_guarded_collecting(|_guard| 1);
+        rendezvous.spawn_guarded_collecting(|_guard| -> i32 { panic!("worker failed") });
+
+        let results = rendezvous.rendezvous_join::<i32>();
+        assert_eq!(results.len(), 2);
+        assert!(matches!(results[0], Ok(1)));
+        assert!(results[1].is_err());
+    }
+}
@@ -0,0 +1,175 @@
+//! Timing out stragglers relative to the lifetimes of already completed guards.
+
+use crate::{lock, Rendezvous, RendezvousTimeoutError, Shared, POLL_INTERVAL};
+use std::ops::ControlFlow;
+use std::sync::atomic::Ordering;
+use std::time::{Duration, Instant};
+
+impl Shared {
+    /// Records the lifetime of a completed guard forked at `forked_at`.
+    pub(crate) fn record_lifetime(&self, forked_at: Instant) {
+        lock(&self.lifetimes).push(forked_at.elapsed());
+    }
+
+    /// Returns the median lifetime of all completed guards, once at least half of all forked
+    /// guards completed.
+    ///
+    /// The median is only recomputed if further guards completed since it was last stored in
+    /// `median`.
+    fn median_lifetime(&self, median: &mut Median) -> Option<Duration> {
+        let forked = self.forked.load(Ordering::Acquire);
+        {
+            let mut lifetimes = lock(&self.lifetimes);
+            if lifetimes.len() != median.completed {
+                median.completed = lifetimes.len();
+                let middle = lifetimes.len() / 2;
+                let (_, lifetime, _) = lifetimes.select_nth_unstable(middle);
+                median.lifetime = *lifetime;
+            }
+        }
+        if median.completed == 0 || median.completed * 2 < forked {
+            return None;
+        }
+        Some(median.lifetime)
+    }
+}
+
+/// The median lifetime of the completed guards, as of a number of completed guards.
+#[derive(Default)]
+struct Median {
+    /// The number of completed guards the median was computed for.
+    completed: usize,
+    /// The median lifetime, or zero if no guards completed.
+    lifetime: Duration,
+}
+
+impl<C> Rendezvous<C> {
+    /// Executes the rendezvous process, timing out once an outstanding guard lives longer than
+    /// `factor` times the median lifetime of the already completed guards.
+    ///
+    /// The dynamic deadline only applies once at least half of all forked guards completed;
+    /// until then, this waits like [`Rendezvous::rendezvous`]. This is a classic
+    /// straggler-mitigation policy: e.g. with a `factor` of `2.0`, a guard taking more than
+    /// twice as long as the typical guard is considered a straggler. The completion state is
+    /// polled every few milliseconds, so stragglers are detected slightly late.
+    ///
+    /// A [minimal](crate::RendezvousBuilder::minimal) rendezvous, the default in release builds
+    /// without the `diagnostics` feature, does not record lifetimes, so it never times out.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `factor` is negative, NaN or infinite.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::thread;
+    /// use std::time::Duration;
+    /// use rendezvous::{RendezvousBuilder, RendezvousTimeoutError};
+    ///
+    /// let mut rendezvous = RendezvousBuilder::new().full_tracking().build();
+    /// for _ in 0..3 {
+    ///     let guard = rendezvous.fork_guard();
+    ///     thread::spawn(move || {
+    ///         thread::sleep(Duration::from_millis(20));
+    ///         drop(guard);
+    ///     });
+    /// }
+    /// let straggler = rendezvous.fork_guard();
+    ///
+    /// let result = rendezvous.rendezvous_straggler(2.0);
+    /// assert_eq!(result, Err(RendezvousTimeoutError::Timeout));
+    /// drop(straggler);
+    /// ```
+    pub fn rendezvous_straggler(&mut self, factor: f32) -> Result<(), RendezvousTimeoutError> {
+        let _span = self.wait_span("rendezvous_straggler");
+        assert!(
+            factor.is_finite() && factor >= 0.0,
+            "the straggler factor must be finite and non-negative, got {factor}"
+        );
+        let started = Instant::now();
+        let mut result = Ok(());
+        if let Some(channel) = self.release() {
+            let shared = &channel.shared;
+            let mut median = Median::default();
+            let straggling = channel.poll_until(|| {
+                let (Some(median), Some(oldest)) =
+                    (shared.median_lifetime(&mut median), shared.oldest_age())
+                else {
+                    return ControlFlow::Continue(POLL_INTERVAL);
+                };
+                // A limit too large to represent is never exceeded.
+                let limit = Duration::try_from_secs_f64(median.as_secs_f64() * f64::from(factor))
+                    .unwrap_or(Duration::MAX);
+                if oldest > limit {
+                    ControlFlow::Break(limit)
+                } else {
+                    ControlFlow::Continue(POLL_INTERVAL.min(limit - oldest))
+                }
+            });
+            if let Some(_limit) = straggling {
+                #[cfg(any(feature = "log", feature = "tracing"))]
+                {
+                    debug!(
+                        "A straggler exceeded {_limit:?} ({factor} times the median guard lifetime){}",
+                        self.log_context()
+                    );
+                }
+                result = Err(RendezvousTimeoutError::Timeout);
+            }
+        }
+        self.record_wait(started);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RendezvousBuilder;
+    use std::thread;
+
+    #[test]
+    fn straggler_is_timed_out() {
+        let mut rendezvous = RendezvousBuilder::new().full_tracking().build();
+        for delay in [90, 100, 100, 110] {
+            let guard = rendezvous.fork_guard();
+            thread::spawn(move || {
+                thread::sleep(Duration::from_millis(delay));
+                drop(guard);
+            });
+        }
+        let straggler = rendezvous.fork_guard();
+        let worker = thread::spawn(move || {
+            thread::sleep(Duration::from_secs(1));
+            drop(straggler);
+        });
+
+        let started = Instant::now();
+        let result = rendezvous.rendezvous_straggler(2.0);
+        assert_eq!(result, Err(RendezvousTimeoutError::Timeout));
+        let elapsed = started.elapsed();
+        assert!(elapsed >= Duration::from_millis(180), "{elapsed:?}");
+        worker.join().unwrap();
+    }
+
+    #[test]
+    fn uniform_guards_complete() {
+        let mut rendezvous = Rendezvous::new();
+        for delay in [40, 50, 60] {
+            let guard = rendezvous.fork_guard();
+            thread::spawn(move || {
+                thread::sleep(Duration::from_millis(delay));
+                drop(guard);
+            });
+        }
+        assert_eq!(rendezvous.rendezvous_straggler(3.0), Ok(()));
+    }
+
+    #[test]
+    #[should_panic(expected = "the straggler factor must be finite and non-negative")]
+    fn invalid_factor_panics() {
+        let mut rendezvous = Rendezvous::new();
+        let _ = rendezvous.rendezvous_straggler(f32::NAN);
+    }
+}
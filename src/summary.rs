@@ -0,0 +1,62 @@
+//! Logging a summary line when a rendezvous completes.
+
+use crate::{lock, Rendezvous};
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
+impl<C> Rendezvous<C> {
+    /// Logs the number of guards, the duration of the wait and the number of panicked guards
+    /// after a completed wait, e.g.
+    /// `Rendezvous completed: 12 guards, 340ms, 0 panics ["ingest", round 0]`.
+    pub(crate) fn log_summary(&self, waited: Duration) {
+        if !enabled!(info) {
+            return;
+        }
+        let (guards, panics) = self.channel.get().map_or((0, 0), |channel| {
+            let shared = &channel.shared;
+            (
+                shared.forked.load(Ordering::SeqCst),
+                lock(&shared.panicked).len(),
+            )
+        });
+        info!(
+            "Rendezvous completed: {guards} guards, {}ms, {panics} panics{}",
+            waited.as_millis(),
+            self.log_context()
+        );
+    }
+}
+
+#[cfg(all(test, feature = "log"))]
+mod tests {
+    use crate::test_log;
+    use crate::Rendezvous;
+    use std::thread;
+
+    #[test]
+    fn summary_counts_guards_and_panics() {
+        test_log::init();
+
+        let rendezvous = Rendezvous::with_context("summary-5d1f");
+        let guard = rendezvous.fork_guard();
+        let nested = guard.fork();
+        let panicking = rendezvous.fork_guard();
+        thread::spawn(move || drop((guard, nested)));
+        let worker = thread::spawn(move || {
+            let _guard = panicking;
+            panic!("worker failed");
+        });
+        rendezvous.rendezvous();
+        assert!(worker.join().is_err());
+
+        let messages = test_log::messages_containing("summary-5d1f");
+        let summaries: Vec<_> = messages
+            .iter()
+            .filter(|(_, message)| message.starts_with("Rendezvous completed"))
+            .collect();
+        assert_eq!(summaries.len(), 1);
+        assert_eq!(summaries[0].0, log::Level::Info);
+        assert!(summaries[0].1.contains(": 3 guards, "));
+        assert!(summaries[0].1.contains("ms, 1 panics"));
+    }
+}
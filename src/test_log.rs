@@ -0,0 +1,43 @@
+//! A logger capturing log messages for assertions in tests.
+
+use crate::lock;
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use std::sync::{Mutex, Once};
+
+/// All messages logged since the logger was installed.
+static MESSAGES: Mutex<Vec<(Level, String)>> = Mutex::new(Vec::new());
+
+/// The capturing logger.
+struct CapturingLogger;
+
+impl Log for CapturingLogger {
+    fn enabled(&self, _metadata: &Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record) {
+        lock(&MESSAGES).push((record.level(), record.args().to_string()));
+    }
+
+    fn flush(&self) {}
+}
+
+/// Installs the capturing logger, if not installed already.
+pub(crate) fn init() {
+    static INIT: Once = Once::new();
+    INIT.call_once(|| {
+        log::set_logger(&CapturingLogger).expect("no other logger is installed");
+        log::set_max_level(LevelFilter::Trace);
+    });
+}
+
+/// Returns all captured messages containing `pattern`.
+///
+/// Tests run concurrently and share the logger, so the pattern should be specific to a test.
+pub(crate) fn messages_containing(pattern: &str) -> Vec<(Level, String)> {
+    lock(&MESSAGES)
+        .iter()
+        .filter(|(_, message)| message.contains(pattern))
+        .cloned()
+        .collect()
+}
@@ -0,0 +1,158 @@
+//! Rate-limiting the per-guard trace messages of a rendezvous.
+
+use crate::{lock, Shared};
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// The kinds of per-guard events that are traced.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum TraceEvent {
+    /// A guard was forked.
+    Fork,
+    /// A guard was dropped.
+    Drop,
+}
+
+impl TraceEvent {
+    fn index(self) -> usize {
+        match self {
+            TraceEvent::Fork => 0,
+            TraceEvent::Drop => 1,
+        }
+    }
+}
+
+/// The interval in which suppressed events are summarized.
+const SUMMARY_INTERVAL: Duration = Duration::from_secs(1);
+
+/// A token bucket admitting at most a fixed number of trace messages per second.
+#[derive(Debug)]
+pub(crate) struct TokenBucket {
+    /// The number of tokens replenished per second, which is also the bucket capacity.
+    rate: f64,
+    /// The number of tokens currently available.
+    tokens: f64,
+    /// When tokens were last replenished.
+    refilled_at: Instant,
+    /// When suppressed events were last summarized.
+    summarized_at: Instant,
+    /// The number of suppressed events since the last summary, by [`TraceEvent::index`].
+    suppressed: [u64; 2],
+}
+
+/// The decision of a [`TokenBucket`] for a single event.
+#[derive(Debug, Default, Eq, PartialEq)]
+pub(crate) struct Admission {
+    /// Whether the event's message should be emitted.
+    pub emit: bool,
+    /// The number of suppressed fork and drop events to summarize, if a summary is due.
+    pub summary: Option<[u64; 2]>,
+}
+
+impl TokenBucket {
+    /// Creates a full bucket admitting `max_per_sec` messages per second.
+    pub fn new(max_per_sec: u32, now: Instant) -> Self {
+        Self {
+            rate: f64::from(max_per_sec),
+            tokens: f64::from(max_per_sec),
+            refilled_at: now,
+            summarized_at: now,
+            suppressed: [0; 2],
+        }
+    }
+
+    /// Decides whether the message for `event` occurring at `now` is emitted.
+    pub fn admit(&mut self, event: TraceEvent, now: Instant) -> Admission {
+        let elapsed = now.saturating_duration_since(self.refilled_at);
+        self.tokens = (self.tokens + elapsed.as_secs_f64() * self.rate).min(self.rate);
+        self.refilled_at = now;
+
+        let emit = self.tokens >= 1.0;
+        if emit {
+            self.tokens -= 1.0;
+        } else {
+            self.suppressed[event.index()] += 1;
+        }
+
+        let mut summary = None;
+        if now.saturating_duration_since(self.summarized_at) >= SUMMARY_INTERVAL {
+            self.summarized_at = now;
+            if self.suppressed != [0; 2] {
+                summary = Some(std::mem::take(&mut self.suppressed));
+            }
+        }
+        Admission { emit, summary }
+    }
+}
+
+impl Shared {
+    /// Emits the trace message for a per-guard event, subject to the configured throttle.
+    pub(crate) fn trace_event(&self, event: TraceEvent, message: fmt::Arguments) {
+        if !enabled!(trace) {
+            return;
+        }
+        let Some(max_per_sec) = self.options.log_throttle else {
+            trace!("{message}");
+            return;
+        };
+
+        let now = Instant::now();
+        let admission = lock(&self.throttle)
+            .get_or_insert_with(|| TokenBucket::new(max_per_sec, now))
+            .admit(event, now);
+        if let Some([forks, drops]) = admission.summary {
+            trace!(
+                "Suppressed {forks} fork events and {drops} drop events in the last second [round {}]",
+                self.round
+            );
+        }
+        if admission.emit {
+            trace!("{message}");
+        }
+    }
+}
+
+#[cfg(all(test, feature = "log"))]
+mod tests {
+    use super::*;
+    use crate::{test_log, Rendezvous};
+
+    #[test]
+    fn bucket_limits_rate_and_summarizes() {
+        let start = Instant::now();
+        let mut bucket = TokenBucket::new(2, start);
+
+        assert!(bucket.admit(TraceEvent::Fork, start).emit);
+        assert!(bucket.admit(TraceEvent::Fork, start).emit);
+        assert_eq!(bucket.admit(TraceEvent::Fork, start), Admission::default());
+        assert_eq!(bucket.admit(TraceEvent::Drop, start), Admission::default());
+
+        // Half a second replenishes one token.
+        let later = start + Duration::from_millis(500);
+        assert!(bucket.admit(TraceEvent::Drop, later).emit);
+        assert!(!bucket.admit(TraceEvent::Drop, later).emit);
+
+        let admission = bucket.admit(TraceEvent::Fork, start + SUMMARY_INTERVAL);
+        assert!(admission.emit);
+        assert_eq!(admission.summary, Some([1, 2]));
+    }
+
+    #[test]
+    fn fork_messages_are_bounded() {
+        test_log::init();
+
+        let mut rendezvous = Rendezvous::with_context("throttle-test");
+        rendezvous.options.log_throttle = Some(10);
+
+        let started = Instant::now();
+        let guards: Vec<_> = (0..1000).map(|_| rendezvous.fork_guard()).collect();
+        let seconds = started.elapsed().as_secs();
+        drop(guards);
+        rendezvous.rendezvous();
+
+        let emitted =
+            test_log::messages_containing("Forking rendezvous guard [\"throttle-test\", round 0]");
+        assert!(!emitted.is_empty());
+        assert!(emitted.len() as u64 <= 10 * (seconds + 1));
+    }
+}
@@ -0,0 +1,163 @@
+//! Completion callbacks for tiers of prioritized guards.
+
+use crate::{lock, Rendezvous, RendezvousGuard, Shared};
+use std::collections::HashMap;
+
+/// A callback invoked when a tier completes.
+type TierCallback = Box<dyn FnOnce() + Send>;
+
+/// The guards and callbacks of a single priority tier.
+#[derive(Default)]
+pub(crate) struct Tier {
+    /// The number of live guards of this priority.
+    live: usize,
+    /// The callbacks to invoke when the last live guard is dropped.
+    callbacks: Vec<TierCallback>,
+}
+
+/// The tiers of prioritized guards, by priority.
+pub(crate) type Tiers = HashMap<u8, Tier>;
+
+impl Shared {
+    /// Counts a newly forked guard of `priority`.
+    fn on_tier_fork(&self, priority: u8) {
+        lock(&self.tiers).entry(priority).or_default().live += 1;
+    }
+
+    /// Counts a dropped guard of `priority`, invoking the tier's callbacks if it was the last.
+    pub(crate) fn on_tier_release(&self, priority: u8) {
+        let callbacks = {
+            let mut tiers = lock(&self.tiers);
+            let tier = tiers.entry(priority).or_default();
+            tier.live -= 1;
+            if tier.live > 0 {
+                return;
+            }
+            std::mem::take(&mut tier.callbacks)
+        };
+        for callback in callbacks {
+            callback();
+        }
+    }
+}
+
+impl<C> Rendezvous<C> {
+    /// Forks a guard belonging to the tier of `priority`.
+    ///
+    /// The guard behaves like one forked using [`Rendezvous::fork_guard`], but additionally
+    /// counts towards its tier, see [`Rendezvous::on_tier_complete`]. Guards forked off it using
+    /// [`RendezvousGuard::fork`] belong to the same tier.
+    pub fn fork_guard_prioritized(&self, priority: u8) -> RendezvousGuard {
+        let mut guard = self.fork_guard();
+        guard.set_priority(priority);
+        guard
+    }
+
+    /// Invokes `f` once all guards of the tier of `priority` are dropped, even if guards of other
+    /// tiers remain.
+    ///
+    /// This supports staged cleanup, e.g. starting a compaction once all high-priority flushes
+    /// are done. The callback is invoked on the thread dropping the last guard of the tier,
+    /// before that guard is released; if the tier has no live guards, it is invoked immediately.
+    /// Each callback is invoked at most once.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::sync::mpsc;
+    /// use rendezvous::Rendezvous;
+    ///
+    /// let (tx, rx) = mpsc::channel();
+    /// let mut rendezvous = Rendezvous::new();
+    /// let flush = rendezvous.fork_guard_prioritized(0);
+    /// let compact = rendezvous.fork_guard_prioritized(1);
+    /// rendezvous.on_tier_complete(0, move || tx.send("flushed").unwrap());
+    ///
+    /// drop(flush);
+    /// assert_eq!(rx.try_recv(), Ok("flushed"));
+    /// drop(compact);
+    /// rendezvous.rendezvous();
+    /// ```
+    pub fn on_tier_complete<F>(&mut self, priority: u8, f: F)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        {
+            let mut tiers = lock(&self.shared().tiers);
+            let tier = tiers.entry(priority).or_default();
+            if tier.live > 0 {
+                tier.callbacks.push(Box::new(f));
+                return;
+            }
+        }
+        f();
+    }
+}
+
+impl RendezvousGuard {
+    /// Assigns this guard to the tier of `priority`.
+    fn set_priority(&mut self, priority: u8) {
+        self.shared.on_tier_fork(priority);
+        self.priority = Some(priority);
+    }
+
+    /// Assigns a guard forked off this one to the same tier, if any.
+    pub(crate) fn inherit_priority(&self, guard: &mut RendezvousGuard) {
+        if let Some(priority) = self.priority {
+            guard.set_priority(priority);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::{mpsc, Arc};
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn high_tier_completes_before_low_tier() {
+        let mut rendezvous = Rendezvous::new();
+        let low_done = Arc::new(AtomicBool::new(false));
+        let (tx, rx) = mpsc::channel();
+
+        let high = rendezvous.fork_guard_prioritized(0);
+        let nested = high.fork();
+        let low = rendezvous.fork_guard_prioritized(1);
+        rendezvous.on_tier_complete(0, {
+            let low_done = low_done.clone();
+            move || tx.send(low_done.load(Ordering::Acquire)).unwrap()
+        });
+
+        thread::spawn(move || drop(high));
+        thread::spawn({
+            let low_done = low_done.clone();
+            move || {
+                thread::sleep(Duration::from_millis(50));
+                drop(nested);
+                thread::sleep(Duration::from_millis(50));
+                low_done.store(true, Ordering::Release);
+                drop(low);
+            }
+        });
+
+        // The high tier includes the nested guard, but not the low-tier guard.
+        assert_eq!(rx.recv(), Ok(false));
+        rendezvous.rendezvous();
+        assert!(low_done.load(Ordering::Acquire));
+    }
+
+    #[test]
+    fn empty_tier_completes_immediately() {
+        let mut rendezvous = Rendezvous::new();
+        let invoked = Arc::new(AtomicBool::new(false));
+        rendezvous.on_tier_complete(3, {
+            let invoked = invoked.clone();
+            move || invoked.store(true, Ordering::Release)
+        });
+        assert!(invoked.load(Ordering::Acquire));
+        rendezvous.rendezvous();
+    }
+}
@@ -0,0 +1,206 @@
+//! Transferring the obligations of all live guards to another rendezvous.
+
+use crate::{lock, Channel, Rendezvous, RendezvousGuard, Shared, Signal};
+use std::sync::atomic::Ordering;
+use std::sync::mpsc::{RecvError, RecvTimeoutError, TryRecvError};
+use std::time::{Duration, Instant};
+
+/// The state of a rendezvous whose guards were transferred to another one.
+pub(crate) struct Transfer {
+    /// The number of guards forked at the moment of the transfer.
+    forked: usize,
+    /// The number of guards completed at the moment of the transfer.
+    completed: usize,
+    /// The guards of the target rendezvous standing in for the transferred guards.
+    heirs: Vec<RendezvousGuard>,
+}
+
+impl Channel {
+    /// Receives the next signal, reporting a disconnect once the guards were transferred.
+    pub(crate) fn recv(&self) -> Result<Signal, RecvError> {
+        if self.shared.is_transferred() {
+            return Err(RecvError);
+        }
+        self.lock_receiver().recv()
+    }
+
+    /// Receives the next signal if one is available, reporting a disconnect once the guards
+    /// were transferred.
+    pub(crate) fn try_recv(&self) -> Result<Signal, TryRecvError> {
+        if self.shared.is_transferred() {
+            return Err(TryRecvError::Disconnected);
+        }
+        match self.try_lock_receiver() {
+            Some(rx) => rx.try_recv(),
+            // Another clone is waiting and will observe any signal instead.
+            None => Err(TryRecvError::Empty),
+        }
+    }
+
+    /// Receives the next signal within `timeout`, reporting a disconnect once the guards were
+    /// transferred.
+    pub(crate) fn recv_timeout(&self, timeout: Duration) -> Result<Signal, RecvTimeoutError> {
+        if self.shared.is_transferred() {
+            return Err(RecvTimeoutError::Disconnected);
+        }
+        let deadline = Instant::now() + timeout;
+        let rx = self
+            .lock_receiver_until(deadline)
+            .ok_or(RecvTimeoutError::Timeout)?;
+        rx.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+    }
+}
+
+impl Shared {
+    /// Returns whether the guards were transferred to another rendezvous.
+    pub(crate) fn is_transferred(&self) -> bool {
+        self.transferred.load(Ordering::SeqCst)
+    }
+
+    /// Called with the fork index of a newly forked guard; forks an heir if the guard was
+    /// forked after the transfer.
+    pub(crate) fn forward_fork(&self, index: usize) {
+        if !self.is_transferred() {
+            return;
+        }
+        let mut transfer = lock(&self.transfer);
+        let Some(transfer) = transfer.as_mut() else {
+            return;
+        };
+        if index >= transfer.forked {
+            // The parent of the guard is alive, so there is at least one heir to fork off.
+            if let Some(heir) = transfer.heirs.last().map(RendezvousGuard::fork) {
+                transfer.heirs.push(heir);
+            }
+        }
+    }
+
+    /// Called with the completion index of a dropped guard; releases an heir if the guard
+    /// was still alive at the moment of the transfer.
+    pub(crate) fn forward_release(&self, index: usize) {
+        if !self.is_transferred() {
+            return;
+        }
+        let heir = lock(&self.transfer)
+            .as_mut()
+            .filter(|transfer| index >= transfer.completed)
+            .and_then(|transfer| transfer.heirs.pop());
+        drop(heir);
+    }
+}
+
+impl<C> Rendezvous<C> {
+    /// Transfers the obligations of all live guards to `target`, so that this rendezvous can
+    /// complete without waiting for them.
+    ///
+    /// For every live guard, `target` forks a guard standing in for it, carrying its label.
+    /// Whenever one of the transferred guards is dropped, one of those guards is dropped as
+    /// well, and guards forked off transferred guards count towards `target`. Afterwards, this
+    /// rendezvous has no remaining guards and completes immediately. Forking further guards
+    /// off this rendezvous itself panics.
+    ///
+    /// The guards of `target` are forked before the transfer takes effect, so `target` never
+    /// misses an obligation, and guards dropped concurrently are accounted for exactly once.
+    /// This rendezvous does not complete in the middle of the transfer, since waiting for it
+    /// requires exclusive access.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the guards were already transferred.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use rendezvous::Rendezvous;
+    ///
+    /// let old = Rendezvous::new();
+    /// let guard = old.fork_guard();
+    ///
+    /// let new = Rendezvous::new();
+    /// old.transfer_all_to(&new);
+    /// old.rendezvous();
+    ///
+    /// assert_eq!(new.remaining_guards(), 1);
+    /// drop(guard);
+    /// new.rendezvous();
+    /// ```
+    pub fn transfer_all_to<D>(&self, target: &Rendezvous<D>) {
+        let shared = self.shared();
+        let mut transfer = lock(&shared.transfer);
+        assert!(transfer.is_none(), "Guards were already transferred");
+
+        shared.transferred.store(true, Ordering::SeqCst);
+        let completed = shared.completed.load(Ordering::SeqCst);
+        let forked = shared.forked.load(Ordering::SeqCst);
+        let mut labels = shared.snapshot().into_iter().map(|guard| guard.label);
+        let heirs = (completed..forked)
+            .map(|_| target.fork_guard_with(labels.next().flatten()))
+            .collect();
+        *transfer = Some(Transfer {
+            forked,
+            completed,
+            heirs,
+        });
+        drop(transfer);
+        shared.wake_if_complete();
+
+        #[cfg(any(feature = "log", feature = "tracing"))]
+        {
+            debug!(
+                "Transferred {} rendezvous guards{}",
+                forked - completed,
+                self.log_context()
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{RendezvousBuilder, RendezvousTimeoutError};
+    use std::thread;
+
+    #[test]
+    fn transferred_guards_gate_the_target() {
+        let source = RendezvousBuilder::new().full_tracking().build();
+        let first = source.fork_guard_labeled("first");
+        let second = source.fork_guard();
+        let third = source.fork_guard();
+        let completed = source.fork_guard();
+        drop(completed);
+
+        let mut target = RendezvousBuilder::new().full_tracking().build();
+        source.transfer_all_to(&target);
+        assert_eq!(source.remaining_guards(), 0);
+        source.rendezvous();
+
+        assert_eq!(target.remaining_guards(), 3);
+        assert_eq!(
+            target.shared().snapshot()[0].label.as_deref(),
+            Some("first")
+        );
+
+        let nested = first.fork();
+        assert_eq!(target.remaining_guards(), 4);
+        drop(first);
+        assert_eq!(target.remaining_guards(), 3);
+        assert_eq!(
+            target.rendezvous_timeout(Duration::from_millis(10)),
+            Err(RendezvousTimeoutError::Timeout)
+        );
+
+        thread::spawn(move || drop((second, third, nested)));
+        assert_eq!(target.rendezvous_timeout(Duration::from_secs(5)), Ok(()));
+    }
+
+    #[test]
+    #[should_panic(expected = "transferred")]
+    fn forking_off_a_transferred_rendezvous_panics() {
+        let source = Rendezvous::new();
+        let target = Rendezvous::new();
+        source.transfer_all_to(&target);
+        target.rendezvous();
+        source.fork_guard();
+    }
+}
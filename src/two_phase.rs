@@ -0,0 +1,202 @@
+//! A two-phase commit across all guards of a rendezvous.
+
+use crate::{lock, Rendezvous, RendezvousGuard, RendezvousTimeoutError, POLL_INTERVAL};
+use std::ops::ControlFlow;
+use std::time::{Duration, Instant};
+
+/// A [`Rendezvous`] modelling a two-phase commit: all guards first prepare, then the
+/// coordinator either commits or aborts them together.
+///
+/// Guard holders call [`RendezvousGuard::prepare`] once their work is ready to be committed,
+/// then wait for the decision of the coordinator: either [`RendezvousGuard::is_committed`]
+/// or [`RendezvousGuard::is_cancelled`] turns `true`, in which case they finalize or roll back
+/// their work, respectively. The coordinator waits for all guards using
+/// [`TwoPhaseRendezvous::wait_prepared`], then calls [`TwoPhaseRendezvous::commit`] or
+/// [`TwoPhaseRendezvous::abort`].
+///
+/// ## Example
+///
+/// ```
+/// use std::thread;
+/// use std::time::Duration;
+/// use rendezvous::TwoPhaseRendezvous;
+///
+/// let rendezvous = TwoPhaseRendezvous::new();
+/// let guard = rendezvous.fork_guard();
+/// let worker = thread::spawn(move || {
+///     guard.prepare();
+///     while !guard.is_committed() && !guard.is_cancelled() {
+///         thread::sleep(Duration::from_millis(1));
+///     }
+///     guard.is_committed()
+/// });
+///
+/// assert_eq!(rendezvous.wait_prepared(Duration::from_secs(5)), Ok(()));
+/// rendezvous.commit();
+/// assert!(worker.join().unwrap());
+/// ```
+pub struct TwoPhaseRendezvous {
+    /// The underlying rendezvous.
+    inner: Rendezvous,
+}
+
+impl TwoPhaseRendezvous {
+    /// Creates a new instance.
+    pub fn new() -> Self {
+        Self {
+            inner: Rendezvous::new(),
+        }
+    }
+
+    /// Forks a guard off the rendezvous. See [`Rendezvous::fork_guard`].
+    pub fn fork_guard(&self) -> RendezvousGuard {
+        self.inner.fork_guard()
+    }
+
+    /// Forks a labeled guard off the rendezvous. See [`Rendezvous::fork_guard_labeled`].
+    pub fn fork_guard_labeled(&self, label: impl Into<String>) -> RendezvousGuard {
+        self.inner.fork_guard_labeled(label)
+    }
+
+    /// Returns the number of guards not yet dropped or committed.
+    /// See [`Rendezvous::remaining_guards`].
+    pub fn remaining_guards(&self) -> usize {
+        self.inner.remaining_guards()
+    }
+
+    /// Returns the number of guards prepared but not yet committed.
+    pub fn prepared_guards(&self) -> usize {
+        self.inner
+            .channel
+            .get()
+            .map_or(0, |channel| lock(&channel.shared.armed).len())
+    }
+
+    /// Blocks until all remaining guards are prepared, or the timeout occurs.
+    ///
+    /// Guards dropped without preparing no longer take part in the commit and are not waited
+    /// for. The preparation state is polled, so this returns slightly after the last guard
+    /// prepared.
+    pub fn wait_prepared(&self, timeout: Duration) -> Result<(), RendezvousTimeoutError> {
+        let deadline = Instant::now() + timeout;
+        let Some(channel) = self.inner.channel.get() else {
+            return Ok(());
+        };
+        // The rendezvous holds on to its sender, so this only disconnects if the guards were
+        // transferred, in which case none of them remain here.
+        channel
+            .poll_until(|| {
+                if self.prepared_guards() >= self.remaining_guards() {
+                    return ControlFlow::Break(Ok(()));
+                }
+                let remaining = deadline.saturating_duration_since(Instant::now());
+                if remaining.is_zero() {
+                    ControlFlow::Break(Err(RendezvousTimeoutError::Timeout))
+                } else {
+                    ControlFlow::Continue(POLL_INTERVAL.min(remaining))
+                }
+            })
+            .unwrap_or(Ok(()))
+    }
+
+    /// Commits all prepared guards, then waits for the guards that were not prepared.
+    ///
+    /// Committed guards observe the decision using [`RendezvousGuard::is_committed`] and no
+    /// longer keep the rendezvous open, even if they are still alive.
+    pub fn commit(self) {
+        self.inner.complete_armed();
+        self.inner.rendezvous();
+    }
+
+    /// Aborts the commit, then waits for all guards to be dropped.
+    ///
+    /// Guards observe the decision using [`RendezvousGuard::is_cancelled`] and are expected to
+    /// roll back their work before dropping their guards. See [`Rendezvous::cancel`].
+    pub fn abort(mut self) {
+        self.inner.cancel();
+        self.inner.rendezvous();
+    }
+}
+
+impl Default for TwoPhaseRendezvous {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<Rendezvous> for TwoPhaseRendezvous {
+    /// Wraps a rendezvous, e.g. one configured using a
+    /// [`RendezvousBuilder`](crate::RendezvousBuilder).
+    fn from(inner: Rendezvous) -> Self {
+        Self { inner }
+    }
+}
+
+impl RendezvousGuard {
+    /// Signals that the work of this guard is ready to be committed by a
+    /// [`TwoPhaseRendezvous`].
+    ///
+    /// The guard keeps the rendezvous open until the coordinator decides; see
+    /// [`RendezvousGuard::is_committed`]. This is the same as
+    /// [`RendezvousGuard::arm_completion`].
+    pub fn prepare(&self) {
+        self.arm_completion();
+    }
+
+    /// Returns whether this guard was prepared and then committed by the coordinator, using
+    /// either [`TwoPhaseRendezvous::commit`] or [`Rendezvous::complete_armed`].
+    pub fn is_committed(&self) -> bool {
+        !self.detached && lock(&self.tx).is_none() && self.shared.armed_sender(self.id).is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    /// Prepares the guard unless told otherwise, then waits for the decision of the
+    /// coordinator and returns whether the guard was committed.
+    fn participate(guard: RendezvousGuard, prepare: bool) -> thread::JoinHandle<bool> {
+        thread::spawn(move || {
+            if prepare {
+                guard.prepare();
+            }
+            while !guard.is_committed() && !guard.is_cancelled() {
+                thread::sleep(Duration::from_millis(1));
+            }
+            guard.is_committed()
+        })
+    }
+
+    #[test]
+    fn prepared_guards_commit_together() {
+        let rendezvous = TwoPhaseRendezvous::new();
+        let workers: Vec<_> = (0..3)
+            .map(|_| participate(rendezvous.fork_guard(), true))
+            .collect();
+
+        assert_eq!(rendezvous.wait_prepared(Duration::from_secs(5)), Ok(()));
+        assert_eq!(rendezvous.prepared_guards(), 3);
+        rendezvous.commit();
+        for worker in workers {
+            assert!(worker.join().unwrap());
+        }
+    }
+
+    #[test]
+    fn unprepared_guard_leads_to_abort() {
+        let rendezvous = TwoPhaseRendezvous::new();
+        let prepared = participate(rendezvous.fork_guard(), true);
+        let stuck = participate(rendezvous.fork_guard(), false);
+
+        assert_eq!(
+            rendezvous.wait_prepared(Duration::from_millis(20)),
+            Err(RendezvousTimeoutError::Timeout)
+        );
+        assert_eq!(rendezvous.prepared_guards(), 1);
+        rendezvous.abort();
+        assert!(!prepared.join().unwrap());
+        assert!(!stuck.join().unwrap());
+    }
+}
@@ -0,0 +1,133 @@
+//! A compile-time typestate API on top of [`Rendezvous`].
+//!
+//! A [`TypedRendezvous`] starts in the [`Armed`] state, in which guards can be forked.
+//! Transitioning into the [`Waiting`] state releases the original sender; from then on,
+//! forking is not available, so forking after waiting began is a compile error rather than
+//! a runtime panic:
+//!
+//! ```compile_fail
+//! use rendezvous::typestate::TypedRendezvous;
+//!
+//! let waiting = TypedRendezvous::new().start_waiting();
+//! let guard = waiting.fork_guard(); // does not compile
+//! ```
+
+use crate::{Rendezvous, RendezvousGuard, RendezvousTimeoutError};
+use std::marker::PhantomData;
+use std::time::Duration;
+
+/// The state of a [`TypedRendezvous`] in which guards can be forked.
+#[derive(Debug)]
+pub struct Armed;
+
+/// The state of a [`TypedRendezvous`] that is waiting for its guards to be dropped.
+#[derive(Debug)]
+pub struct Waiting;
+
+/// The state of a [`TypedRendezvous`] whose guards were all dropped.
+#[derive(Debug)]
+pub struct Completed;
+
+/// A [`Rendezvous`] whose state is encoded in its type parameter `S`.
+///
+/// ## Example
+///
+/// ```
+/// use std::thread;
+/// use rendezvous::typestate::TypedRendezvous;
+///
+/// let rendezvous = TypedRendezvous::new();
+/// let guard = rendezvous.fork_guard();
+/// thread::spawn(move || drop(guard));
+///
+/// let completed = rendezvous.start_waiting().wait();
+/// ```
+pub struct TypedRendezvous<S> {
+    /// The underlying dynamic rendezvous.
+    inner: Rendezvous,
+    /// The current state.
+    _state: PhantomData<S>,
+}
+
+impl TypedRendezvous<Armed> {
+    /// Creates a new rendezvous in the [`Armed`] state.
+    pub fn new() -> Self {
+        Self {
+            inner: Rendezvous::new(),
+            _state: PhantomData,
+        }
+    }
+
+    /// Forks a guard off the rendezvous. See [`Rendezvous::fork_guard`].
+    pub fn fork_guard(&self) -> RendezvousGuard {
+        self.inner.fork_guard()
+    }
+
+    /// Releases the original sender and transitions into the [`Waiting`] state.
+    pub fn start_waiting(mut self) -> TypedRendezvous<Waiting> {
+        self.inner.release();
+        self.transition()
+    }
+}
+
+impl Default for TypedRendezvous<Armed> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TypedRendezvous<Waiting> {
+    /// Blocks until all guards are dropped and transitions into the [`Completed`] state.
+    /// See [`Rendezvous::rendezvous`].
+    pub fn wait(mut self) -> TypedRendezvous<Completed> {
+        self.inner.rendezvous_internal();
+        self.transition()
+    }
+
+    /// Blocks until all guards are dropped or the timeout occurs. On success, transitions into
+    /// the [`Completed`] state; on timeout, the rendezvous is returned in the [`Waiting`] state
+    /// along with the error.
+    // Handing the rendezvous back on timeout is the point of this method, so the error is large.
+    #[allow(clippy::result_large_err)]
+    pub fn wait_timeout(
+        mut self,
+        timeout: Duration,
+    ) -> Result<TypedRendezvous<Completed>, (Self, RendezvousTimeoutError)> {
+        match self.inner.rendezvous_timeout(timeout) {
+            Ok(()) => Ok(self.transition()),
+            Err(err) => Err((self, err)),
+        }
+    }
+}
+
+impl<S> TypedRendezvous<S> {
+    /// Moves the rendezvous into the state `T`.
+    fn transition<T>(self) -> TypedRendezvous<T> {
+        TypedRendezvous {
+            inner: self.inner,
+            _state: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn typed_rendezvous_transitions() {
+        let rendezvous = TypedRendezvous::new();
+        let guard = rendezvous.fork_guard();
+
+        let waiting = rendezvous.start_waiting();
+        let (waiting, err) = waiting
+            .wait_timeout(Duration::from_millis(10))
+            .err()
+            .unwrap();
+        assert_eq!(err, RendezvousTimeoutError::Timeout);
+
+        thread::spawn(move || drop(guard));
+        let _completed: TypedRendezvous<Completed> = waiting.wait();
+    }
+}
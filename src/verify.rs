@@ -0,0 +1,144 @@
+//! Verifying the number of guards forked against an expected count.
+
+use crate::builder::Options;
+use crate::Rendezvous;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::sync::atomic::Ordering;
+
+/// Error returned by [`Rendezvous::rendezvous_verified`] if the number of forked guards
+/// differs from the expected count.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum VerifyError {
+    /// Fewer guards were forked than expected, e.g. because a worker was never spawned.
+    TooFewGuards {
+        /// The number of guards expected.
+        expected: usize,
+        /// The number of guards forked.
+        forked: usize,
+    },
+    /// More guards were forked than expected.
+    TooManyGuards {
+        /// The number of guards expected.
+        expected: usize,
+        /// The number of guards forked.
+        forked: usize,
+    },
+}
+
+impl Display for VerifyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VerifyError::TooFewGuards { expected, forked } => {
+                write!(f, "Only {forked} of {expected} expected guards were forked")
+            }
+            VerifyError::TooManyGuards { expected, forked } => {
+                write!(
+                    f,
+                    "{forked} guards were forked, but only {expected} were expected"
+                )
+            }
+        }
+    }
+}
+
+impl Error for VerifyError {}
+
+impl Rendezvous {
+    /// Creates a new instance expecting exactly `n` guards to be forked, which
+    /// [`Rendezvous::rendezvous_verified`] checks.
+    ///
+    /// This is the same as building the rendezvous using
+    /// [`RendezvousBuilder::expected_guards`](crate::RendezvousBuilder::expected_guards).
+    pub fn expecting(n: usize) -> Self {
+        Self::from_parts(
+            (),
+            Options {
+                expected_guards: Some(n),
+                ..Options::default()
+            },
+        )
+    }
+}
+
+impl<C> Rendezvous<C> {
+    /// Executes the rendezvous process, then verifies that the expected number of guards was
+    /// forked; see [`Rendezvous::expecting`].
+    ///
+    /// All guards forked in the current [round](Rendezvous::round) are counted, including
+    /// nested guards. Without an expected count, this behaves like [`Rendezvous::rendezvous`].
+    /// The other wait methods do not verify the count, so rendezvous with a dynamic fan-out
+    /// are not affected.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use rendezvous::{Rendezvous, VerifyError};
+    ///
+    /// let rendezvous = Rendezvous::expecting(3);
+    /// for _ in 0..2 {
+    ///     rendezvous.fork_guard().completed();
+    /// }
+    ///
+    /// assert_eq!(
+    ///     rendezvous.rendezvous_verified(),
+    ///     Err(VerifyError::TooFewGuards { expected: 3, forked: 2 })
+    /// );
+    /// ```
+    pub fn rendezvous_verified(mut self) -> Result<(), VerifyError> {
+        let _span = self.wait_span("rendezvous_verified");
+        self.rendezvous_ref();
+        let Some(expected) = self.options.expected_guards else {
+            return Ok(());
+        };
+        let forked = self
+            .channel
+            .get()
+            .map_or(0, |channel| channel.shared.forked.load(Ordering::SeqCst));
+        match forked.cmp(&expected) {
+            std::cmp::Ordering::Less => Err(VerifyError::TooFewGuards { expected, forked }),
+            std::cmp::Ordering::Equal => Ok(()),
+            std::cmp::Ordering::Greater => Err(VerifyError::TooManyGuards { expected, forked }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RendezvousBuilder;
+    use std::thread;
+
+    #[test]
+    fn expected_guard_count_is_verified() {
+        let rendezvous = Rendezvous::expecting(2);
+        let guard = rendezvous.fork_guard();
+        let nested = guard.fork();
+        thread::spawn(move || drop((guard, nested)));
+        assert_eq!(rendezvous.rendezvous_verified(), Ok(()));
+
+        let rendezvous = RendezvousBuilder::new().expected_guards(1).build();
+        rendezvous.fork_guard().completed();
+        rendezvous.fork_guard().completed();
+        assert_eq!(
+            rendezvous.rendezvous_verified(),
+            Err(VerifyError::TooManyGuards {
+                expected: 1,
+                forked: 2
+            })
+        );
+    }
+
+    #[test]
+    fn missing_guards_are_reported() {
+        let rendezvous = Rendezvous::expecting(4);
+        assert_eq!(
+            rendezvous.rendezvous_verified(),
+            Err(VerifyError::TooFewGuards {
+                expected: 4,
+                forked: 0
+            })
+        );
+        assert_eq!(Rendezvous::new().rendezvous_verified(), Ok(()));
+    }
+}
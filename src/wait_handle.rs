@@ -0,0 +1,214 @@
+//! Waiting for a rendezvous on a dedicated thread.
+
+use crate::{Rendezvous, POLL_INTERVAL};
+use std::ops::ControlFlow;
+use std::panic;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::Instant;
+
+/// The outcome of a wait spawned using [`Rendezvous::spawn_wait`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum WaitOutcome {
+    /// All guards were dropped.
+    Completed,
+    /// The wait was cancelled using [`WaitHandle::cancel`] before all guards were dropped.
+    Cancelled,
+}
+
+/// A handle to a rendezvous waited on by a dedicated thread; see [`Rendezvous::spawn_wait`].
+///
+/// Dropping the handle detaches the waiting thread, which keeps waiting for the guards.
+#[derive(Debug)]
+pub struct WaitHandle {
+    /// Set to abandon the wait.
+    cancelled: Arc<AtomicBool>,
+    /// The waiting thread.
+    thread: JoinHandle<WaitOutcome>,
+}
+
+impl WaitHandle {
+    /// Abandons the wait. The remaining guards keep running but are no longer waited on.
+    ///
+    /// The waiting thread observes the cancellation within a few milliseconds. Cancelling a
+    /// wait that already completed has no effect.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    /// Returns whether the wait has ended, either because all guards were dropped or because
+    /// it was cancelled.
+    pub fn is_finished(&self) -> bool {
+        self.thread.is_finished()
+    }
+
+    /// Blocks until the wait has ended and returns its outcome.
+    ///
+    /// ## Panics
+    ///
+    /// Resumes the panic of the waiting thread, if any.
+    pub fn join(self) -> WaitOutcome {
+        self.thread
+            .join()
+            .unwrap_or_else(|payload| panic::resume_unwind(payload))
+    }
+}
+
+impl<C: Send + 'static> Rendezvous<C> {
+    /// Moves the rendezvous into a dedicated thread that waits for all guards to be dropped.
+    ///
+    /// The returned [`WaitHandle`] retrieves the outcome using [`WaitHandle::join`], or
+    /// abandons the wait using [`WaitHandle::cancel`]. A cancelled rendezvous is dropped
+    /// without blocking, like one interrupted by a failing guard.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use rendezvous::{Rendezvous, WaitOutcome};
+    ///
+    /// let rendezvous = Rendezvous::new();
+    /// let guard = rendezvous.fork_guard();
+    ///
+    /// let wait = rendezvous.spawn_wait();
+    /// drop(guard);
+    /// assert_eq!(wait.join(), WaitOutcome::Completed);
+    /// ```
+    pub fn spawn_wait(mut self) -> WaitHandle {
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let flag = Arc::clone(&cancelled);
+        let thread = thread::Builder::new()
+            .name("rendezvous-wait".into())
+            .spawn(move || self.wait_until_cancelled(&flag))
+            .expect("failed to spawn the waiting thread");
+        WaitHandle { cancelled, thread }
+    }
+
+    /// Moves the rendezvous into a dedicated thread that waits for all guards to be dropped,
+    /// then invokes `f`.
+    ///
+    /// This is a fire-and-forget [`Rendezvous::rendezvous`]: the calling thread continues
+    /// immediately. If no guards are outstanding, including if none were ever forked, `f` is
+    /// invoked promptly. Use [`Rendezvous::spawn_wait`] to cancel the wait or join it instead.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::sync::mpsc;
+    /// use rendezvous::Rendezvous;
+    ///
+    /// let rendezvous = Rendezvous::new();
+    /// let guard = rendezvous.fork_guard();
+    ///
+    /// let (tx, rx) = mpsc::channel();
+    /// rendezvous.on_complete(move || tx.send("done").unwrap());
+    /// drop(guard);
+    /// assert_eq!(rx.recv(), Ok("done"));
+    /// ```
+    pub fn on_complete(self, f: impl FnOnce() + Send + 'static) {
+        thread::Builder::new()
+            .name("rendezvous-wait".into())
+            .spawn(move || {
+                self.rendezvous();
+                f();
+            })
+            .expect("failed to spawn the waiting thread");
+    }
+
+    /// Waits for all guards to be dropped or for `cancelled` to be set.
+    fn wait_until_cancelled(&mut self, cancelled: &AtomicBool) -> WaitOutcome {
+        let started = Instant::now();
+        let mut outcome = WaitOutcome::Completed;
+        if let Some(channel) = self.release() {
+            let cancelled = channel.poll_until(|| {
+                if cancelled.load(Ordering::SeqCst) {
+                    ControlFlow::Break(WaitOutcome::Cancelled)
+                } else {
+                    ControlFlow::Continue(POLL_INTERVAL)
+                }
+            });
+            outcome = cancelled.unwrap_or(WaitOutcome::Completed);
+        }
+        self.record_wait(started);
+        if outcome == WaitOutcome::Cancelled {
+            #[cfg(any(feature = "log", feature = "tracing"))]
+            {
+                debug!(
+                    "A spawned rendezvous wait was cancelled{}",
+                    self.log_context()
+                );
+            }
+            self.abandoned = true;
+        }
+        outcome
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc::{self, RecvTimeoutError};
+    use std::time::Duration;
+
+    #[test]
+    fn cancelled_wait_returns_promptly() {
+        let rendezvous = Rendezvous::new();
+        let stuck = rendezvous.fork_guard();
+
+        let wait = rendezvous.spawn_wait();
+        thread::sleep(Duration::from_millis(20));
+        assert!(!wait.is_finished());
+
+        let started = Instant::now();
+        wait.cancel();
+        assert_eq!(wait.join(), WaitOutcome::Cancelled);
+        assert!(started.elapsed() < Duration::from_secs(1));
+        drop(stuck);
+    }
+
+    #[test]
+    fn wait_completes_once_guards_are_dropped() {
+        let rendezvous = Rendezvous::new();
+        let guard = rendezvous.fork_guard();
+
+        let wait = rendezvous.spawn_wait();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(10));
+            drop(guard);
+        });
+        assert_eq!(wait.join(), WaitOutcome::Completed);
+    }
+
+    #[test]
+    fn callback_runs_once_guards_are_dropped() {
+        let rendezvous = Rendezvous::new();
+        let guard = rendezvous.fork_guard();
+        let (tx, rx) = mpsc::channel();
+        rendezvous.on_complete(move || tx.send(()).unwrap());
+
+        assert_eq!(
+            rx.recv_timeout(Duration::from_millis(20)),
+            Err(RecvTimeoutError::Timeout)
+        );
+        drop(guard);
+        assert_eq!(rx.recv_timeout(Duration::from_secs(5)), Ok(()));
+    }
+
+    #[test]
+    fn callback_runs_promptly_without_outstanding_guards() {
+        let (tx, rx) = mpsc::channel();
+        let tx_unforked = tx.clone();
+        Rendezvous::new().on_complete(move || tx_unforked.send("unforked").unwrap());
+
+        let completed = Rendezvous::new();
+        completed.fork_guard().completed();
+        completed.on_complete(move || tx.send("completed").unwrap());
+
+        let mut received = [
+            rx.recv_timeout(Duration::from_secs(5)).unwrap(),
+            rx.recv_timeout(Duration::from_secs(5)).unwrap(),
+        ];
+        received.sort();
+        assert_eq!(received, ["completed", "unforked"]);
+    }
+}
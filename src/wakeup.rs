@@ -0,0 +1,105 @@
+//! Measuring how promptly a waiter observes the completion of the last guard.
+
+use crate::{lock, Rendezvous, Shared};
+use std::time::{Duration, Instant};
+
+impl Shared {
+    /// Records that a sender is about to be dropped, which may disconnect the channel.
+    ///
+    /// Concurrent drops keep the latest instant, so the recorded instant is that of the
+    /// last disconnect up to the time between stamping and dropping the sender.
+    pub(crate) fn stamp_disconnect(&self) {
+        let now = Instant::now();
+        let mut disconnected_at = lock(&self.disconnected_at);
+        if disconnected_at.map_or(true, |at| at < now) {
+            *disconnected_at = Some(now);
+        }
+    }
+}
+
+impl<C> Rendezvous<C> {
+    /// Returns how long it took the last completed wait to return after the channel
+    /// disconnected, i.e. after the last guard was dropped or, if all guards were dropped
+    /// before waiting, after the wait started.
+    ///
+    /// This quantifies the overhead of the rendezvous itself, chiefly the scheduler latency of
+    /// waking up the waiting thread, separate from the time the guarded work took. Returns
+    /// `None` before the first wait and after a wait that did not complete, e.g. one that timed
+    /// out, as well as for a [minimal](crate::RendezvousBuilder::minimal) rendezvous.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::thread;
+    /// use std::time::Duration;
+    /// use rendezvous::RendezvousBuilder;
+    ///
+    /// let mut rendezvous = RendezvousBuilder::new().full_tracking().build();
+    /// let guard = rendezvous.fork_guard();
+    /// thread::spawn(move || drop(guard));
+    ///
+    /// rendezvous.rendezvous_timeout(Duration::from_secs(1)).unwrap();
+    /// let latency = rendezvous.last_wakeup_latency().unwrap();
+    /// println!("woke up {latency:?} after the last guard was dropped");
+    /// ```
+    pub fn last_wakeup_latency(&self) -> Option<Duration> {
+        self.wakeup_latency
+    }
+
+    /// Records the wakeup latency of a wait that returned at `observed`.
+    pub(crate) fn record_wakeup(&mut self, observed: Instant) {
+        self.wakeup_latency = self.channel.get().and_then(|channel| {
+            if channel.shared.live_guards() != 0 {
+                return None;
+            }
+            let disconnected_at = (*lock(&channel.shared.disconnected_at))?;
+            Some(observed.saturating_duration_since(disconnected_at))
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{RendezvousBuilder, RendezvousTimeoutError};
+    use std::sync::{Arc, Barrier};
+    use std::thread;
+
+    #[test]
+    fn wakeup_latency_is_small() {
+        let mut rendezvous = RendezvousBuilder::new().full_tracking().build();
+        assert_eq!(rendezvous.last_wakeup_latency(), None);
+
+        let guard = rendezvous.fork_guard();
+        let barrier = Arc::new(Barrier::new(2));
+        let worker = {
+            let barrier = barrier.clone();
+            thread::spawn(move || {
+                barrier.wait();
+                thread::sleep(Duration::from_millis(50));
+                drop(guard);
+            })
+        };
+
+        barrier.wait();
+        rendezvous
+            .rendezvous_timeout(Duration::from_secs(5))
+            .unwrap();
+        let latency = rendezvous.last_wakeup_latency().unwrap();
+        // Well below the timeout, to tolerate scheduling delays on loaded machines.
+        assert!(latency < Duration::from_secs(2), "{latency:?}");
+        worker.join().unwrap();
+    }
+
+    #[test]
+    fn no_wakeup_latency_after_timeout() {
+        let mut rendezvous = Rendezvous::new();
+        let guard = rendezvous.fork_guard();
+        assert_eq!(
+            rendezvous.rendezvous_timeout(Duration::from_millis(10)),
+            Err(RendezvousTimeoutError::Timeout)
+        );
+        assert_eq!(rendezvous.last_wakeup_latency(), None);
+        drop(guard);
+    }
+}
@@ -0,0 +1,163 @@
+//! Weighted guards and waiting for a quorum of completed weight.
+
+use crate::{lock, GuardId, Rendezvous, RendezvousError, RendezvousGuard, Shared, POLL_INTERVAL};
+use std::ops::ControlFlow;
+use std::sync::atomic::Ordering;
+use std::time::Instant;
+
+impl Shared {
+    /// Records the weight of the dropped guard `id`, if it was forked with a weight.
+    ///
+    /// Guards without a weight count as one through the number of dropped guards, so this
+    /// takes no lock unless a weighted guard was ever forked.
+    pub(crate) fn record_weight(&self, id: GuardId) {
+        if !self.weighted.load(Ordering::Acquire) {
+            return;
+        }
+        if let Some(weight) = lock(&self.weights).remove(&id) {
+            self.weight_offset
+                .fetch_add(i64::from(weight) - 1, Ordering::SeqCst);
+        }
+    }
+
+    /// Returns the total weight of all dropped guards.
+    fn completed_weight(&self) -> u64 {
+        let offset = self.weight_offset.load(Ordering::SeqCst);
+        let completed = self.completed.load(Ordering::SeqCst) as i64;
+        u64::try_from(completed + offset).unwrap_or(0)
+    }
+}
+
+impl<C> Rendezvous<C> {
+    /// Forks a guard that contributes `weight` to the completed weight once dropped; see
+    /// [`Rendezvous::rendezvous_weight_quorum`].
+    ///
+    /// Guards forked otherwise, including guards forked off a weighted guard, have a weight
+    /// of one. Apart from its weight, the guard behaves exactly like one forked using
+    /// [`Rendezvous::fork_guard`].
+    pub fn fork_guard_weighted(&self, weight: u32) -> RendezvousGuard {
+        let guard = self.fork_guard();
+        let shared = &guard.shared;
+        lock(&shared.weights).insert(guard.id, weight);
+        shared.weighted.store(true, Ordering::Release);
+        guard
+    }
+
+    /// Blocks until the weights of the dropped guards add up to at least `weight`, then
+    /// returns without waiting for the remaining guards.
+    ///
+    /// A guard contributes its weight once dropped, no matter how: guards completed
+    /// regularly, dropped while panicking, or completed using
+    /// [`Rendezvous::complete_armed`] all count, and guards dropped before this call count as
+    /// well. Like [`Rendezvous::rendezvous_timeout`], this releases the sender held by the
+    /// rendezvous, and the remaining guards still keep it open. The completed weight is polled
+    /// every few milliseconds.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`RendezvousError::QuorumUnreachable`] if all guards were dropped without
+    /// reaching `weight`, i.e. if the quorum can never be reached.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use rendezvous::Rendezvous;
+    ///
+    /// let mut rendezvous = Rendezvous::new();
+    /// let minor = rendezvous.fork_guard_weighted(1);
+    /// let major = rendezvous.fork_guard_weighted(3);
+    /// drop(major);
+    ///
+    /// assert_eq!(rendezvous.rendezvous_weight_quorum(3), Ok(()));
+    /// drop(minor);
+    /// ```
+    pub fn rendezvous_weight_quorum(&mut self, weight: u32) -> Result<(), RendezvousError> {
+        let _span = self.wait_span("rendezvous_weight_quorum");
+        let started = Instant::now();
+        let weight = u64::from(weight);
+        let mut completed = 0;
+        if let Some(channel) = self.release() {
+            let shared = &channel.shared;
+            channel.poll_until(|| {
+                if shared.completed_weight() >= weight {
+                    ControlFlow::Break(())
+                } else {
+                    ControlFlow::Continue(POLL_INTERVAL)
+                }
+            });
+            completed = shared.completed_weight();
+        }
+        self.record_wait(started);
+        if completed >= weight {
+            return Ok(());
+        }
+        #[cfg(any(feature = "log", feature = "tracing"))]
+        {
+            debug!(
+                "Only a weight of {completed} of the requested {weight} completed{}",
+                self.log_context()
+            );
+        }
+        Err(RendezvousError::QuorumUnreachable {
+            requested: weight,
+            completed,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RendezvousTimeoutError;
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn heaviest_guard_alone_satisfies_the_quorum() {
+        let mut rendezvous = Rendezvous::new();
+        let light = rendezvous.fork_guard_weighted(1);
+        let medium = rendezvous.fork_guard_weighted(2);
+        let heavy = rendezvous.fork_guard_weighted(3);
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(10));
+            drop(heavy);
+        });
+
+        assert_eq!(rendezvous.rendezvous_weight_quorum(3), Ok(()));
+        assert_eq!(rendezvous.remaining_guards(), 2);
+        drop((light, medium));
+    }
+
+    #[test]
+    fn light_guards_add_up() {
+        let mut rendezvous = Rendezvous::new();
+        let nested = rendezvous.fork_guard_weighted(2).fork();
+        let zero = rendezvous.fork_guard_weighted(0);
+        drop(zero);
+        let heavy = rendezvous.fork_guard_weighted(5);
+        thread::spawn(move || drop(nested));
+
+        // 2 + 1 + 0 falls short of 4, until the heavy guard drops.
+        assert_eq!(
+            rendezvous.rendezvous_timeout(Duration::from_millis(20)),
+            Err(RendezvousTimeoutError::Timeout)
+        );
+        assert_eq!(rendezvous.shared().completed_weight(), 3);
+        drop(heavy);
+        assert_eq!(rendezvous.rendezvous_weight_quorum(4), Ok(()));
+    }
+
+    #[test]
+    fn unreachable_quorum_fails_once_all_guards_dropped() {
+        let mut rendezvous = Rendezvous::new();
+        let guard = rendezvous.fork_guard_weighted(2);
+        thread::spawn(move || drop(guard));
+        assert_eq!(
+            rendezvous.rendezvous_weight_quorum(3),
+            Err(RendezvousError::QuorumUnreachable {
+                requested: 3,
+                completed: 2,
+            })
+        );
+    }
+}
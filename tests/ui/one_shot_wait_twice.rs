@@ -0,0 +1,8 @@
+use std::time::Duration;
+use rendezvous::OneShotRendezvous;
+
+fn main() {
+    let rendezvous = OneShotRendezvous::new();
+    rendezvous.rendezvous_timeout(Duration::from_millis(10)).ok();
+    rendezvous.rendezvous();
+}
@@ -0,0 +1,6 @@
+use rendezvous::Rendezvous;
+
+fn main() {
+    let pending = Rendezvous::new().into_pending();
+    let _guard = pending.fork_guard();
+}
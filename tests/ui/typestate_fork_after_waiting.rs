@@ -0,0 +1,6 @@
+use rendezvous::typestate::TypedRendezvous;
+
+fn main() {
+    let waiting = TypedRendezvous::new().start_waiting();
+    let _guard = waiting.fork_guard();
+}
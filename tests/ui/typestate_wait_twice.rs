@@ -0,0 +1,7 @@
+use rendezvous::typestate::TypedRendezvous;
+
+fn main() {
+    let waiting = TypedRendezvous::new().start_waiting();
+    let completed = waiting.wait();
+    let _again = completed.wait();
+}